@@ -0,0 +1,276 @@
+//! Activity model linking sessions to their laps, and laps to their records and lengths
+//!
+//! Laps belong to the sessions listing them with `first_lap_index` and `num_laps`, or to the
+//! session they end in when a session lacks those fields. Records, lengths and events belong to
+//! the lap or session they are timestamped in, as the exporters split records by laps.
+
+use crate::decoder::{Messages, Record};
+use crate::export::{date_time, lap_index, number, profile_type, text};
+use crate::profile::types::Sport;
+use chrono::{DateTime, Duration, Utc};
+use indexmap::IndexMap;
+use serde::Serialize;
+
+/// `message_index` bits holding the index, the upper bits are flags
+const MESSAGE_INDEX_MASK: usize = 0x0FFF;
+
+/// Decoded activity file
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Activity {
+    pub file_id: Option<Record>,
+    /// The `activity` message, summarizing the sessions
+    pub activity: Option<Record>,
+    pub sessions: Vec<Session>,
+    /// `device_info` messages merged per `device_index`, holding the latest value of each field
+    pub devices: Vec<Record>,
+    /// Laps no session lists, and the laps of a file without sessions
+    pub laps: Vec<Lap>,
+    /// Records of a file without laps
+    pub records: Vec<Record>,
+    /// Lengths of a file without laps
+    pub lengths: Vec<Record>,
+    /// Events of a file without sessions
+    pub events: Vec<Record>,
+    /// Messages of the other types, e.g. `hrv` or `sport`, as decoded
+    pub other: Messages,
+}
+
+/// A `session` message with its laps and events
+///
+/// Multisport activities have one session per sport, and transitions between them are sessions
+/// of the `transition` sport.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Session {
+    pub session: Record,
+    pub laps: Vec<Lap>,
+    pub events: Vec<Record>,
+}
+
+/// A `lap` message with its records and lengths
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Lap {
+    pub lap: Record,
+    pub records: Vec<Record>,
+    /// Pool lengths of a swimming lap
+    pub lengths: Vec<Record>,
+}
+
+impl From<Messages> for Activity {
+    fn from(messages: Messages) -> Self {
+        Activity::new(messages)
+    }
+}
+
+impl Activity {
+    /// Build the model from the messages of a decoded file
+    pub fn new(mut messages: Messages) -> Self {
+        let mut take = |name: &str| messages.shift_remove(name).unwrap_or_default();
+        let file_id = take("file_id").into_iter().next();
+        let activity = take("activity").into_iter().next();
+        let devices = merge_devices(take("device_info"));
+        let sessions = take("session");
+        let laps = take("lap");
+        let (records, lengths, events) = (take("record"), take("length"), take("event"));
+
+        let mut activity = Activity {
+            file_id,
+            activity,
+            devices,
+            other: messages,
+            ..Activity::default()
+        };
+        let laps = if laps.is_empty() {
+            activity.records = records;
+            activity.lengths = lengths;
+            Vec::new()
+        } else {
+            let ends = end_times(&laps);
+            laps.into_iter()
+                .zip(group(records, &ends))
+                .zip(group(lengths, &ends))
+                .map(|((lap, records), lengths)| Lap {
+                    lap,
+                    records,
+                    lengths,
+                })
+                .collect()
+        };
+        if sessions.is_empty() {
+            activity.laps = laps;
+            activity.events = events;
+            return activity;
+        }
+
+        let ends = end_times(&sessions);
+        let mut session_laps = sessions.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+        let lap_ranges = sessions
+            .iter()
+            .map(|it| {
+                let first = read_usize(it, "first_lap_index")?;
+                Some(first..first + read_usize(it, "num_laps")?)
+            })
+            .collect::<Option<Vec<_>>>();
+        for (position, lap) in laps.into_iter().enumerate() {
+            let session = match &lap_ranges {
+                Some(ranges) => {
+                    let index = read_usize(&lap.lap, "message_index")
+                        .map_or(position, |it| it & MESSAGE_INDEX_MASK);
+                    ranges.iter().position(|it| it.contains(&index))
+                }
+                None => Some(match end_time(&lap.lap) {
+                    Some(time) => lap_index(&ends, time),
+                    None => 0,
+                }),
+            };
+            match session {
+                Some(session) => session_laps[session].push(lap),
+                None => activity.laps.push(lap),
+            }
+        }
+        activity.sessions = sessions
+            .into_iter()
+            .zip(session_laps)
+            .zip(group(events, &ends))
+            .map(|((session, laps), events)| Session {
+                session,
+                laps,
+                events,
+            })
+            .collect();
+        activity
+    }
+
+    /// Whether the activity has several sessions, not counting transitions
+    pub fn is_multisport(&self) -> bool {
+        self.legs().count() > 1
+    }
+
+    /// Sessions other than transitions
+    pub fn legs(&self) -> impl Iterator<Item = &Session> {
+        self.sessions.iter().filter(|it| !it.is_transition())
+    }
+
+    /// Transition sessions between the legs of a multisport activity
+    pub fn transitions(&self) -> impl Iterator<Item = &Session> {
+        self.sessions.iter().filter(|it| it.is_transition())
+    }
+
+    /// Distinct sports of the legs, in order
+    pub fn sports(&self) -> Vec<Sport> {
+        let mut sports = Vec::new();
+        for sport in self.legs().filter_map(|it| it.sport()) {
+            if !sports.contains(&sport) {
+                sports.push(sport);
+            }
+        }
+        sports
+    }
+
+    /// The session in progress at `time`
+    pub fn session_at(&self, time: DateTime<Utc>) -> Option<&Session> {
+        self.sessions.iter().find(|it| {
+            it.start_time().is_some_and(|start| start <= time)
+                && it.end_time().is_some_and(|end| time <= end)
+        })
+    }
+
+    /// Laps of every session, followed by the laps outside of sessions
+    pub fn laps(&self) -> impl Iterator<Item = &Lap> {
+        self.sessions
+            .iter()
+            .flat_map(|it| &it.laps)
+            .chain(&self.laps)
+    }
+
+    /// Records of every lap, followed by the records outside of laps
+    pub fn records(&self) -> impl Iterator<Item = &Record> {
+        self.laps().flat_map(|it| &it.records).chain(&self.records)
+    }
+
+    /// Lengths of every lap, followed by the lengths outside of laps
+    pub fn lengths(&self) -> impl Iterator<Item = &Record> {
+        self.laps().flat_map(|it| &it.lengths).chain(&self.lengths)
+    }
+}
+
+impl Session {
+    pub fn sport(&self) -> Option<Sport> {
+        profile_type(&self.session, "sport")
+    }
+    pub fn is_transition(&self) -> bool {
+        self.sport() == Some(Sport::Transition)
+    }
+    pub fn start_time(&self) -> Option<DateTime<Utc>> {
+        date_time(&self.session, "start_time")
+    }
+    pub fn end_time(&self) -> Option<DateTime<Utc>> {
+        end_time(&self.session)
+    }
+    pub fn records(&self) -> impl Iterator<Item = &Record> {
+        self.laps.iter().flat_map(|it| &it.records)
+    }
+    pub fn lengths(&self) -> impl Iterator<Item = &Record> {
+        self.laps.iter().flat_map(|it| &it.lengths)
+    }
+}
+
+impl Lap {
+    pub fn start_time(&self) -> Option<DateTime<Utc>> {
+        date_time(&self.lap, "start_time")
+    }
+    pub fn end_time(&self) -> Option<DateTime<Utc>> {
+        end_time(&self.lap)
+    }
+}
+
+/// End of a lap or session, its `timestamp` or else `start_time` plus `total_elapsed_time`
+fn end_time(record: &Record) -> Option<DateTime<Utc>> {
+    date_time(record, "timestamp").or_else(|| {
+        let elapsed = number(record, "total_elapsed_time")?;
+        Some(date_time(record, "start_time")? + Duration::milliseconds((elapsed * 1000.0) as i64))
+    })
+}
+
+/// End times of laps or sessions, one lacking an end time ends with the previous one
+fn end_times(records: &[Record]) -> Vec<DateTime<Utc>> {
+    let mut previous = DateTime::<Utc>::MIN_UTC;
+    records
+        .iter()
+        .map(|it| {
+            previous = end_time(it).unwrap_or(previous);
+            previous
+        })
+        .collect()
+}
+
+/// Group messages by the lap or session they are timestamped in, see [`lap_index`]
+fn group(messages: Vec<Record>, ends: &[DateTime<Utc>]) -> Vec<Vec<Record>> {
+    let mut groups = ends.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+    let mut index = 0;
+    for message in messages {
+        // messages without a timestamp stay with the previous message
+        if let Some(time) = date_time(&message, "timestamp") {
+            index = lap_index(ends, time);
+        }
+        groups[index].push(message);
+    }
+    groups
+}
+
+fn merge_devices(device_infos: Vec<Record>) -> Vec<Record> {
+    let mut devices: IndexMap<Option<String>, Record> = IndexMap::new();
+    for device_info in device_infos {
+        devices
+            .entry(text(&device_info, "device_index"))
+            .or_default()
+            .extend(device_info);
+    }
+    devices.into_values().collect()
+}
+
+fn read_usize(record: &Record, name: &str) -> Option<usize> {
+    record
+        .get(name)
+        .filter(|it| it.is_valid())
+        .and_then(|it| it.try_as_usize().ok())
+}
@@ -94,7 +94,7 @@ mod tests {
             assert_eq!(reader.consumed, index + 1);
             assert_eq!(reader.available(), index + 1 != values.len());
         }
-        let scenarios = vec![
+        let scenarios = [
             Scenario {
                 data: &[0xAA],
                 base_type: fit::BaseType::UInt8,
@@ -132,7 +132,7 @@ mod tests {
                 values: &[0x76543210],
             },
         ];
-        for (idx, scenario) in scenarios.iter().enumerate() {
+        for scenario in scenarios.iter() {
             let mut reader = BitReader::new(scenario.to_value());
             for (index, value) in scenario.values.iter().enumerate() {
                 assert_eq!(
@@ -150,7 +150,7 @@ mod tests {
         for value in values {
             assert_eq!(Some(*value), reader.next())
         }
-        let scenarios = vec![
+        let scenarios = [
             Scenario {
                 data: &[0xAA],
                 base_type: fit::BaseType::UInt8,
@@ -34,6 +34,17 @@ impl<'input> ByteReader<'input> {
     pub(crate) fn offset(&self) -> usize {
         self.offset
     }
+    /// Error if fewer than `len` bytes are left, as reading past the end panics
+    pub(crate) fn ensure(&self, len: usize) -> Result<(), ErrorKind> {
+        if self.offset + len > self.bytes.len() {
+            return Err(ErrorKind::OutOfBoundsRead {
+                offset: self.offset,
+                requested_len: len,
+                remaining_len: self.bytes.len().saturating_sub(self.offset),
+            });
+        }
+        Ok(())
+    }
     pub(crate) fn read_bytes(&mut self, len: usize) -> &'input [u8] {
         if self.offset + len > self.bytes.len() {
             panic!(
@@ -76,11 +87,6 @@ impl<'input> ByteReader<'input> {
     }
 }
 
-trait FromNumeric {
-    fn from_be_bytes(bytes: &[u8]) -> Self;
-    fn from_le_bytes(bytes: &[u8]) -> Self;
-}
-
 impl<'input> From<&'input [u8]> for ByteReader<'input> {
     fn from(value: &'input [u8]) -> Self {
         ByteReader::new(value)
@@ -0,0 +1,358 @@
+//! Summary catalog of every FIT file under a directory
+//!
+//! Files ending in `.fit` are read from the directory tree and from the zip archives in it,
+//! including archives nested in archives as in Garmin data exports. Files are decoded in
+//! parallel, and a file that fails to decode, or makes the decoder panic, only fails its own
+//! catalog entry.
+
+use crate::decoder::{DecodeEvent, DecodedMessage, Decoder, Messages, Record};
+use crate::export::{date_time, number, rfc3339, text};
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Serializer};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Cursor, Read, Seek};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use zip::ZipArchive;
+
+/// Summary of a FIT file
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CatalogEntry {
+    /// Path of the file, files in a zip archive follow the archive path, e.g.
+    /// `export.zip/activities/1.fit`
+    pub path: String,
+    pub file_type: Option<String>,
+    #[serde(serialize_with = "serialize_time")]
+    pub time_created: Option<DateTime<Utc>>,
+    /// Sports of the sessions, joined with `,` for multisport activities
+    pub sport: Option<String>,
+    /// Total timer time of the sessions in seconds
+    pub duration: Option<f64>,
+    /// Total distance of the sessions in metres
+    pub distance: Option<f64>,
+    /// Product name, or manufacturer and product, of the device that created the file
+    pub device: Option<String>,
+    /// Number of recoverable decoding errors
+    pub errors: usize,
+    /// Error that stopped decoding, the summary holds the messages decoded before it
+    pub error: Option<String>,
+}
+
+/// Largest zip entry read by default, 512 MiB
+pub const MAX_ENTRY_SIZE: u64 = 512 << 20;
+
+/// Options of [`scan`]
+#[derive(Debug, Clone, Default)]
+pub struct ScanOptions {
+    /// Number of decoding threads, one per CPU when 0
+    pub threads: usize,
+    /// Largest zip entry in bytes, nested archives included, as entries are decompressed in
+    /// memory, [`MAX_ENTRY_SIZE`] when 0
+    pub max_entry_size: u64,
+}
+
+/// Where the bytes of a zip archive are read from
+#[derive(Debug, Clone)]
+enum ArchiveData {
+    Path(PathBuf),
+    /// An archive nested in another archive
+    Bytes(Arc<[u8]>),
+}
+
+#[derive(Debug)]
+enum Source {
+    File(PathBuf),
+    /// Entry of one of the found archives
+    Entry {
+        archive: usize,
+        index: usize,
+    },
+    /// A directory or archive that could not be read
+    Failed(String),
+}
+
+trait ReadSeek: Read + Seek + Send {}
+impl<T: Read + Seek + Send> ReadSeek for T {}
+
+/// Decode every FIT file under `root`, which may also be a single FIT file or zip archive
+///
+/// Entries are in path order, only failing to read `root` itself is an error.
+pub fn scan(root: &Path, options: &ScanOptions) -> io::Result<Vec<CatalogEntry>> {
+    let limit = match options.max_entry_size {
+        0 => MAX_ENTRY_SIZE,
+        limit => limit,
+    };
+    let mut archives = Vec::new();
+    let mut sources = Vec::new();
+    // only an unreadable root fails the scan, other paths fail their own entries
+    if fs::metadata(root)?.is_dir() {
+        fs::read_dir(root)?;
+        find_files(root, limit, &mut archives, &mut sources);
+    } else if has_extension(&root.to_string_lossy(), "zip") {
+        File::open(root)?;
+        find_entries(
+            &root.to_string_lossy(),
+            ArchiveData::Path(root.to_owned()),
+            limit,
+            &mut archives,
+            &mut sources,
+        );
+    } else {
+        sources.push((
+            root.to_string_lossy().into_owned(),
+            Source::File(root.to_owned()),
+        ));
+    }
+
+    let threads = match options.threads {
+        0 => thread::available_parallelism().map_or(1, |it| it.get()),
+        threads => threads,
+    };
+    let next = AtomicUsize::new(0);
+    let entries = Mutex::new(Vec::with_capacity(sources.len()));
+    thread::scope(|scope| {
+        for _ in 0..threads.min(sources.len()) {
+            scope.spawn(|| {
+                // each thread opens the archives it reads entries from once
+                let mut opened = HashMap::new();
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some((path, source)) = sources.get(index) else {
+                        break;
+                    };
+                    let entry = match read_source(source, &archives, limit, &mut opened) {
+                        Ok(bytes) => summarize(path.clone(), &bytes),
+                        Err(error) => CatalogEntry {
+                            path: path.clone(),
+                            error: Some(error),
+                            ..CatalogEntry::default()
+                        },
+                    };
+                    entries.lock().unwrap().push((index, entry));
+                }
+            });
+        }
+    });
+    let mut entries = entries.into_inner().unwrap();
+    entries.sort_by_key(|(index, _)| *index);
+    Ok(entries.into_iter().map(|(_, entry)| entry).collect())
+}
+
+/// Summarize a FIT file, catching decoder panics
+pub fn summarize(path: impl Into<String>, bytes: &[u8]) -> CatalogEntry {
+    let path = path.into();
+    // collected outside the closure so a panic keeps what was decoded before it
+    let mut messages = Messages::default();
+    let mut errors = 0;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        Decoder::new(bytes).decode_events(|event| match event {
+            DecodeEvent::Message {
+                message: DecodedMessage::Data { name, record, .. },
+                ..
+            } => messages.entry(name).or_default().push(record),
+            DecodeEvent::Message { .. } => {}
+            DecodeEvent::Error { .. } => errors += 1,
+        })
+    }));
+    let error = match result {
+        Ok(result) => result.err().map(|it| it.to_string()),
+        Err(payload) => {
+            let reason = payload
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| payload.downcast_ref::<&str>().map(|it| it.to_string()))
+                .unwrap_or_default();
+            Some(format!("decoder panicked: {reason}"))
+        }
+    };
+
+    let first = |name: &str| messages.get(name).and_then(|it| it.first());
+    let sessions = messages
+        .get("session")
+        .map(|it| it.as_slice())
+        .unwrap_or_default();
+    let sum = |name: &str| {
+        sessions
+            .iter()
+            .filter_map(|it| number(it, name))
+            .reduce(|a, b| a + b)
+    };
+    let mut sports = Vec::new();
+    for sport in sessions.iter().filter_map(|it| text(it, "sport")) {
+        if !sports.contains(&sport) {
+            sports.push(sport);
+        }
+    }
+    let file_id = first("file_id");
+    CatalogEntry {
+        path,
+        file_type: file_id.and_then(|it| text(it, "type")),
+        time_created: file_id.and_then(|it| date_time(it, "time_created")),
+        sport: match sports.is_empty() {
+            true => first("sport").and_then(|it| text(it, "sport")),
+            false => Some(sports.join(",")),
+        },
+        duration: sum("total_timer_time")
+            .or_else(|| first("activity").and_then(|it| number(it, "total_timer_time"))),
+        distance: sum("total_distance"),
+        device: file_id.and_then(|it| device(it, first("device_info"))),
+        errors,
+        error,
+    }
+}
+
+fn device(file_id: &Record, device_info: Option<&Record>) -> Option<String> {
+    file_id
+        .get("product_name")
+        .or_else(|| device_info?.get("product_name"))
+        .map(|it| it.to_string())
+        .filter(|it| !it.is_empty())
+        .or_else(|| {
+            let manufacturer = text(file_id, "manufacturer")?;
+            let product = file_id
+                .get("garmin_product")
+                .or_else(|| file_id.get("product"));
+            match product {
+                Some(product) => Some(format!("{manufacturer} {product}")),
+                None => Some(manufacturer),
+            }
+        })
+}
+
+/// Symbolic links to directories are not followed, they may form cycles
+fn find_files(
+    dir: &Path,
+    limit: u64,
+    archives: &mut Vec<ArchiveData>,
+    sources: &mut Vec<(String, Source)>,
+) {
+    let display = dir.to_string_lossy().into_owned();
+    let mut paths = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|it| it.ok())
+            .map(|it| (it.path(), it.file_type().is_ok_and(|it| it.is_dir())))
+            .collect::<Vec<_>>(),
+        Err(error) => return sources.push((display, Source::Failed(error.to_string()))),
+    };
+    paths.sort();
+    for (path, is_dir) in paths {
+        let name = path.to_string_lossy().into_owned();
+        if is_dir {
+            find_files(&path, limit, archives, sources);
+        } else if has_extension(&name, "fit") {
+            sources.push((name, Source::File(path)));
+        } else if has_extension(&name, "zip") {
+            find_entries(&name, ArchiveData::Path(path), limit, archives, sources);
+        }
+    }
+}
+
+fn find_entries(
+    name: &str,
+    data: ArchiveData,
+    limit: u64,
+    archives: &mut Vec<ArchiveData>,
+    sources: &mut Vec<(String, Source)>,
+) {
+    let mut archive = match open(&data) {
+        Ok(archive) => archive,
+        Err(error) => return sources.push((name.to_string(), Source::Failed(error))),
+    };
+    let index = archives.len();
+    archives.push(data);
+    for entry in 0..archive.len() {
+        let Some(entry_name) = archive.name_for_index(entry).map(|it| it.to_string()) else {
+            continue;
+        };
+        let path = format!("{name}/{entry_name}");
+        if has_extension(&entry_name, "fit") {
+            sources.push((
+                path,
+                Source::Entry {
+                    archive: index,
+                    index: entry,
+                },
+            ));
+        } else if has_extension(&entry_name, "zip") {
+            match read_entry(&mut archive, entry, limit) {
+                Ok(bytes) => find_entries(
+                    &path,
+                    ArchiveData::Bytes(bytes.into()),
+                    limit,
+                    archives,
+                    sources,
+                ),
+                Err(error) => sources.push((path, Source::Failed(error))),
+            }
+        }
+    }
+}
+
+fn read_source(
+    source: &Source,
+    archives: &[ArchiveData],
+    limit: u64,
+    opened: &mut HashMap<usize, ZipArchive<Box<dyn ReadSeek>>>,
+) -> Result<Vec<u8>, String> {
+    match source {
+        Source::File(path) => fs::read(path).map_err(|it| it.to_string()),
+        Source::Entry { archive, index } => {
+            let zip = match opened.entry(*archive) {
+                Entry::Occupied(it) => it.into_mut(),
+                Entry::Vacant(it) => it.insert(open(&archives[*archive])?),
+            };
+            read_entry(zip, *index, limit)
+        }
+        Source::Failed(error) => Err(error.clone()),
+    }
+}
+
+fn open(data: &ArchiveData) -> Result<ZipArchive<Box<dyn ReadSeek>>, String> {
+    let reader: Box<dyn ReadSeek> = match data {
+        ArchiveData::Path(path) => Box::new(File::open(path).map_err(|it| it.to_string())?),
+        ArchiveData::Bytes(bytes) => Box::new(Cursor::new(bytes.clone())),
+    };
+    ZipArchive::new(reader).map_err(|it| it.to_string())
+}
+
+/// Read an entry of at most `limit` bytes, the size in the archive is not trusted
+fn read_entry<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    index: usize,
+    limit: u64,
+) -> Result<Vec<u8>, String> {
+    let too_large = || format!("entry larger than {limit} bytes");
+    let file = archive.by_index(index).map_err(|it| it.to_string())?;
+    if file.size() > limit {
+        return Err(too_large());
+    }
+    let mut bytes = Vec::with_capacity(file.size() as usize);
+    file.take(limit + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|it| it.to_string())?;
+    if bytes.len() as u64 > limit {
+        return Err(too_large());
+    }
+    Ok(bytes)
+}
+
+fn has_extension(name: &str, extension: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .is_some_and(|it| it.eq_ignore_ascii_case(extension))
+}
+
+fn serialize_time<S: Serializer>(
+    time: &Option<DateTime<Utc>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match time {
+        Some(time) => serializer.serialize_str(&rfc3339(time)),
+        None => serializer.serialize_none(),
+    }
+}
@@ -1,8 +1,12 @@
+pub mod hex_dump;
+
 use crate::byte_reader::ByteReader;
 use crate::crc;
 use crate::error::{ErrorKind, ParserResult};
 use crate::fit;
+use indexmap::IndexMap;
 use std::collections::HashMap;
+use std::ops::Range;
 use std::sync::Arc;
 
 macro_rules! fit_value_covert {
@@ -19,9 +23,9 @@ macro_rules! fit_value_covert {
     };
 }
 
-#[derive(Debug, Clone)]
-#[allow(unused)]
-struct FitFileHeader {
+/// File header of a FIT file, `header_crc` is 0 for 12 byte headers
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitFileHeader {
     pub header_size: u32,
     pub protocol_version: u8,
     pub profile_version: u16,
@@ -30,8 +34,23 @@ struct FitFileHeader {
     pub header_crc: u16,
 }
 
+impl FitFileHeader {
+    /// Profile version as `major.minor`, stored as `major * 100 + minor` or, for minor
+    /// versions above 99, as `major * 1000 + minor`, e.g. `2194` is `21.94` and `21126` is
+    /// `21.126`
+    pub fn profile_version_string(&self) -> String {
+        let version = self.profile_version;
+        if version >= 10000 {
+            format!("{}.{:02}", version / 1000, version % 1000)
+        } else {
+            format!("{}.{:02}", version / 100, version % 100)
+        }
+    }
+}
+
+/// Byte order of the data messages of a definition
 #[derive(Debug, Clone, Copy, PartialEq)]
-enum Architecture {
+pub enum Architecture {
     LittleEndian,
     BigEndian,
 }
@@ -67,35 +86,53 @@ enum FitMessage {
     Data(FitDataMessage),
     Definition(FitDefinitionMessage),
 }
-#[derive(Debug, Clone)]
-struct FieldDefinition {
-    field_definition_number: u8,
-    size: u8,
-    base_type: fit::BaseType,
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDefinition {
+    pub field_definition_number: u8,
+    /// Size in bytes, a multiple of the base type size for arrays
+    pub size: u8,
+    pub base_type: fit::BaseType,
 }
-#[derive(Debug, Clone)]
-#[allow(unused)]
-struct DeveloperFieldDefinition {
-    field_number: u8,
-    size: u8,
-    developer_data_index: u8,
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeveloperFieldDefinition {
+    pub field_number: u8,
+    pub size: u8,
+    pub developer_data_index: u8,
 }
 
-#[derive(Debug, Clone)]
-struct FitDefinitionMessage {
-    architecture: Architecture,
-    local_message_number: u8,
-    global_message_number: u16,
-    field_definitions: Vec<FieldDefinition>,
-    developer_field_definitions: Vec<DeveloperFieldDefinition>,
+/// Layout of the data messages sent with a local message number
+#[derive(Debug, Clone, PartialEq)]
+pub struct FitDefinitionMessage {
+    pub architecture: Architecture,
+    pub local_message_number: u8,
+    pub global_message_number: u16,
+    pub field_definitions: Vec<FieldDefinition>,
+    pub developer_field_definitions: Vec<DeveloperFieldDefinition>,
+}
+
+/// A part of a message passed to the `on_read` callback of the `read_*_with` functions,
+/// used by [`hex_dump`] to annotate the bytes it was read from
+enum ReadPart<'a> {
+    /// Reserved byte, architecture, global message number and number of fields
+    DefinitionHeader {
+        architecture: Architecture,
+        global_message_number: u16,
+        number_of_fields: u8,
+    },
+    FieldDefinition(&'a FieldDefinition),
+    DeveloperFieldCount(u8),
+    DeveloperFieldDefinition(&'a DeveloperFieldDefinition),
+    Field(&'a FieldDefinition, &'a ParserResult<fit::Value>),
+    DeveloperField(&'a DeveloperFieldDefinition, &'a ParserResult<fit::Value>),
 }
 
 #[derive(Debug, Clone)]
 struct FitDataMessage {
+    local_message_number: u8,
     global_message_number: u16,
     time_offset: Option<u8>,
-    // HashMap<field_definition_number, Value>
-    fields: HashMap<u8, fit::Value>,
+    // IndexMap<field_definition_number, Value>, in definition order
+    fields: IndexMap<u8, fit::Value>,
     // HashMap<developer_data_index, HashMap<field_definition_number, Value>>
     developer_fields: HashMap<u8, HashMap<u8, fit::Value>>,
 }
@@ -108,7 +145,20 @@ struct FitDeveloperDataDefinition {
     manufacturer_id: Option<fit::Value>,
     developer_data_index: u8,
     application_version: u32,
-    field_map: HashMap<u8, HashMap<&'static str, fit::Value>>,
+    field_map: HashMap<u8, Record>,
+}
+
+/// A UTC instant paired with the device system time it was observed at, both in seconds
+#[derive(Debug, Clone, Copy)]
+struct TimestampCorrelation {
+    utc: f64,
+    system: f64,
+}
+
+impl TimestampCorrelation {
+    fn to_utc(self, system: f64) -> f64 {
+        self.utc + (system - self.system)
+    }
 }
 
 const CRC_SIZE: u32 = 2;
@@ -120,12 +170,46 @@ pub struct Decoder<'input> {
     dev_data_defs: HashMap<u8, FitDeveloperDataDefinition>,
     dev_data: HashMap<String, HashMap<u8, fit::Value>>,
     timestamp_ref: Option<u32>,
+    timestamp_correlation: Option<TimestampCorrelation>,
     errors: Vec<ErrorKind>,
 }
 
-pub type Record = HashMap<&'static str, fit::Value>;
+/// Fields of a message in definition order, followed by expanded and supplementary fields
+pub type Record = IndexMap<&'static str, fit::Value>;
 
-pub type Messages = HashMap<String, Vec<Record>>;
+/// Records grouped by message type, in order of first appearance
+pub type Messages = IndexMap<String, Vec<Record>>;
+
+/// A message or a recoverable error, as read by [`Decoder::decode_events`]
+///
+/// `offset` is the byte offset of the message header in the input.
+#[derive(Debug, Clone)]
+pub enum DecodeEvent {
+    Message {
+        offset: usize,
+        message: DecodedMessage,
+    },
+    /// An error found while reading the message at `offset`, which may have been skipped
+    Error { offset: usize, error: ErrorKind },
+}
+
+/// A file of a chained FIT file, as read by [`Decoder::decode_files`]
+#[derive(Debug, Clone)]
+pub struct DecodedFile {
+    pub header: FitFileHeader,
+    pub messages: Messages,
+}
+
+/// A message in file order, as read by [`Decoder::decode_messages`]
+#[derive(Debug, Clone)]
+pub enum DecodedMessage {
+    Definition(FitDefinitionMessage),
+    Data {
+        local_message_number: u8,
+        name: String,
+        record: Record,
+    },
+}
 
 impl<'input> Decoder<'input> {
     pub fn new(bytes: &'input [u8]) -> Self {
@@ -136,6 +220,7 @@ impl<'input> Decoder<'input> {
             dev_data: HashMap::new(),
             errors: Vec::new(),
             timestamp_ref: None,
+            timestamp_correlation: None,
         }
     }
     fn read_file_header(&mut self) -> FitFileHeader {
@@ -201,14 +286,63 @@ impl<'input> Decoder<'input> {
     }
     /// 阅读信息
     pub fn decode(&mut self) -> ParserResult<(Vec<ErrorKind>, Messages)> {
+        let mut messages: Messages = IndexMap::new();
+        self.decode_with(|event| {
+            if let DecodeEvent::Message {
+                message: DecodedMessage::Data { name, record, .. },
+                ..
+            } = event
+            {
+                messages.entry(name).or_default().push(record);
+            }
+        })?;
+        Ok((self.errors.to_owned(), messages))
+    }
+    /// Read all messages in file order, definition messages included
+    pub fn decode_messages(&mut self) -> ParserResult<(Vec<ErrorKind>, Vec<DecodedMessage>)> {
+        let mut messages = Vec::new();
+        self.decode_with(|event| {
+            if let DecodeEvent::Message { message, .. } = event {
+                messages.push(message);
+            }
+        })?;
+        Ok((self.errors.to_owned(), messages))
+    }
+    /// Read all messages in file order, passing each message and recoverable error to the sink
+    /// as soon as it is read
+    pub fn decode_events(&mut self, sink: impl FnMut(DecodeEvent)) -> ParserResult<()> {
+        self.decode_with(sink)
+    }
+    /// Read the messages of each file of a chained FIT file separately
+    pub fn decode_files(&mut self) -> ParserResult<(Vec<ErrorKind>, Vec<DecodedFile>)> {
+        let mut files = Vec::new();
         self.reader.reset();
-        let mut messages: Messages = HashMap::new();
         while !self.reader.is_end() {
-            self.decode_next_file(&mut messages)?;
+            let mut messages: Messages = IndexMap::new();
+            let header = self.decode_next_file(&mut |event| {
+                if let DecodeEvent::Message {
+                    message: DecodedMessage::Data { name, record, .. },
+                    ..
+                } = event
+                {
+                    messages.entry(name).or_default().push(record);
+                }
+            })?;
+            files.push(DecodedFile { header, messages });
         }
-        Ok((self.errors.to_owned(), messages))
+        Ok((self.errors.to_owned(), files))
     }
-    fn decode_next_file(&mut self, messages: &mut Messages) -> ParserResult<()> {
+    fn decode_with(&mut self, mut sink: impl FnMut(DecodeEvent)) -> ParserResult<()> {
+        self.reader.reset();
+        while !self.reader.is_end() {
+            self.decode_next_file(&mut sink)?;
+        }
+        Ok(())
+    }
+    fn decode_next_file(
+        &mut self,
+        sink: &mut impl FnMut(DecodeEvent),
+    ) -> ParserResult<FitFileHeader> {
         let start = self.reader.offset();
         if !Decoder::is_fit(&self.reader[start..]) {
             return Err(ErrorKind::InvalidFitFile);
@@ -216,35 +350,47 @@ impl<'input> Decoder<'input> {
         let header = self.read_file_header();
         let end = start + header.header_size as usize + header.data_size as usize;
         while self.reader.offset() < end {
-            match self.decode_next_record() {
-                Ok(Some((k, v))) => {
-                    messages.entry(k).or_default().push(v);
-                }
-                Ok(None) => continue,
-                Err(e) => return Err(e),
+            let offset = self.reader.offset();
+            let errors = self.errors.len();
+            if let Some(message) = self.decode_next_record()? {
+                sink(DecodeEvent::Message { offset, message });
+            }
+            for error in &self.errors[errors..] {
+                sink(DecodeEvent::Error {
+                    offset,
+                    error: error.clone(),
+                });
             }
         }
+        self.reader.ensure(CRC_SIZE as usize)?;
         if !self.crc_valid(start, end) {
             return Err(ErrorKind::InvalidCRC);
         }
-        Ok(())
+        Ok(header)
     }
 
-    fn decode_next_record(&mut self) -> ParserResult<Option<(String, Record)>> {
+    fn decode_next_record(&mut self) -> ParserResult<Option<DecodedMessage>> {
         let message = self.read_message()?;
         match message {
             FitMessage::Definition(message) => {
                 self.defs
-                    .insert(message.local_message_number, Arc::new(message));
-                Ok(None)
+                    .insert(message.local_message_number, Arc::new(message.clone()));
+                Ok(Some(DecodedMessage::Definition(message)))
             }
-            FitMessage::Data(message) => match self.decode_message(message) {
-                Ok((k, v)) => Ok(Some((k, v))),
-                Err(e) => {
-                    self.errors.push(e);
-                    Ok(None)
+            FitMessage::Data(message) => {
+                let local_message_number = message.local_message_number;
+                match self.decode_message(message) {
+                    Ok((name, record)) => Ok(Some(DecodedMessage::Data {
+                        local_message_number,
+                        name,
+                        record,
+                    })),
+                    Err(e) => {
+                        self.errors.push(e);
+                        Ok(None)
+                    }
                 }
-            },
+            }
         }
     }
 
@@ -264,6 +410,7 @@ impl<'input> Decoder<'input> {
     /// - bit 6..5: local message type
     /// - bit 4..0: time offset
     fn read_message_header(&mut self) -> ParserResult<FitMessageHeader> {
+        self.reader.ensure(1)?;
         let byte = self.reader.read_next_u8();
         if byte & 0x80 == 0x80 {
             // compressed timestamp header
@@ -303,6 +450,15 @@ impl<'input> Decoder<'input> {
         })
     }
     fn read_data_message(&mut self, header: &FitMessageHeader) -> ParserResult<FitDataMessage> {
+        self.read_data_message_with(header, |_, _| {})
+    }
+    /// Read a data message, passing each field with the byte range it was read from to
+    /// `on_read`
+    fn read_data_message_with(
+        &mut self,
+        header: &FitMessageHeader,
+        mut on_read: impl FnMut(Range<usize>, ReadPart<'_>),
+    ) -> ParserResult<FitDataMessage> {
         let def = self
             .defs
             .get(&header.local_message_number)
@@ -310,14 +466,31 @@ impl<'input> Decoder<'input> {
                 header.local_message_number,
             ))?
             .clone();
+        let size = def
+            .field_definitions
+            .iter()
+            .map(|it| it.size as usize)
+            .chain(
+                def.developer_field_definitions
+                    .iter()
+                    .map(|it| it.size as usize),
+            )
+            .sum();
+        self.reader.ensure(size)?;
 
-        let mut fields = HashMap::new();
+        let mut fields = IndexMap::new();
         for field_def in &def.field_definitions {
-            match self.read_field_value(
+            let start = self.reader.offset();
+            let value = self.read_field_value(
                 field_def.size as usize,
                 field_def.base_type,
                 def.architecture.is_big_endian(),
-            ) {
+            );
+            on_read(
+                start..start + field_def.size as usize,
+                ReadPart::Field(field_def, &value),
+            );
+            match value {
                 Ok(value) => {
                     fields.insert(field_def.field_definition_number, value);
                 }
@@ -335,11 +508,17 @@ impl<'input> Decoder<'input> {
         let mut developer_fields = HashMap::new();
 
         for field_def in &def.developer_field_definitions {
-            let value = match self.read_field_value(
+            let start = self.reader.offset();
+            let value = self.read_field_value(
                 field_def.size as usize,
                 fit::BaseType::Byte,
                 def.architecture.is_big_endian(),
-            ) {
+            );
+            on_read(
+                start..start + field_def.size as usize,
+                ReadPart::DeveloperField(field_def, &value),
+            );
+            let value = match value {
                 Ok(v) => v,
                 Err(e) => {
                     self.errors.push(ErrorKind::DecodeDeveloperFieldFailed {
@@ -356,6 +535,7 @@ impl<'input> Decoder<'input> {
                 .insert(field_def.field_number, value);
         }
         Ok(FitDataMessage {
+            local_message_number: header.local_message_number,
             fields,
             developer_fields,
             global_message_number: def.global_message_number,
@@ -369,7 +549,7 @@ impl<'input> Decoder<'input> {
         is_big_endian: bool,
     ) -> ParserResult<fit::Value> {
         use fit::{BaseType, Value};
-        if size % base_type.size() as usize != 0 {
+        if !size.is_multiple_of(base_type.size() as usize) {
             return Err(ErrorKind::SizeMismatch {
                 field_size: size,
                 base_type_size: base_type.size(),
@@ -388,15 +568,12 @@ impl<'input> Decoder<'input> {
                 BaseType::SInt32 => Value::SInt32(reader.read_next_i32(is_big_endian)),
                 BaseType::UInt32 => Value::UInt32(reader.read_next_u32(is_big_endian)),
                 BaseType::String => {
-                    let bytes = reader.read_bytes(size);
-                    let mut new_bytes = Vec::new();
-                    for byte in bytes {
-                        if byte == &0u8 {
-                            break;
-                        }
-                        new_bytes.push(*byte)
+                    let offset = reader.offset();
+                    let (value, is_lossy) = decode_strings(reader.read_bytes(size));
+                    if is_lossy {
+                        self.errors.push(ErrorKind::InvalidUtf8String { offset });
                     }
-                    Value::String(String::from_utf8(new_bytes).unwrap())
+                    value
                 }
                 BaseType::Float32 => Value::Float32(reader.read_next_f32(is_big_endian)),
                 BaseType::Float64 => Value::Float64(reader.read_next_f64(is_big_endian)),
@@ -463,14 +640,35 @@ impl<'input> Decoder<'input> {
         &mut self,
         header: &FitMessageHeader,
     ) -> ParserResult<FitDefinitionMessage> {
+        self.read_definition_message_with(header, |_, _| {})
+    }
+    /// Read a definition message, passing each part with the byte range it was read from to
+    /// `on_read`
+    fn read_definition_message_with(
+        &mut self,
+        header: &FitMessageHeader,
+        mut on_read: impl FnMut(Range<usize>, ReadPart<'_>),
+    ) -> ParserResult<FitDefinitionMessage> {
+        let start = self.reader.offset();
+        self.reader.ensure(5)?;
         // Consume reserved byte
         self.reader.read_next_u8();
         let architecture = Architecture::from(self.reader.read_next_u8());
         let global_message_number = self.reader.read_next_u16(architecture.is_big_endian());
+        let number_of_fields = self.reader.read_next_u8();
+        self.reader.ensure(3 * number_of_fields as usize)?;
+        on_read(
+            start..self.reader.offset(),
+            ReadPart::DefinitionHeader {
+                architecture,
+                global_message_number,
+                number_of_fields,
+            },
+        );
         let field_definitions = {
             let mut definitions = Vec::new();
-            let number_of_fields = self.reader.read_next_u8();
             for _ in 0..number_of_fields {
+                let start = self.reader.offset();
                 let field_definition_number = self.reader.read_next_u8();
                 let size = self.reader.read_next_u8();
                 let base_type =
@@ -479,26 +677,44 @@ impl<'input> Decoder<'input> {
                             reason: err.to_owned(),
                         }
                     })?;
-                definitions.push(FieldDefinition {
+                let definition = FieldDefinition {
                     field_definition_number,
                     size,
                     base_type,
-                });
+                };
+                on_read(
+                    start..self.reader.offset(),
+                    ReadPart::FieldDefinition(&definition),
+                );
+                definitions.push(definition);
             }
             definitions
         };
         let developer_field_definitions = if header.contains_developer_data {
             let mut definitions = Vec::new();
+            self.reader.ensure(1)?;
+            let start = self.reader.offset();
             let number_of_fields = self.reader.read_next_u8();
+            self.reader.ensure(3 * number_of_fields as usize)?;
+            on_read(
+                start..self.reader.offset(),
+                ReadPart::DeveloperFieldCount(number_of_fields),
+            );
             for _ in 0..number_of_fields {
+                let start = self.reader.offset();
                 let field_number = self.reader.read_next_u8();
                 let size = self.reader.read_next_u8();
                 let developer_data_index = self.reader.read_next_u8();
-                definitions.push(DeveloperFieldDefinition {
+                let definition = DeveloperFieldDefinition {
                     field_number,
                     size,
                     developer_data_index,
-                });
+                };
+                on_read(
+                    start..self.reader.offset(),
+                    ReadPart::DeveloperFieldDefinition(&definition),
+                );
+                definitions.push(definition);
             }
             definitions
         } else {
@@ -514,6 +730,15 @@ impl<'input> Decoder<'input> {
         })
     }
     fn decode_message(&mut self, message: FitDataMessage) -> ParserResult<(String, Record)> {
+        self.decode_message_with(message, |_, _| {})
+    }
+    /// Decode a data message, passing the field number and the name of each value decoded from
+    /// a field, including subfields and components, to `on_field`
+    fn decode_message_with(
+        &mut self,
+        message: FitDataMessage,
+        mut on_field: impl FnMut(u8, &'static str),
+    ) -> ParserResult<(String, Record)> {
         use crate::profile::{messages, types};
         let mut accumulator = crate::accumulator::Accumulator::default();
         let msg_ty = types::MesgNum::try_from(&fit::Value::UInt16(message.global_message_number))
@@ -522,10 +747,12 @@ impl<'input> Decoder<'input> {
         })?;
         let decode = messages::from_message_type(&msg_ty.to_string())
             .ok_or(ErrorKind::UnknownMessage(msg_ty.to_string()))?;
-        let mut message_map = HashMap::new();
+        let mut message_map = IndexMap::new();
         for (field_def_number, val) in message.fields.iter() {
+            // decoded on its own, components may overwrite values of other fields
+            let mut decoded = IndexMap::new();
             if let Err(e) = decode(
-                &mut message_map,
+                &mut decoded,
                 &mut accumulator,
                 messages::MessageDecodeArgs {
                     msg_ty: &msg_ty,
@@ -542,6 +769,10 @@ impl<'input> Decoder<'input> {
             }) {
                 self.errors.push(e)
             };
+            for (name, value) in decoded {
+                on_field(*field_def_number, name);
+                message_map.insert(name, value);
+            }
 
             // common timestamp field, used in combination with the compressed timestamp
             if field_def_number == &253 {
@@ -587,14 +818,12 @@ impl<'input> Decoder<'input> {
                     .insert(field_def_number, value);
             }
         }
-        if message.time_offset.is_some() {
-            let result = self
-                .update_time_offset(message.time_offset.unwrap())
-                .and_then(|timestamp| {
-                    chrono::DateTime::<chrono::Utc>::from_timestamp(timestamp as i64, 0)
-                        .ok_or(ErrorKind::InvalidTimestamp { timestamp })
-                        .map(fit::Value::DateTime)
-                });
+        if let Some(time_offset) = message.time_offset {
+            let result = self.update_time_offset(time_offset).and_then(|timestamp| {
+                chrono::DateTime::<chrono::Utc>::from_timestamp(timestamp as i64, 0)
+                    .ok_or(ErrorKind::InvalidTimestamp { timestamp })
+                    .map(fit::Value::DateTime)
+            });
             match result {
                 Ok(value) => {
                     message_map.insert(
@@ -610,6 +839,11 @@ impl<'input> Decoder<'input> {
                 Err(err) => self.errors.push(err),
             };
         }
+        if msg_ty == types::MesgNum::TimestampCorrelation {
+            self.update_timestamp_correlation(&message_map);
+        } else {
+            self.correlate_timestamps(&mut message_map);
+        }
         if msg_ty == types::MesgNum::DeveloperDataId {
             let developer_data_map = message_map
                 .into_iter()
@@ -622,9 +856,13 @@ impl<'input> Decoder<'input> {
                                 .to_string(),
                         ),
                     ),
+                    "developer_id" | "application_id" => {
+                        let uuid = field.value.to_bytes().and_then(|it| fit::to_uuid(&it));
+                        (name, uuid.map(fit::Value::String).unwrap_or(field.value))
+                    }
                     _ => (name, field.value),
                 })
-                .collect::<HashMap<_, _>>();
+                .collect::<Record>();
             let developer_data_index =
                 fit_value_covert!(developer_data_map.get("developer_data_index"), UInt8);
             self.dev_data_defs.insert(
@@ -690,7 +928,7 @@ impl<'input> Decoder<'input> {
                     ),
                     _ => (name, field.value),
                 })
-                .collect::<HashMap<_, _>>();
+                .collect::<Record>();
             let developer_data_index =
                 fit_value_covert!(field_description_map.get("developer_data_index"), UInt8);
             let field_definition_number =
@@ -706,10 +944,102 @@ impl<'input> Decoder<'input> {
                 message_map
                     .into_iter()
                     .map(|(k, v)| (k, v.value))
-                    .collect::<HashMap<_, _>>(),
+                    .collect::<Record>(),
             ))
         }
     }
+    /// Keep the most recent `timestamp_correlation`, used to move system time onto the UTC timeline
+    fn update_timestamp_correlation(
+        &mut self,
+        message_map: &IndexMap<&'static str, crate::profile::messages::Field>,
+    ) {
+        let seconds = |name: &str, fractional: &str, millis: &str| {
+            let whole = match message_map.get(name).map(|it| &it.value) {
+                Some(fit::Value::DateTime(val)) => val.timestamp() - fit::FIT_EPOCH_OFFSET,
+                _ => return None,
+            };
+            let fraction = message_map
+                .get(fractional)
+                .and_then(|it| it.value.try_as_f64().ok())
+                .or_else(|| {
+                    message_map
+                        .get(millis)
+                        .and_then(|it| it.value.try_as_f64().ok())
+                        .map(|it| it / 1000.0)
+                })
+                .unwrap_or(0.0);
+            Some(whole as f64 + fraction)
+        };
+        if let (Some(utc), Some(system)) = (
+            seconds("timestamp", "fractional_timestamp", "timestamp_ms"),
+            seconds(
+                "system_timestamp",
+                "fractional_system_timestamp",
+                "system_timestamp_ms",
+            ),
+        ) {
+            self.timestamp_correlation = Some(TimestampCorrelation { utc, system });
+        }
+    }
+    /// Attach `utc_timestamp` to messages timed by `system_time` or a system time `timestamp`
+    ///
+    /// System time is converted through the most recent `timestamp_correlation`, messages are
+    /// left untouched when no correlation has been seen yet or they are already timed in UTC.
+    fn correlate_timestamps(
+        &self,
+        message_map: &mut IndexMap<&'static str, crate::profile::messages::Field>,
+    ) {
+        let correlation = match self.timestamp_correlation {
+            Some(correlation) if !message_map.contains_key("utc_timestamp") => correlation,
+            _ => return,
+        };
+        let to_date_time = |seconds: f64| {
+            chrono::DateTime::<chrono::Utc>::from_timestamp_millis(
+                ((correlation.to_utc(seconds) + fit::FIT_EPOCH_OFFSET as f64) * 1000.0).round()
+                    as i64,
+            )
+            .map(fit::Value::DateTime)
+        };
+        let value = if let Some(system_time) = message_map.get("system_time") {
+            // `system_time` is in milliseconds
+            let convert = |value: &fit::Value| {
+                value
+                    .try_as_f64()
+                    .ok()
+                    .and_then(|ms| to_date_time(ms / 1000.0))
+            };
+            match &system_time.value {
+                fit::Value::Array(arr) => {
+                    Some(fit::Value::Array(arr.iter().filter_map(convert).collect()))
+                }
+                value => convert(value),
+            }
+        } else {
+            let timestamp = match message_map.get("timestamp").map(|it| &it.value) {
+                Some(fit::Value::DateTime(val)) => val.timestamp() - fit::FIT_EPOCH_OFFSET,
+                _ => return,
+            };
+            if timestamp >= fit::SYSTEM_TIME_MAX as i64 {
+                return;
+            }
+            let timestamp_ms = message_map
+                .get("timestamp_ms")
+                .and_then(|it| it.value.try_as_f64().ok())
+                .unwrap_or(0.0);
+            to_date_time(timestamp as f64 + timestamp_ms / 1000.0)
+        };
+        if let Some(value) = value {
+            message_map.insert(
+                "utc_timestamp",
+                crate::profile::messages::Field {
+                    name: "utc_timestamp",
+                    value,
+                    units: "",
+                    is_subfield: false,
+                },
+            );
+        }
+    }
     fn update_time_offset(&mut self, offset: u8) -> ParserResult<u32> {
         let previous = if let Some(previous) = self.timestamp_ref {
             previous
@@ -726,3 +1056,27 @@ impl<'input> Decoder<'input> {
         Ok(next)
     }
 }
+
+/// Split a string field on its null terminators, dropping the trailing padding
+///
+/// Returns the decoded value and whether invalid UTF-8 was replaced.
+fn decode_strings(bytes: &[u8]) -> (fit::Value, bool) {
+    let mut is_lossy = false;
+    let mut strings = bytes
+        .split(|byte| *byte == 0)
+        .map(|it| {
+            let string = String::from_utf8_lossy(it);
+            is_lossy |= matches!(string, std::borrow::Cow::Owned(_));
+            string.into_owned()
+        })
+        .collect::<Vec<_>>();
+    while strings.len() > 1 && strings.last().is_some_and(|it| it.is_empty()) {
+        strings.pop();
+    }
+    let value = if strings.len() == 1 {
+        fit::Value::String(strings.swap_remove(0))
+    } else {
+        fit::Value::Array(strings.into_iter().map(fit::Value::String).collect())
+    };
+    (value, is_lossy)
+}
@@ -0,0 +1,454 @@
+//! Byte-level view of a FIT file for debugging encoders and device firmware
+//!
+//! [`annotate`] reads the file the same way as the [`Decoder`], recording for each byte range
+//! what it was read as: file headers, message header bytes, the field triples of definition
+//! messages, the raw value of each data field next to the values decoded from it, and CRCs.
+//! The [`fmt::Display`] implementation renders a hex dump with these annotations.
+
+use super::{
+    Architecture, Decoder, DeveloperFieldDefinition, FieldDefinition, FitFileHeader,
+    FitMessageHeader, FitMessageType, ReadPart,
+};
+use crate::crc;
+use crate::error::ErrorKind;
+use crate::fit;
+use crate::profile::{messages, types};
+use std::fmt;
+use std::sync::Arc;
+
+/// Bytes shown per line of the dump
+const BYTES_PER_LINE: usize = 8;
+
+/// A byte range of the input and what it was read as
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span<'input> {
+    pub offset: usize,
+    pub bytes: &'input [u8],
+    pub item: Item,
+}
+
+/// Meaning of a message header byte
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageHeader {
+    pub definition: bool,
+    pub local_message_number: u8,
+    /// Definition message followed by developer field definitions
+    pub developer_data: bool,
+    /// Time offset of a compressed timestamp header, `None` for normal headers
+    pub time_offset: Option<u8>,
+}
+
+/// A data message field as read, and the values decoded from it
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataField {
+    pub number: u8,
+    pub name: Option<&'static str>,
+    pub base_type: fit::BaseType,
+    /// The raw value, or why it was skipped, e.g. an invalid value
+    pub value: Result<fit::Value, ErrorKind>,
+    /// Values of the message decoded from this field, the field itself, subfields or components
+    pub decoded: Vec<(&'static str, fit::Value)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    FileHeader(FitFileHeader),
+    MessageHeader(MessageHeader),
+    /// Reserved byte, architecture, global message number and number of fields
+    DefinitionHeader {
+        architecture: Architecture,
+        global_message_number: u16,
+        message: Option<String>,
+        number_of_fields: u8,
+    },
+    FieldDefinition {
+        definition: FieldDefinition,
+        name: Option<&'static str>,
+    },
+    DeveloperFieldCount(u8),
+    DeveloperFieldDefinition(DeveloperFieldDefinition),
+    Field(DataField),
+    DeveloperField {
+        definition: DeveloperFieldDefinition,
+        value: Result<fit::Value, ErrorKind>,
+    },
+    Crc {
+        value: u16,
+        expected: u16,
+    },
+}
+
+/// Annotated byte ranges of a FIT file, in file order
+#[derive(Debug, Clone, PartialEq)]
+pub struct HexDump<'input> {
+    pub spans: Vec<Span<'input>>,
+    /// Recoverable errors, with the offset of the message they were found in
+    pub errors: Vec<(usize, ErrorKind)>,
+    /// Error that stopped reading, the bytes after the last span are not annotated
+    pub fatal: Option<ErrorKind>,
+}
+
+/// Read every file of the input, annotating each byte range
+pub fn annotate(bytes: &[u8]) -> HexDump<'_> {
+    let mut dump = HexDump {
+        spans: Vec::new(),
+        errors: Vec::new(),
+        fatal: None,
+    };
+    let mut decoder = Decoder::new(bytes);
+    while !decoder.reader.is_end() {
+        if let Err(err) = annotate_file(&mut decoder, bytes, &mut dump) {
+            dump.fatal = Some(err);
+            break;
+        }
+    }
+    dump
+}
+
+fn annotate_file<'input>(
+    decoder: &mut Decoder<'input>,
+    bytes: &'input [u8],
+    dump: &mut HexDump<'input>,
+) -> Result<(), ErrorKind> {
+    let start = decoder.reader.offset();
+    if !Decoder::is_fit(&bytes[start..]) {
+        return Err(ErrorKind::InvalidFitFile);
+    }
+    let header = decoder.read_file_header();
+    let end = start + header.header_size as usize + header.data_size as usize;
+    push(
+        dump,
+        bytes,
+        start,
+        decoder.reader.offset(),
+        Item::FileHeader(header),
+    );
+    while decoder.reader.offset() < end {
+        let offset = decoder.reader.offset();
+        let errors = decoder.errors.len();
+        let header = decoder.read_message_header()?;
+        push(
+            dump,
+            bytes,
+            offset,
+            offset + 1,
+            Item::MessageHeader(MessageHeader {
+                definition: header.message_type == FitMessageType::Definition,
+                local_message_number: header.local_message_number,
+                developer_data: header.contains_developer_data,
+                time_offset: header.time_offset,
+            }),
+        );
+        match header.message_type {
+            FitMessageType::Definition => annotate_definition(decoder, bytes, &header, dump)?,
+            FitMessageType::Data => annotate_data(decoder, bytes, &header, dump)?,
+        }
+        dump.errors.extend(
+            decoder.errors[errors..]
+                .iter()
+                .map(|error| (offset, error.clone())),
+        );
+    }
+    decoder.reader.ensure(2)?;
+    let expected = crc::crc_16(&bytes[start..end]);
+    let value = decoder.reader.read_next_u16(false);
+    push(dump, bytes, end, end + 2, Item::Crc { value, expected });
+    Ok(())
+}
+
+fn annotate_definition<'input>(
+    decoder: &mut Decoder<'input>,
+    bytes: &'input [u8],
+    header: &FitMessageHeader,
+    dump: &mut HexDump<'input>,
+) -> Result<(), ErrorKind> {
+    let mut message = None;
+    let definition = decoder.read_definition_message_with(header, |range, part| {
+        let item = match part {
+            ReadPart::DefinitionHeader {
+                architecture,
+                global_message_number,
+                number_of_fields,
+            } => {
+                message = message_name(global_message_number);
+                Item::DefinitionHeader {
+                    architecture,
+                    global_message_number,
+                    message: message.clone(),
+                    number_of_fields,
+                }
+            }
+            ReadPart::FieldDefinition(definition) => Item::FieldDefinition {
+                definition: definition.clone(),
+                name: message
+                    .as_deref()
+                    .and_then(|it| messages::field_name(it, definition.field_definition_number)),
+            },
+            ReadPart::DeveloperFieldCount(count) => Item::DeveloperFieldCount(count),
+            ReadPart::DeveloperFieldDefinition(definition) => {
+                Item::DeveloperFieldDefinition(definition.clone())
+            }
+            ReadPart::Field(..) | ReadPart::DeveloperField(..) => return,
+        };
+        push(dump, bytes, range.start, range.end, item);
+    })?;
+    decoder
+        .defs
+        .insert(definition.local_message_number, Arc::new(definition));
+    Ok(())
+}
+
+/// Read the message keeping the range of each field, then credit the decoded values to the
+/// fields they were decoded from
+fn annotate_data<'input>(
+    decoder: &mut Decoder<'input>,
+    bytes: &'input [u8],
+    header: &FitMessageHeader,
+    dump: &mut HexDump<'input>,
+) -> Result<(), ErrorKind> {
+    let message = decoder
+        .defs
+        .get(&header.local_message_number)
+        .and_then(|it| message_name(it.global_message_number));
+
+    let mut spans = Vec::new();
+    let data = decoder.read_data_message_with(header, |range, part| {
+        let item = match part {
+            ReadPart::Field(field, value) => Item::Field(DataField {
+                number: field.field_definition_number,
+                name: message
+                    .as_deref()
+                    .and_then(|it| messages::field_name(it, field.field_definition_number)),
+                base_type: field.base_type,
+                value: value.clone(),
+                decoded: Vec::new(),
+            }),
+            ReadPart::DeveloperField(definition, value) => Item::DeveloperField {
+                definition: definition.clone(),
+                value: value.clone(),
+            },
+            _ => return,
+        };
+        spans.push((range, item));
+    })?;
+
+    let mut sources = Vec::new();
+    let record =
+        match decoder.decode_message_with(data, |number, name| sources.push((number, name))) {
+            Ok((_, record)) => record,
+            Err(e) => {
+                decoder.errors.push(e);
+                Default::default()
+            }
+        };
+    for (range, mut item) in spans {
+        if let Item::Field(field) = &mut item {
+            field.decoded = sources
+                .iter()
+                .filter(|(number, _)| *number == field.number)
+                .filter_map(|(_, name)| Some((*name, record.get(name)?.clone())))
+                .collect();
+        }
+        push(dump, bytes, range.start, range.end, item);
+    }
+    Ok(())
+}
+
+fn push<'input>(
+    dump: &mut HexDump<'input>,
+    bytes: &'input [u8],
+    start: usize,
+    end: usize,
+    item: Item,
+) {
+    dump.spans.push(Span {
+        offset: start,
+        bytes: &bytes[start..end.min(bytes.len())],
+        item,
+    });
+}
+
+fn message_name(global_message_number: u16) -> Option<String> {
+    types::MesgNum::try_from(&fit::Value::UInt16(global_message_number))
+        .ok()
+        .map(|it| it.to_string())
+}
+
+impl fmt::Display for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut errors = self.errors.iter().peekable();
+        for (index, span) in self.spans.iter().enumerate() {
+            let indent = match span.item {
+                Item::FileHeader(_) | Item::MessageHeader(_) | Item::Crc { .. } => "",
+                Item::DefinitionHeader { .. } | Item::Field(_) | Item::DeveloperField { .. } => {
+                    "  "
+                }
+                _ => "    ",
+            };
+            let mut lines = span.bytes.chunks(BYTES_PER_LINE);
+            let first = lines.next().unwrap_or_default();
+            writeln!(
+                f,
+                "{:08x}  {:<width$}  {indent}{}",
+                span.offset,
+                hex(first),
+                span.item,
+                width = BYTES_PER_LINE * 3 - 1
+            )?;
+            for (line, bytes) in lines.enumerate() {
+                let offset = span.offset + (line + 1) * BYTES_PER_LINE;
+                writeln!(f, "{offset:08x}  {}", hex(bytes))?;
+            }
+            // errors follow the last span of the message they were found in
+            let next = self.spans.get(index + 1);
+            if next.is_none_or(|it| matches!(it.item, Item::MessageHeader(_) | Item::Crc { .. })) {
+                while let Some((_, error)) = errors.next_if(|(offset, _)| *offset <= span.offset) {
+                    writeln!(
+                        f,
+                        "{:<width$}  ! {error}",
+                        "",
+                        width = 8 + BYTES_PER_LINE * 3
+                    )?;
+                }
+            }
+        }
+        if let Some(error) = &self.fatal {
+            writeln!(f, "error: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Item::FileHeader(header) => write!(
+                f,
+                "file header: size {}, protocol {}.{}, profile {}, data size {}, {}, crc {:#06x}",
+                header.header_size,
+                header.protocol_version >> 4,
+                header.protocol_version & 0x0F,
+                header.profile_version_string(),
+                header.data_size,
+                header.data_type,
+                header.header_crc
+            ),
+            Item::MessageHeader(header) => {
+                match (header.time_offset, header.definition) {
+                    (Some(offset), _) => write!(
+                        f,
+                        "compressed timestamp header: local {}, time offset {offset}",
+                        header.local_message_number
+                    )?,
+                    (None, true) => write!(
+                        f,
+                        "definition header: local {}",
+                        header.local_message_number
+                    )?,
+                    (None, false) => {
+                        write!(f, "data header: local {}", header.local_message_number)?
+                    }
+                }
+                if header.developer_data {
+                    write!(f, ", developer data")?;
+                }
+                Ok(())
+            }
+            Item::DefinitionHeader {
+                architecture,
+                global_message_number,
+                message,
+                number_of_fields,
+            } => write!(
+                f,
+                "definition: global {global_message_number} ({}), {}, {number_of_fields} fields",
+                message.as_deref().unwrap_or("unknown"),
+                match architecture {
+                    Architecture::LittleEndian => "little endian",
+                    Architecture::BigEndian => "big endian",
+                }
+            ),
+            Item::FieldDefinition { definition, name } => write!(
+                f,
+                "field {} {}: size {}, {}",
+                definition.field_definition_number,
+                name.unwrap_or("unknown"),
+                definition.size,
+                definition.base_type
+            ),
+            Item::DeveloperFieldCount(count) => write!(f, "{count} developer fields"),
+            Item::DeveloperFieldDefinition(definition) => write!(
+                f,
+                "developer field {} of developer {}: size {}",
+                definition.field_number, definition.developer_data_index, definition.size
+            ),
+            Item::Field(field) => {
+                write!(
+                    f,
+                    "field {} {}: ",
+                    field.number,
+                    field.name.unwrap_or("unknown")
+                )?;
+                match &field.value {
+                    Ok(value) => write!(f, "{}", display(value))?,
+                    Err(ErrorKind::InvalidFieldValue { value, .. }) => {
+                        write!(f, "invalid ({})", display(value))?
+                    }
+                    Err(error) => write!(f, "{error}")?,
+                }
+                if let [(name, value)] = field.decoded.as_slice() {
+                    if Some(*name) == field.name {
+                        // a value decoded as is, e.g. an unscaled number, is not repeated
+                        return match &field.value {
+                            Ok(raw) if display(raw) == display(value) => Ok(()),
+                            _ => write!(f, " -> {}", display(value)),
+                        };
+                    }
+                }
+                let decoded = field
+                    .decoded
+                    .iter()
+                    .map(|(name, value)| format!("{name} = {}", display(value)))
+                    .collect::<Vec<_>>();
+                if !decoded.is_empty() {
+                    write!(f, " -> {}", decoded.join(", "))?;
+                }
+                Ok(())
+            }
+            Item::DeveloperField { definition, value } => {
+                write!(
+                    f,
+                    "developer field {} of developer {}: ",
+                    definition.field_number, definition.developer_data_index
+                )?;
+                match value {
+                    Ok(value) => write!(f, "{}", display(value)),
+                    Err(error) => write!(f, "{error}"),
+                }
+            }
+            Item::Crc { value, expected } if value == expected => write!(f, "crc: {value:#06x}"),
+            Item::Crc { value, expected } => {
+                write!(f, "crc: {value:#06x}, expected {expected:#06x}")
+            }
+        }
+    }
+}
+
+fn display(value: &fit::Value) -> String {
+    match value {
+        fit::Value::Array(values) => format!(
+            "[{}]",
+            values.iter().map(display).collect::<Vec<_>>().join(", ")
+        ),
+        fit::Value::String(value) => format!("{value:?}"),
+        value => value.to_string(),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|it| format!("{it:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
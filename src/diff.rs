@@ -0,0 +1,316 @@
+//! Message level comparison of two decoded FIT files
+//!
+//! The messages of each type are aligned by `timestamp`, then by `message_index`, and messages
+//! having neither by their position. Aligned messages are compared field by field, the others
+//! are reported as added or removed.
+
+use crate::decoder::{Messages, Record};
+use crate::fit;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+/// Fields aligning messages, in order of preference
+const ALIGN_FIELDS: [&str; 2] = ["timestamp", "message_index"];
+
+/// Options of [`diff`]
+#[derive(Debug, Clone)]
+pub struct DiffOptions {
+    /// Fields left out of the comparison, a field name such as `serial_number` ignores the field
+    /// in every message, `record.distance` only in one message type. `time_created` and
+    /// `serial_number` by default
+    pub ignore_fields: Vec<String>,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        DiffOptions {
+            ignore_fields: vec!["time_created".to_string(), "serial_number".to_string()],
+        }
+    }
+}
+
+impl DiffOptions {
+    fn is_ignored(&self, message_type: &str, field: &str) -> bool {
+        self.ignore_fields.iter().any(|it| {
+            it == field
+                || it
+                    .split_once('.')
+                    .is_some_and(|(message, name)| message == message_type && name == field)
+        })
+    }
+}
+
+/// A field whose value differs, `None` on the side missing the field
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub left: Option<fit::Value>,
+    pub right: Option<fit::Value>,
+}
+
+/// A difference between the messages, indexes count the messages of the same type
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum MessageChange {
+    Added {
+        message: String,
+        index: usize,
+        timestamp: Option<fit::Value>,
+        fields: Record,
+    },
+    Removed {
+        message: String,
+        index: usize,
+        timestamp: Option<fit::Value>,
+        fields: Record,
+    },
+    Changed {
+        message: String,
+        left_index: usize,
+        right_index: usize,
+        timestamp: Option<fit::Value>,
+        fields: Vec<FieldChange>,
+    },
+}
+
+/// Differences of two files, grouped by message type
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Diff {
+    pub changes: Vec<MessageChange>,
+}
+
+/// Number of added, removed and changed messages
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Summary {
+    pub added: usize,
+    pub removed: usize,
+    pub changed: usize,
+}
+
+impl Diff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+    pub fn summary(&self) -> Summary {
+        let mut summary = Summary::default();
+        for change in &self.changes {
+            match change {
+                MessageChange::Added { .. } => summary.added += 1,
+                MessageChange::Removed { .. } => summary.removed += 1,
+                MessageChange::Changed { .. } => summary.changed += 1,
+            }
+        }
+        summary
+    }
+}
+
+/// Compare the messages of two files
+pub fn diff(left: &Messages, right: &Messages, options: &DiffOptions) -> Diff {
+    let mut changes = Vec::new();
+    let message_types = left
+        .keys()
+        .chain(right.keys().filter(|it| !left.contains_key(*it)));
+    for message_type in message_types {
+        diff_records(
+            message_type,
+            records(left, message_type),
+            records(right, message_type),
+            options,
+            &mut changes,
+        );
+    }
+    Diff { changes }
+}
+
+fn records<'a>(messages: &'a Messages, message_type: &str) -> &'a [Record] {
+    messages
+        .get(message_type)
+        .map(|it| it.as_slice())
+        .unwrap_or_default()
+}
+
+fn diff_records(
+    message_type: &str,
+    left: &[Record],
+    right: &[Record],
+    options: &DiffOptions,
+    changes: &mut Vec<MessageChange>,
+) {
+    let mut unaligned = VecDeque::new();
+    let mut aligned: HashMap<_, VecDeque<usize>> = HashMap::new();
+    for (index, record) in right.iter().enumerate() {
+        match align_key(record) {
+            Some(key) => aligned.entry(key).or_default().push_back(index),
+            None => unaligned.push_back(index),
+        }
+    }
+    let mut pairs = Vec::new();
+    let mut paired = vec![false; right.len()];
+    for (index, record) in left.iter().enumerate() {
+        let other = match align_key(record) {
+            Some(key) => aligned.get_mut(&key).and_then(|it| it.pop_front()),
+            None => unaligned.pop_front(),
+        };
+        if let Some(other) = other {
+            paired[other] = true;
+        }
+        pairs.push((Some(index), other));
+    }
+    pairs.extend(
+        paired
+            .iter()
+            .enumerate()
+            .filter(|(_, paired)| !**paired)
+            .map(|(index, _)| (None, Some(index))),
+    );
+    // chronological when timestamped, otherwise in the order of the left file
+    pairs.sort_by_key(|(l, r)| {
+        let record = l.map(|it| &left[it]).or(r.map(|it| &right[it]));
+        let timestamp = record.and_then(|it| match it.get("timestamp") {
+            Some(fit::Value::DateTime(val)) => Some(*val),
+            _ => None,
+        });
+        (timestamp, l.unwrap_or(usize::MAX), *r)
+    });
+
+    for pair in pairs {
+        let change = match pair {
+            (Some(l), Some(r)) => {
+                let fields = diff_fields(message_type, &left[l], &right[r], options);
+                if fields.is_empty() {
+                    continue;
+                }
+                MessageChange::Changed {
+                    message: message_type.to_string(),
+                    left_index: l,
+                    right_index: r,
+                    timestamp: right[r].get("timestamp").cloned(),
+                    fields,
+                }
+            }
+            (Some(l), None) => MessageChange::Removed {
+                message: message_type.to_string(),
+                index: l,
+                timestamp: left[l].get("timestamp").cloned(),
+                fields: left[l].clone(),
+            },
+            (None, Some(r)) => MessageChange::Added {
+                message: message_type.to_string(),
+                index: r,
+                timestamp: right[r].get("timestamp").cloned(),
+                fields: right[r].clone(),
+            },
+            (None, None) => continue,
+        };
+        changes.push(change);
+    }
+}
+
+fn align_key(record: &Record) -> Option<(&'static str, String)> {
+    ALIGN_FIELDS
+        .iter()
+        .find_map(|name| Some((*name, record.get(name)?.to_string())))
+}
+
+fn diff_fields(
+    message_type: &str,
+    left: &Record,
+    right: &Record,
+    options: &DiffOptions,
+) -> Vec<FieldChange> {
+    left.keys()
+        .chain(right.keys().filter(|it| !left.contains_key(*it)))
+        .filter(|name| !options.is_ignored(message_type, name))
+        .filter(|name| left.get(*name) != right.get(*name))
+        .map(|name| FieldChange {
+            field: name,
+            left: left.get(name).cloned(),
+            right: right.get(name).cloned(),
+        })
+        .collect()
+}
+
+impl Serialize for Diff {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Diff", 2)?;
+        state.serialize_field("summary", &self.summary())?;
+        state.serialize_field("changes", &self.changes)?;
+        state.end()
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            match change {
+                MessageChange::Added {
+                    message,
+                    index,
+                    timestamp,
+                    fields,
+                } => {
+                    writeln!(f, "+ {message} #{index}{}", at(timestamp))?;
+                    for (name, value) in fields {
+                        writeln!(f, "    {name}: {value}")?;
+                    }
+                }
+                MessageChange::Removed {
+                    message,
+                    index,
+                    timestamp,
+                    fields,
+                } => {
+                    writeln!(f, "- {message} #{index}{}", at(timestamp))?;
+                    for (name, value) in fields {
+                        writeln!(f, "    {name}: {value}")?;
+                    }
+                }
+                MessageChange::Changed {
+                    message,
+                    left_index,
+                    right_index,
+                    timestamp,
+                    fields,
+                } => {
+                    if left_index == right_index {
+                        writeln!(f, "~ {message} #{left_index}{}", at(timestamp))?;
+                    } else {
+                        writeln!(
+                            f,
+                            "~ {message} #{left_index} -> #{right_index}{}",
+                            at(timestamp)
+                        )?;
+                    }
+                    for field in fields {
+                        match (&field.left, &field.right) {
+                            (Some(left), Some(right)) => {
+                                writeln!(f, "    {}: {left} -> {right}", field.field)?
+                            }
+                            (Some(left), None) => writeln!(f, "  - {}: {left}", field.field)?,
+                            (None, Some(right)) => writeln!(f, "  + {}: {right}", field.field)?,
+                            (None, None) => {}
+                        }
+                    }
+                }
+            }
+        }
+        let summary = self.summary();
+        writeln!(
+            f,
+            "{} added, {} removed, {} changed",
+            summary.added, summary.removed, summary.changed
+        )
+    }
+}
+
+fn at(timestamp: &Option<fit::Value>) -> String {
+    timestamp
+        .as_ref()
+        .map(|it| format!(" @ {it}"))
+        .unwrap_or_default()
+}
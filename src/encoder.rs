@@ -0,0 +1,192 @@
+use crate::crc;
+use crate::decoder::{Architecture, FitDefinitionMessage};
+use crate::error::{ErrorKind, ParserResult};
+use crate::fit::{self, BaseType};
+use crate::profile::VERSION;
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+const HEADER_SIZE: u8 = 14;
+const PROTOCOL_VERSION: u8 = 0x20;
+
+/// Encode messages into a FIT file
+///
+/// Data messages are written with the most recent definition of their local message number,
+/// always in little endian. Missing field values are written as the invalid value of the
+/// field base type.
+#[derive(Debug, Default)]
+pub struct Encoder {
+    data: Vec<u8>,
+    defs: HashMap<u8, FitDefinitionMessage>,
+}
+
+impl Encoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Write a definition message, developer field definitions are not supported and skipped
+    pub fn write_definition(&mut self, def: &FitDefinitionMessage) {
+        let def = FitDefinitionMessage {
+            architecture: Architecture::LittleEndian,
+            developer_field_definitions: Vec::new(),
+            ..def.clone()
+        };
+        self.data.push(0x40 | (def.local_message_number & 0x0F));
+        // reserved
+        self.data.push(0);
+        self.data.push(0);
+        self.data
+            .extend_from_slice(&def.global_message_number.to_le_bytes());
+        self.data.push(def.field_definitions.len() as u8);
+        for field in &def.field_definitions {
+            self.data.push(field.field_definition_number);
+            self.data.push(field.size);
+            self.data.push(field.base_type as u8);
+        }
+        self.defs.insert(def.local_message_number, def);
+    }
+    /// Write a data message, `fields` holds raw values by field definition number
+    pub fn write_data(
+        &mut self,
+        local_message_number: u8,
+        fields: &IndexMap<u8, fit::Value>,
+    ) -> ParserResult<()> {
+        let def = self.defs.get(&local_message_number).ok_or(
+            ErrorKind::LocalDefinitionMessageNotFound(local_message_number),
+        )?;
+        self.data.push(local_message_number & 0x0F);
+        for field in &def.field_definitions {
+            let value = fields.get(&field.field_definition_number);
+            write_value(&mut self.data, field.base_type, field.size as usize, value);
+        }
+        Ok(())
+    }
+    /// Wrap the written messages with the file header and CRC
+    pub fn finish(self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_SIZE as usize + self.data.len() + 2);
+        bytes.push(HEADER_SIZE);
+        bytes.push(PROTOCOL_VERSION);
+        bytes.extend_from_slice(&profile_version().to_le_bytes());
+        bytes.extend_from_slice(&(self.data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(b".FIT");
+        let header_crc = crc::crc_16(&bytes);
+        bytes.extend_from_slice(&header_crc.to_le_bytes());
+        bytes.extend_from_slice(&self.data);
+        let crc = crc::crc_16(&bytes);
+        bytes.extend_from_slice(&crc.to_le_bytes());
+        bytes
+    }
+}
+
+/// Profile version as written in the file header, e.g. `21.126` is `21126`, read back with
+/// [`FitFileHeader::profile_version_string`](crate::decoder::FitFileHeader::profile_version_string)
+fn profile_version() -> u16 {
+    let mut parts = VERSION.split('.').map(|it| it.parse::<u16>().unwrap_or(0));
+    let major = parts.next().unwrap_or(0);
+    let minor = parts.next().unwrap_or(0);
+    if minor > 99 {
+        major * 1000 + minor
+    } else {
+        major * 100 + minor
+    }
+}
+
+/// Write `size` bytes of a value, filling missing elements with the invalid value, strings are
+/// cut on a char boundary to leave at least one NUL byte
+fn write_value(buf: &mut Vec<u8>, base_type: BaseType, size: usize, value: Option<&fit::Value>) {
+    if base_type == BaseType::String {
+        let text = match value {
+            Some(fit::Value::Array(values)) => values
+                .iter()
+                .map(|it| it.to_string())
+                .collect::<Vec<_>>()
+                .join("\0"),
+            Some(value) => value.to_string(),
+            None => String::new(),
+        };
+        // keep room for the NUL terminator and cut on a char boundary
+        let end = (0..=text.len().min(size.saturating_sub(1)))
+            .rev()
+            .find(|it| text.is_char_boundary(*it))
+            .unwrap_or(0);
+        let mut bytes = text.into_bytes();
+        bytes.truncate(end);
+        bytes.resize(size, 0);
+        buf.extend_from_slice(&bytes);
+        return;
+    }
+    let elements = match value {
+        Some(fit::Value::Array(values)) => values.iter().collect(),
+        Some(fit::Value::Bytes(bytes)) => {
+            let end = buf.len() + size;
+            buf.extend(bytes.iter().take(size));
+            buf.resize(end, base_type.invalid() as u8);
+            return;
+        }
+        Some(value) => vec![value],
+        None => Vec::new(),
+    };
+    let element_size = base_type.size() as usize;
+    for index in 0..size / element_size {
+        write_element(buf, base_type, elements.get(index).copied());
+    }
+}
+
+fn write_element(buf: &mut Vec<u8>, base_type: BaseType, value: Option<&fit::Value>) {
+    let size = base_type.size() as usize;
+    let bytes = match (base_type, value) {
+        (BaseType::Float32, Some(value)) => {
+            as_f64(value).map(|it| (it as f32).to_le_bytes().to_vec())
+        }
+        (BaseType::Float64, Some(value)) => as_f64(value).map(|it| it.to_le_bytes().to_vec()),
+        (
+            BaseType::UInt64 | BaseType::UInt64z,
+            Some(fit::Value::UInt64(val) | fit::Value::UInt64z(val)),
+        ) => Some(val.to_le_bytes().to_vec()),
+        (_, Some(value)) => as_i64(value).map(|it| it.to_le_bytes()[..size].to_vec()),
+        (_, None) => None,
+    };
+    let bytes =
+        bytes.unwrap_or_else(|| (base_type.invalid() as u64).to_le_bytes()[..size].to_vec());
+    buf.extend_from_slice(&bytes);
+}
+
+fn as_i64(value: &fit::Value) -> Option<i64> {
+    match value {
+        fit::Value::DateTime(val) => Some(val.timestamp() - fit::FIT_EPOCH_OFFSET),
+        fit::Value::LocalDateTime(val) => {
+            Some(val.naive_local().and_utc().timestamp() - fit::FIT_EPOCH_OFFSET)
+        }
+        fit::Value::Bool(val) => Some(*val as i64),
+        _ => value
+            .try_as_i64()
+            .ok()
+            .or_else(|| value.try_as_f64().ok().map(|it| it.round() as i64)),
+    }
+}
+
+fn as_f64(value: &fit::Value) -> Option<f64> {
+    value
+        .try_as_f64()
+        .ok()
+        .or_else(|| as_i64(value).map(|it| it as f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_are_cut_on_char_boundaries() {
+        let string = |text: &str| {
+            let mut buf = Vec::new();
+            let value = fit::Value::String(text.to_string());
+            write_value(&mut buf, BaseType::String, 8, Some(&value));
+            buf
+        };
+        // 9 bytes of text, the last char does not fit with the NUL terminator
+        assert_eq!(string("湖湖湖"), "湖湖\0\0".as_bytes());
+        assert_eq!(string("abcdefgh"), b"abcdefg\0");
+        assert_eq!(string("abc"), b"abc\0\0\0\0\0");
+    }
+}
@@ -69,6 +69,12 @@ pub enum ErrorKind {
         developer_data_index: u8,
         field_no: u8,
     },
+    #[error("Invalid UTF-8 in string field at offset {offset}, decoded lossily.")]
+    InvalidUtf8String { offset: usize },
+    #[error("Invalid CSV at line {line}: {reason}")]
+    InvalidCsv { line: usize, reason: String },
+    #[error("Invalid XML: {reason}")]
+    InvalidXml { reason: String },
 }
 pub type ParserResult<T> = Result<T, ErrorKind>;
 
@@ -95,6 +101,9 @@ impl ErrorKind {
             InvalidTimestamp { .. } => "InvalidTimestamp",
             MissingDeveloperDataDef { .. } => "MissingDeveloperDataDef",
             MissingDeveloperFieldDescription { .. } => "MissingDeveloperFieldDescription",
+            InvalidUtf8String { .. } => "InvalidUtf8String",
+            InvalidCsv { .. } => "InvalidCsv",
+            InvalidXml { .. } => "InvalidXml",
         }
     }
 }
@@ -0,0 +1,272 @@
+//! Apache Arrow record batches of decoded messages, one per message type
+//!
+//! Column types derive from the profile field types:
+//!
+//! - `date_time` is a second timestamp at offset `+00:00`, `local_date_time` one without zone
+//! - profile types (e.g. `sport`) are dictionary encoded value names, bit masks stay numeric
+//! - scaled fields are `Float64`, other numbers keep the width and sign of their base type
+//! - byte arrays are `Binary`, other array fields are lists
+//!
+//! Fields the profile does not define, such as sub-fields, take the type of their values.
+//! Columns are in profile order and carry their units as `units` field metadata.
+
+use super::profile_order;
+use crate::decoder::{Messages, Record};
+use crate::fit::{self, BaseType};
+use crate::profile::messages;
+use arrow_array::types::Int32Type;
+use arrow_array::{
+    ArrayRef, BinaryArray, BooleanArray, DictionaryArray, Float32Array, Float64Array, Int16Array,
+    Int32Array, Int64Array, Int8Array, ListArray, RecordBatch, RecordBatchOptions, StringArray,
+    TimestampSecondArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema, TimeUnit};
+use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Convert every message type into its own record batch, keyed by message type
+pub fn to_record_batches(messages: &Messages) -> Result<IndexMap<String, RecordBatch>, ArrowError> {
+    messages
+        .iter()
+        .map(|(message_type, records)| {
+            to_record_batch(message_type, records).map(|it| (message_type.to_owned(), it))
+        })
+        .collect()
+}
+
+/// Convert the records of one message type into a record batch
+pub fn to_record_batch(message_type: &str, records: &[Record]) -> Result<RecordBatch, ArrowError> {
+    let mut names = records
+        .iter()
+        .flat_map(|it| it.keys().copied())
+        .collect::<indexmap::IndexSet<_>>();
+    names.sort_by_cached_key(|name| profile_order(message_type, name));
+
+    let mut fields = Vec::with_capacity(names.len());
+    let mut columns = Vec::with_capacity(names.len());
+    for name in names {
+        let values = records.iter().map(|it| it.get(name)).collect::<Vec<_>>();
+        let field_def = messages::field_def(message_type, name);
+        let data_type = column_type(field_def.as_ref(), &values);
+        let mut field = Field::new(name, data_type.clone(), true);
+        if let Some(units) = field_def.map(|it| it.units).filter(|it| !it.is_empty()) {
+            field.set_metadata(HashMap::from([("units".to_string(), units.to_string())]));
+        }
+        columns.push(build_array(&data_type, &values));
+        fields.push(field);
+    }
+    RecordBatch::try_new_with_options(
+        Arc::new(Schema::new(fields)),
+        columns,
+        &RecordBatchOptions::new().with_row_count(Some(records.len())),
+    )
+}
+
+/// Scalar values of a column, array elements included
+fn elements<'a>(values: &'a [Option<&'a fit::Value>]) -> impl Iterator<Item = &'a fit::Value> {
+    values.iter().flatten().flat_map(|value| match value {
+        fit::Value::Array(values) => values.iter().collect::<Vec<_>>(),
+        _ => vec![*value],
+    })
+}
+
+fn column_type(field_def: Option<&messages::FieldDef>, values: &[Option<&fit::Value>]) -> DataType {
+    let item = match field_def {
+        Some(field_def) => profile_type(field_def, values),
+        None => elements(values)
+            .next()
+            .map(value_type)
+            .unwrap_or(DataType::Utf8),
+    };
+    if values
+        .iter()
+        .any(|it| matches!(it, Some(fit::Value::Array(_))))
+    {
+        DataType::new_list(item, true)
+    } else {
+        item
+    }
+}
+
+fn profile_type(field_def: &messages::FieldDef, values: &[Option<&fit::Value>]) -> DataType {
+    match field_def.field_ty {
+        "date_time" => return DataType::Timestamp(TimeUnit::Second, Some("+00:00".into())),
+        "local_date_time" => return DataType::Timestamp(TimeUnit::Second, None),
+        "bool" => return DataType::Boolean,
+        _ => {}
+    }
+    if field_def.scale != 1.0 || field_def.offset != 0.0 {
+        return DataType::Float64;
+    }
+    let is_profile_type = BaseType::try_from(field_def.field_ty).is_err();
+    let is_bit_mask = elements(values).any(|it| matches!(it, fit::Value::Flags(_)));
+    if is_profile_type && !is_bit_mask {
+        return DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    }
+    if field_def.base_type == BaseType::Byte
+        && values
+            .iter()
+            .any(|it| matches!(it, Some(fit::Value::Bytes(_))))
+    {
+        return DataType::Binary;
+    }
+    base_type(field_def.base_type)
+}
+
+fn base_type(base_type: BaseType) -> DataType {
+    match base_type {
+        BaseType::Enum | BaseType::UInt8 | BaseType::UInt8z | BaseType::Byte => DataType::UInt8,
+        BaseType::SInt8 => DataType::Int8,
+        BaseType::SInt16 => DataType::Int16,
+        BaseType::UInt16 | BaseType::UInt16z => DataType::UInt16,
+        BaseType::SInt32 => DataType::Int32,
+        BaseType::UInt32 | BaseType::UInt32z => DataType::UInt32,
+        BaseType::SInt64 => DataType::Int64,
+        BaseType::UInt64 | BaseType::UInt64z => DataType::UInt64,
+        BaseType::Float32 => DataType::Float32,
+        BaseType::Float64 => DataType::Float64,
+        BaseType::String => DataType::Utf8,
+    }
+}
+
+/// Type of a value of a field the profile does not define
+fn value_type(value: &fit::Value) -> DataType {
+    match value {
+        fit::Value::DateTime(_) => DataType::Timestamp(TimeUnit::Second, Some("+00:00".into())),
+        fit::Value::LocalDateTime(_) => DataType::Timestamp(TimeUnit::Second, None),
+        fit::Value::Typed(_) => {
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        }
+        fit::Value::Flags(val) => value_type(&val.value),
+        fit::Value::Bytes(_) => DataType::Binary,
+        fit::Value::Bool(_) => DataType::Boolean,
+        fit::Value::String(_) => DataType::Utf8,
+        fit::Value::Array(values) => values.first().map(value_type).unwrap_or(DataType::Utf8),
+        _ => base_type(BaseType::from(value)),
+    }
+}
+
+fn as_i64(value: &fit::Value) -> Option<i64> {
+    match value {
+        fit::Value::DateTime(val) => Some(val.timestamp()),
+        // wall clock seconds, as the column has no zone
+        fit::Value::LocalDateTime(val) => Some(val.naive_local().and_utc().timestamp()),
+        fit::Value::Bool(val) => Some(*val as i64),
+        fit::Value::String(val) => val.parse().ok(),
+        _ => value
+            .try_as_i64()
+            .ok()
+            .or_else(|| value.try_as_f64().ok().map(|it| it.round() as i64)),
+    }
+}
+
+fn as_u64(value: &fit::Value) -> Option<u64> {
+    match value {
+        fit::Value::UInt64(val) | fit::Value::UInt64z(val) => Some(*val),
+        _ => as_i64(value).and_then(|it| u64::try_from(it).ok()),
+    }
+}
+
+fn as_f64(value: &fit::Value) -> Option<f64> {
+    value
+        .try_as_f64()
+        .ok()
+        .or_else(|| as_i64(value).map(|it| it as f64))
+}
+
+fn as_bool(value: &fit::Value) -> Option<bool> {
+    match value {
+        fit::Value::Bool(val) => Some(*val),
+        fit::Value::String(val) if val == "true" => Some(true),
+        fit::Value::String(val) if val == "false" => Some(false),
+        _ => as_i64(value).map(|it| it != 0),
+    }
+}
+
+macro_rules! integer_array {
+    ($array: ident, $native: ty, $values: expr) => {
+        Arc::new($array::from_iter($values.iter().map(|it| {
+            it.and_then(as_i64)
+                .and_then(|it| <$native>::try_from(it).ok())
+        })))
+    };
+}
+
+fn build_array(data_type: &DataType, values: &[Option<&fit::Value>]) -> ArrayRef {
+    match data_type {
+        DataType::Boolean => Arc::new(BooleanArray::from_iter(
+            values.iter().map(|it| it.and_then(as_bool)),
+        )),
+        DataType::Int8 => integer_array!(Int8Array, i8, values),
+        DataType::Int16 => integer_array!(Int16Array, i16, values),
+        DataType::Int32 => integer_array!(Int32Array, i32, values),
+        DataType::Int64 => integer_array!(Int64Array, i64, values),
+        DataType::UInt8 => integer_array!(UInt8Array, u8, values),
+        DataType::UInt16 => integer_array!(UInt16Array, u16, values),
+        DataType::UInt32 => integer_array!(UInt32Array, u32, values),
+        DataType::UInt64 => Arc::new(UInt64Array::from_iter(
+            values.iter().map(|it| it.and_then(as_u64)),
+        )),
+        DataType::Float32 => Arc::new(Float32Array::from_iter(
+            values
+                .iter()
+                .map(|it| it.and_then(as_f64).map(|it| it as f32)),
+        )),
+        DataType::Float64 => Arc::new(Float64Array::from_iter(
+            values.iter().map(|it| it.and_then(as_f64)),
+        )),
+        DataType::Timestamp(_, zone) => Arc::new(
+            TimestampSecondArray::from_iter(values.iter().map(|it| it.and_then(as_i64)))
+                .with_timezone_opt(zone.clone()),
+        ),
+        DataType::Binary => Arc::new(BinaryArray::from_iter(
+            values.iter().map(|it| it.and_then(|it| it.to_bytes())),
+        )),
+        DataType::Dictionary(_, _) => {
+            let names = values
+                .iter()
+                .map(|it| it.map(|it| it.to_string()))
+                .collect::<Vec<_>>();
+            Arc::new(
+                names
+                    .iter()
+                    .map(|it| it.as_deref())
+                    .collect::<DictionaryArray<Int32Type>>(),
+            )
+        }
+        DataType::List(item) => {
+            // scalar values become single element lists
+            let lists = values
+                .iter()
+                .map(|value| {
+                    value.map(|value| match value {
+                        fit::Value::Array(values) => values.iter().map(Some).collect(),
+                        _ => vec![Some(value)],
+                    })
+                })
+                .collect::<Vec<Option<Vec<_>>>>();
+            let items = lists
+                .iter()
+                .flatten()
+                .flatten()
+                .copied()
+                .collect::<Vec<_>>();
+            let offsets = arrow_array::builder::OffsetBufferBuilder::new(lists.len());
+            let offsets = lists.iter().fold(offsets, |mut offsets, it| {
+                offsets.push_length(it.as_ref().map(|it| it.len()).unwrap_or(0));
+                offsets
+            });
+            let nulls = lists.iter().map(|it| it.is_some()).collect::<Vec<_>>();
+            Arc::new(ListArray::new(
+                item.clone(),
+                offsets.finish(),
+                build_array(item.data_type(), &items),
+                Some(nulls.into()),
+            ))
+        }
+        _ => Arc::new(StringArray::from_iter(
+            values.iter().map(|it| it.map(|it| it.to_string())),
+        )),
+    }
+}
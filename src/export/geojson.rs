@@ -0,0 +1,198 @@
+//! GeoJSON export of tracks, laps and course points
+//!
+//! `record` positions become `LineString` features, one per lap, or `MultiLineString`
+//! features, one per session with a line per lap. Time, heart rate and power of each
+//! coordinate are kept in the `coordinateProperties` member of the feature properties, the
+//! convention read by Mapbox and `togeojson`. `course_point`, `segment_point` and the lap
+//! start positions become `Point` features. Coordinates are `[longitude, latitude]`, with the
+//! altitude as third element when the record has one.
+
+use super::{date_time, first_number, number, position, rfc3339, split_by_laps};
+use crate::decoder::{Messages, Record};
+use serde_json::{json, Map, Value};
+use std::io;
+
+/// Group of records making up a track feature
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrackGrouping {
+    /// A `LineString` per lap
+    #[default]
+    Lap,
+    /// A `MultiLineString` per session, with a line per lap
+    Session,
+}
+
+/// Write the messages as a GeoJSON `FeatureCollection`
+pub fn write<W: io::Write>(
+    messages: &Messages,
+    grouping: TrackGrouping,
+    writer: &mut W,
+) -> io::Result<()> {
+    serde_json::to_writer(writer, &to_value(messages, grouping)).map_err(io::Error::from)
+}
+
+/// Render the messages as a GeoJSON `FeatureCollection`
+pub fn to_string(messages: &Messages, grouping: TrackGrouping) -> String {
+    to_value(messages, grouping).to_string()
+}
+
+/// Build the GeoJSON `FeatureCollection` of the messages
+pub fn to_value(messages: &Messages, grouping: TrackGrouping) -> Value {
+    let get = |name: &str| {
+        messages
+            .get(name)
+            .map(|it| it.as_slice())
+            .unwrap_or_default()
+    };
+    let records = get("record");
+    let laps = get("lap");
+    let mut features = Vec::new();
+    match grouping {
+        TrackGrouping::Lap => {
+            for (index, segment) in split_by_laps(records, laps).into_iter().enumerate() {
+                if let Some(line) = Line::new(&segment) {
+                    let properties = properties(laps.get(index), "lap", index);
+                    features.push(line_feature(properties, vec![line], false));
+                }
+            }
+        }
+        TrackGrouping::Session => {
+            let sessions = get("session");
+            for (index, segment) in split_by_laps(records, sessions).into_iter().enumerate() {
+                let lines = split_by_laps(segment, laps)
+                    .iter()
+                    .filter_map(|it| Line::new(it))
+                    .collect::<Vec<_>>();
+                if !lines.is_empty() {
+                    let properties = properties(sessions.get(index), "session", index);
+                    features.push(line_feature(properties, lines, true));
+                }
+            }
+        }
+    }
+    for (index, lap) in laps.iter().enumerate() {
+        if let Some((lat, lon)) = position(lap, "start_position") {
+            let mut properties = properties(None, "lap", index);
+            if let Some(time) = lap.get("start_time") {
+                properties.insert("start_time".to_string(), json!(time));
+            }
+            features.push(point_feature(properties, vec![round(lon), round(lat)]));
+        }
+    }
+    for message in ["course_point", "segment_point"] {
+        for (index, point) in get(message).iter().enumerate() {
+            if let Some(coordinates) = coordinates(point) {
+                features.push(point_feature(
+                    properties(Some(point), message, index),
+                    coordinates,
+                ));
+            }
+        }
+    }
+    json!({
+        "type": "FeatureCollection",
+        "features": features,
+    })
+}
+
+/// Coordinates of a track line with the properties of each coordinate
+struct Line {
+    coordinates: Vec<Value>,
+    times: Vec<Value>,
+    heart_rates: Vec<Value>,
+    powers: Vec<Value>,
+}
+
+impl Line {
+    /// `None` if less than two records have a position
+    fn new(records: &[&Record]) -> Option<Self> {
+        let mut line = Line {
+            coordinates: Vec::new(),
+            times: Vec::new(),
+            heart_rates: Vec::new(),
+            powers: Vec::new(),
+        };
+        for record in records {
+            let coordinates = if let Some(coordinates) = coordinates(record) {
+                coordinates
+            } else {
+                continue;
+            };
+            line.coordinates.push(json!(coordinates));
+            line.times
+                .push(json!(date_time(record, "timestamp").map(|it| rfc3339(&it))));
+            line.heart_rates.push(json!(number(record, "heart_rate")));
+            line.powers.push(json!(number(record, "power")));
+        }
+        if line.coordinates.len() < 2 {
+            None
+        } else {
+            Some(line)
+        }
+    }
+}
+
+/// `[longitude, latitude]`, followed by the altitude if known
+fn coordinates(record: &Record) -> Option<Vec<f64>> {
+    let (lat, lon) = position(record, "position")?;
+    let mut coordinates = vec![round(lon), round(lat)];
+    if let Some(ele) = first_number(record, &["enhanced_altitude", "altitude"]) {
+        coordinates.push(ele);
+    }
+    Some(coordinates)
+}
+
+/// Round degrees to 7 decimal places, about 1 cm
+fn round(degrees: f64) -> f64 {
+    (degrees * 1e7).round() / 1e7
+}
+
+/// Fields of the message as feature properties, tagged with the message type and index
+fn properties(record: Option<&Record>, message: &str, index: usize) -> Map<String, Value> {
+    let mut properties = Map::new();
+    properties.insert("message".to_string(), json!(message));
+    properties.insert("index".to_string(), json!(index));
+    for (name, value) in record.into_iter().flatten() {
+        properties.insert(name.to_string(), json!(value));
+    }
+    properties
+}
+
+fn line_feature(mut properties: Map<String, Value>, lines: Vec<Line>, multi: bool) -> Value {
+    let (geometry, coordinate_properties) = if multi {
+        let mut coordinates = Vec::new();
+        let mut times = Vec::new();
+        let mut heart_rates = Vec::new();
+        let mut powers = Vec::new();
+        for line in lines {
+            coordinates.push(line.coordinates);
+            times.push(line.times);
+            heart_rates.push(line.heart_rates);
+            powers.push(line.powers);
+        }
+        (
+            json!({ "type": "MultiLineString", "coordinates": coordinates }),
+            json!({ "times": times, "heart_rates": heart_rates, "powers": powers }),
+        )
+    } else {
+        let line = lines.into_iter().next().unwrap();
+        (
+            json!({ "type": "LineString", "coordinates": line.coordinates }),
+            json!({ "times": line.times, "heart_rates": line.heart_rates, "powers": line.powers }),
+        )
+    };
+    properties.insert("coordinateProperties".to_string(), coordinate_properties);
+    json!({
+        "type": "Feature",
+        "geometry": geometry,
+        "properties": properties,
+    })
+}
+
+fn point_feature(properties: Map<String, Value>, coordinates: Vec<f64>) -> Value {
+    json!({
+        "type": "Feature",
+        "geometry": { "type": "Point", "coordinates": coordinates },
+        "properties": properties,
+    })
+}
@@ -0,0 +1,146 @@
+//! GPX 1.1 export of activities and courses
+//!
+//! `record` messages become track points, split into one `trkseg` per `lap`, and
+//! `course_point` messages become waypoints. Heart rate, cadence, speed and temperature
+//! are written to the Garmin TrackPointExtension v2. The extension has no power element,
+//! so power is written as a `power` element next to it, which is what Garmin Connect and
+//! Strava read.
+
+use super::{
+    activity_name, date_time, degrees, escape_xml, first_number, number, position, rfc3339,
+    split_by_laps, text,
+};
+use crate::decoder::{Messages, Record};
+use std::io;
+
+const HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="fit" xmlns="http://www.topografix.com/GPX/1/1" xmlns:gpxtpx="http://www.garmin.com/xmlschemas/TrackPointExtension/v2" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://www.topografix.com/GPX/1/1 http://www.topografix.com/GPX/1/1/gpx.xsd http://www.garmin.com/xmlschemas/TrackPointExtension/v2 http://www.garmin.com/xmlschemas/TrackPointExtensionv2.xsd">
+"#;
+
+/// Write the messages as a GPX 1.1 document
+pub fn write<W: io::Write>(messages: &Messages, writer: &mut W) -> io::Result<()> {
+    writer.write_all(to_string(messages).as_bytes())
+}
+
+/// Render the messages as a GPX 1.1 document
+pub fn to_string(messages: &Messages) -> String {
+    let mut gpx = String::from(HEADER);
+    if let Some(time) = messages
+        .get("file_id")
+        .and_then(|it| it.first())
+        .and_then(|it| date_time(it, "time_created"))
+    {
+        gpx.push_str(&format!(
+            "  <metadata>\n    <time>{}</time>\n  </metadata>\n",
+            rfc3339(&time)
+        ));
+    }
+    for course_point in messages.get("course_point").into_iter().flatten() {
+        write_waypoint(&mut gpx, course_point);
+    }
+    let records = messages
+        .get("record")
+        .map(|it| it.as_slice())
+        .unwrap_or_default();
+    let laps = messages
+        .get("lap")
+        .map(|it| it.as_slice())
+        .unwrap_or_default();
+    if !records.is_empty() {
+        gpx.push_str("  <trk>\n");
+        if let Some(name) = activity_name(messages) {
+            gpx.push_str(&format!("    <name>{}</name>\n", escape_xml(&name)));
+        }
+        if let Some(sport) = sport(messages) {
+            gpx.push_str(&format!("    <type>{}</type>\n", escape_xml(&sport)));
+        }
+        for segment in split_by_laps(records, laps) {
+            let points = segment
+                .into_iter()
+                .filter_map(track_point)
+                .collect::<Vec<_>>();
+            if points.is_empty() {
+                continue;
+            }
+            gpx.push_str("    <trkseg>\n");
+            for point in points {
+                gpx.push_str(&point);
+            }
+            gpx.push_str("    </trkseg>\n");
+        }
+        gpx.push_str("  </trk>\n");
+    }
+    gpx.push_str("</gpx>\n");
+    gpx
+}
+
+fn sport(messages: &Messages) -> Option<String> {
+    ["session", "course", "sport"]
+        .iter()
+        .filter_map(|name| messages.get(*name).and_then(|it| it.first()))
+        .find_map(|it| text(it, "sport"))
+}
+
+fn write_waypoint(gpx: &mut String, course_point: &Record) {
+    let (lat, lon) = if let Some(position) = position(course_point, "position") {
+        position
+    } else {
+        return;
+    };
+    gpx.push_str(&format!("  <wpt lat=\"{lat:.7}\" lon=\"{lon:.7}\">\n"));
+    if let Some(time) = date_time(course_point, "timestamp") {
+        gpx.push_str(&format!("    <time>{}</time>\n", rfc3339(&time)));
+    }
+    if let Some(name) = text(course_point, "name") {
+        gpx.push_str(&format!("    <name>{}</name>\n", escape_xml(&name)));
+    }
+    if let Some(ty) = text(course_point, "type") {
+        gpx.push_str(&format!("    <type>{}</type>\n", escape_xml(&ty)));
+    }
+    gpx.push_str("  </wpt>\n");
+}
+
+fn track_point(record: &Record) -> Option<String> {
+    let lat = degrees(record, "position_lat")?;
+    let lon = degrees(record, "position_long")?;
+    let mut point = format!("      <trkpt lat=\"{lat:.7}\" lon=\"{lon:.7}\">\n");
+    if let Some(ele) = first_number(record, &["enhanced_altitude", "altitude"]) {
+        point.push_str(&format!("        <ele>{ele:.1}</ele>\n"));
+    }
+    if let Some(time) = date_time(record, "timestamp") {
+        point.push_str(&format!("        <time>{}</time>\n", rfc3339(&time)));
+    }
+
+    let power = number(record, "power");
+    let mut tpx = String::new();
+    if let Some(atemp) = number(record, "temperature") {
+        tpx.push_str(&format!(
+            "            <gpxtpx:atemp>{atemp}</gpxtpx:atemp>\n"
+        ));
+    }
+    if let Some(hr) = number(record, "heart_rate") {
+        tpx.push_str(&format!("            <gpxtpx:hr>{hr}</gpxtpx:hr>\n"));
+    }
+    if let Some(cad) = number(record, "cadence") {
+        tpx.push_str(&format!("            <gpxtpx:cad>{cad}</gpxtpx:cad>\n"));
+    }
+    if let Some(speed) = first_number(record, &["enhanced_speed", "speed"]) {
+        tpx.push_str(&format!(
+            "            <gpxtpx:speed>{speed:.3}</gpxtpx:speed>\n"
+        ));
+    }
+    if power.is_some() || !tpx.is_empty() {
+        point.push_str("        <extensions>\n");
+        if let Some(power) = power {
+            point.push_str(&format!("          <power>{power}</power>\n"));
+        }
+        if !tpx.is_empty() {
+            point.push_str("          <gpxtpx:TrackPointExtension>\n");
+            point.push_str(&tpx);
+            point.push_str("          </gpxtpx:TrackPointExtension>\n");
+        }
+        point.push_str("        </extensions>\n");
+    }
+    point.push_str("      </trkpt>\n");
+    Some(point)
+}
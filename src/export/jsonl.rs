@@ -0,0 +1,108 @@
+//! JSON Lines output, writing each message as soon as it is decoded
+//!
+//! Data messages become `{"message":"record","offset":1234,"fields":{...}}` lines, where
+//! `offset` is the byte offset of the message header. Decoding errors are written as separate
+//! `{"diagnostic":{"kind":...,"message":...},"offset":1234}` lines. An error that stops decoding
+//! ends the output with a `{"diagnostic":{...},"fatal":true}` line.
+
+use crate::decoder::{DecodeEvent, DecodedMessage, Decoder, Record};
+use crate::error::ErrorKind;
+use serde::{Serialize, Serializer};
+use std::io;
+
+#[derive(Serialize)]
+struct MessageLine<'a> {
+    message: &'a str,
+    offset: usize,
+    fields: Fields<'a>,
+}
+
+/// Fields of a record that pass the filter
+struct Fields<'a> {
+    record: &'a Record,
+    keep: &'a dyn Fn(&str) -> bool,
+}
+
+impl Serialize for Fields<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.record.iter().filter(|(name, _)| (self.keep)(name)))
+    }
+}
+
+#[derive(Serialize)]
+struct DiagnosticLine<'a> {
+    diagnostic: &'a ErrorKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<usize>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    fatal: bool,
+}
+
+/// Decode the input, writing a line per data message and diagnostic
+pub fn write<W: io::Write>(decoder: &mut Decoder, writer: &mut W) -> io::Result<()> {
+    write_filtered(decoder, writer, |_, _| true).map(|_| ())
+}
+
+/// Like [`write`], keeping the fields for which `filter(message, field)` is true, messages
+/// left without fields are skipped. Returns `false` if decoding stopped with a fatal error.
+pub fn write_filtered<W: io::Write>(
+    decoder: &mut Decoder,
+    writer: &mut W,
+    filter: impl Fn(&str, &str) -> bool,
+) -> io::Result<bool> {
+    let mut result = Ok(());
+    let decoded = decoder.decode_events(|event| {
+        if result.is_err() {
+            return;
+        }
+        result = match &event {
+            DecodeEvent::Message {
+                offset,
+                message: DecodedMessage::Data { name, record, .. },
+            } => {
+                let keep = |field: &str| filter(name, field);
+                if !record.keys().any(|field| keep(field)) {
+                    return;
+                }
+                write_line(
+                    writer,
+                    &MessageLine {
+                        message: name,
+                        offset: *offset,
+                        fields: Fields {
+                            record,
+                            keep: &keep,
+                        },
+                    },
+                )
+            }
+            DecodeEvent::Message { .. } => Ok(()),
+            DecodeEvent::Error { offset, error } => write_line(
+                writer,
+                &DiagnosticLine {
+                    diagnostic: error,
+                    offset: Some(*offset),
+                    fatal: false,
+                },
+            ),
+        };
+    });
+    result?;
+    if let Err(error) = &decoded {
+        write_line(
+            writer,
+            &DiagnosticLine {
+                diagnostic: error,
+                offset: None,
+                fatal: true,
+            },
+        )?;
+    }
+    writer.flush()?;
+    Ok(decoded.is_ok())
+}
+
+fn write_line<W: io::Write, T: Serialize>(writer: &mut W, line: &T) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, line)?;
+    writer.write_all(b"\n")
+}
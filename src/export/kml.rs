@@ -0,0 +1,273 @@
+//! KML export of tracks for Google Earth, optionally zipped as KMZ
+//!
+//! Each lap becomes a placemark holding a time stamped `gx:Track`, so the laps can be played
+//! back. When a [`ColorMetric`] is chosen, the route is added as a second folder of line
+//! segments coloured from green (low) to red (high) by that metric. `course_point` messages
+//! become point placemarks. KMZ archives require the `kmz` feature.
+
+use super::{
+    activity_name, date_time, escape_xml, first_number, position, rfc3339, split_by_laps, text,
+};
+use crate::decoder::{Messages, Record};
+use std::io;
+
+const HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<kml xmlns="http://www.opengis.net/kml/2.2" xmlns:gx="http://www.google.com/kml/ext/2.2">
+"#;
+
+/// Number of colours of a colour-coded route
+const COLOR_STEPS: usize = 8;
+
+/// Record metric used to colour the route
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMetric {
+    HeartRate,
+    Speed,
+    Power,
+    Cadence,
+    Altitude,
+}
+
+impl ColorMetric {
+    fn name(&self) -> &'static str {
+        match self {
+            ColorMetric::HeartRate => "Heart rate",
+            ColorMetric::Speed => "Speed",
+            ColorMetric::Power => "Power",
+            ColorMetric::Cadence => "Cadence",
+            ColorMetric::Altitude => "Altitude",
+        }
+    }
+    fn value(&self, record: &Record) -> Option<f64> {
+        match self {
+            ColorMetric::HeartRate => first_number(record, &["heart_rate"]),
+            ColorMetric::Speed => first_number(record, &["enhanced_speed", "speed"]),
+            ColorMetric::Power => first_number(record, &["power"]),
+            ColorMetric::Cadence => first_number(record, &["cadence"]),
+            ColorMetric::Altitude => first_number(record, &["enhanced_altitude", "altitude"]),
+        }
+    }
+}
+
+/// Write the messages as a KML document
+pub fn write<W: io::Write>(
+    messages: &Messages,
+    color_by: Option<ColorMetric>,
+    writer: &mut W,
+) -> io::Result<()> {
+    writer.write_all(to_string(messages, color_by).as_bytes())
+}
+
+/// Write the messages as a KMZ archive holding a single `doc.kml`
+#[cfg(feature = "kmz")]
+pub fn write_kmz<W: io::Write>(
+    messages: &Messages,
+    color_by: Option<ColorMetric>,
+    writer: &mut W,
+) -> io::Result<()> {
+    writer.write_all(&to_kmz(messages, color_by)?)
+}
+
+/// Render the messages as a KMZ archive holding a single deflated `doc.kml`
+#[cfg(feature = "kmz")]
+pub fn to_kmz(messages: &Messages, color_by: Option<ColorMetric>) -> io::Result<Vec<u8>> {
+    use io::Write;
+    use zip::write::SimpleFileOptions;
+    use zip::{CompressionMethod, ZipWriter};
+
+    let mut zip = ZipWriter::new(io::Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file("doc.kml", options)?;
+    zip.write_all(to_string(messages, color_by).as_bytes())?;
+    Ok(zip.finish()?.into_inner())
+}
+
+/// Render the messages as a KML document
+pub fn to_string(messages: &Messages, color_by: Option<ColorMetric>) -> String {
+    let records = messages
+        .get("record")
+        .map(|it| it.as_slice())
+        .unwrap_or_default();
+    let laps = messages
+        .get("lap")
+        .map(|it| it.as_slice())
+        .unwrap_or_default();
+    let mut kml = String::from(HEADER);
+    kml.push_str("  <Document>\n");
+    if let Some(name) = activity_name(messages) {
+        kml.push_str(&format!("    <name>{}</name>\n", escape_xml(&name)));
+    }
+    kml.push_str(
+        "    <Style id=\"track\">\n      <LineStyle>\n        <color>ffff7f00</color>\n        <width>4</width>\n      </LineStyle>\n    </Style>\n",
+    );
+    for step in (0..COLOR_STEPS).filter(|_| color_by.is_some()) {
+        kml.push_str(&format!(
+            "    <Style id=\"step{step}\">\n      <LineStyle>\n        <color>{}</color>\n        <width>6</width>\n      </LineStyle>\n    </Style>\n",
+            step_color(step)
+        ));
+    }
+
+    kml.push_str("    <Folder>\n      <name>Laps</name>\n");
+    for (index, segment) in split_by_laps(records, laps).into_iter().enumerate() {
+        write_lap(&mut kml, index, &segment);
+    }
+    kml.push_str("    </Folder>\n");
+    if let Some(metric) = color_by {
+        write_route(&mut kml, records, metric);
+    }
+    let course_points = messages.get("course_point").into_iter().flatten();
+    for course_point in course_points {
+        write_course_point(&mut kml, course_point);
+    }
+    kml.push_str("  </Document>\n</kml>\n");
+    kml
+}
+
+fn write_lap(kml: &mut String, index: usize, records: &[&Record]) {
+    let points = records
+        .iter()
+        .filter_map(|record| {
+            let time = date_time(record, "timestamp")?;
+            let (lat, lon) = position(record, "position")?;
+            let ele = first_number(record, &["enhanced_altitude", "altitude"]);
+            Some((time, lat, lon, ele))
+        })
+        .collect::<Vec<_>>();
+    if points.is_empty() {
+        return;
+    }
+    let altitude_mode = if points.iter().all(|(_, _, _, ele)| ele.is_some()) {
+        "absolute"
+    } else {
+        "clampToGround"
+    };
+    kml.push_str("      <Placemark>\n");
+    kml.push_str(&format!("        <name>Lap {}</name>\n", index + 1));
+    kml.push_str("        <styleUrl>#track</styleUrl>\n");
+    kml.push_str("        <gx:Track>\n");
+    kml.push_str(&format!(
+        "          <altitudeMode>{altitude_mode}</altitudeMode>\n"
+    ));
+    for (time, ..) in &points {
+        kml.push_str(&format!("          <when>{}</when>\n", rfc3339(time)));
+    }
+    for (_, lat, lon, ele) in &points {
+        kml.push_str(&format!(
+            "          <gx:coord>{lon:.7} {lat:.7} {:.1}</gx:coord>\n",
+            ele.unwrap_or(0.0)
+        ));
+    }
+    kml.push_str("        </gx:Track>\n");
+    kml.push_str("      </Placemark>\n");
+}
+
+/// Route split into line segments, one placemark per colour step
+fn write_route(kml: &mut String, records: &[Record], metric: ColorMetric) {
+    let points = records
+        .iter()
+        .filter_map(|record| Some((position(record, "position")?, metric.value(record))))
+        .collect::<Vec<_>>();
+    let values = points.iter().filter_map(|(_, value)| *value);
+    let min = values.clone().fold(f64::INFINITY, f64::min);
+    let max = values.fold(f64::NEG_INFINITY, f64::max);
+    if !min.is_finite() {
+        return;
+    }
+    // value range covered by each step
+    let width = (max - min) / (COLOR_STEPS - 1) as f64;
+    let step = |value: f64| {
+        if width > 0.0 {
+            ((value - min) / width).round() as usize
+        } else {
+            0
+        }
+    };
+    // runs of consecutive points sharing a colour step, each segment takes the step of its end
+    let mut lines: Vec<Vec<Vec<(f64, f64)>>> = vec![Vec::new(); COLOR_STEPS];
+    let mut current: Option<(usize, Vec<(f64, f64)>)> = None;
+    let mut last_step = 0;
+    for window in points.windows(2) {
+        let (start, _) = window[0];
+        let (end, value) = window[1];
+        let segment_step = value.map(step).unwrap_or(last_step);
+        last_step = segment_step;
+        match &mut current {
+            Some((step, line)) if *step == segment_step => line.push(end),
+            _ => {
+                if let Some((step, line)) = current.take() {
+                    lines[step].push(line);
+                }
+                current = Some((segment_step, vec![start, end]));
+            }
+        }
+    }
+    if let Some((step, line)) = current {
+        lines[step].push(line);
+    }
+
+    kml.push_str("    <Folder>\n");
+    kml.push_str(&format!("      <name>{}</name>\n", metric.name()));
+    for (step, lines) in lines.into_iter().enumerate() {
+        if lines.is_empty() {
+            continue;
+        }
+        let low = (min + (step as f64 - 0.5) * width).max(min);
+        let high = (min + (step as f64 + 0.5) * width).min(max);
+        kml.push_str("      <Placemark>\n");
+        kml.push_str(&format!(
+            "        <name>{} {low:.1} - {high:.1}</name>\n",
+            metric.name()
+        ));
+        kml.push_str(&format!("        <styleUrl>#step{step}</styleUrl>\n"));
+        kml.push_str("        <MultiGeometry>\n");
+        for line in lines {
+            let coordinates = line
+                .iter()
+                .map(|(lat, lon)| format!("{lon:.7},{lat:.7}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            kml.push_str(&format!(
+                "          <LineString>\n            <tessellate>1</tessellate>\n            <coordinates>{coordinates}</coordinates>\n          </LineString>\n"
+            ));
+        }
+        kml.push_str("        </MultiGeometry>\n");
+        kml.push_str("      </Placemark>\n");
+    }
+    kml.push_str("    </Folder>\n");
+}
+
+/// KML colour (`aabbggrr`) of a step, from green through yellow to red
+fn step_color(step: usize) -> String {
+    let t = step as f64 / (COLOR_STEPS - 1) as f64;
+    let red = (t * 2.0).min(1.0) * 255.0;
+    let green = ((1.0 - t) * 2.0).min(1.0) * 255.0;
+    format!("ff00{:02x}{:02x}", green.round() as u8, red.round() as u8)
+}
+
+fn write_course_point(kml: &mut String, course_point: &Record) {
+    let (lat, lon) = if let Some(position) = position(course_point, "position") {
+        position
+    } else {
+        return;
+    };
+    kml.push_str("    <Placemark>\n");
+    if let Some(name) = text(course_point, "name") {
+        kml.push_str(&format!("      <name>{}</name>\n", escape_xml(&name)));
+    }
+    if let Some(ty) = text(course_point, "type") {
+        kml.push_str(&format!(
+            "      <description>{}</description>\n",
+            escape_xml(&ty)
+        ));
+    }
+    if let Some(time) = date_time(course_point, "timestamp") {
+        kml.push_str(&format!(
+            "      <TimeStamp>\n        <when>{}</when>\n      </TimeStamp>\n",
+            rfc3339(&time)
+        ));
+    }
+    kml.push_str(&format!(
+        "      <Point>\n        <coordinates>{lon:.7},{lat:.7}</coordinates>\n      </Point>\n"
+    ));
+    kml.push_str("    </Placemark>\n");
+}
@@ -0,0 +1,148 @@
+//! Conversions of decoded [`Messages`](crate::decoder::Messages) into other file formats
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod geojson;
+pub mod gpx;
+pub mod jsonl;
+pub mod kml;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod table;
+pub mod tcx;
+
+use crate::decoder::{Messages, Record};
+use crate::fit;
+use crate::profile::messages;
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// Degrees per semicircle, `180 / 2^31`
+const SEMICIRCLE_TO_DEGREES: f64 = 180.0 / 2147483648.0;
+
+/// Read a numeric field as `f64`
+pub(crate) fn number(record: &Record, name: &str) -> Option<f64> {
+    record
+        .get(name)
+        .filter(|it| it.is_valid())
+        .and_then(|it| it.try_as_f64().ok())
+}
+
+/// Read the first of several numeric fields that is present, e.g. `enhanced_speed` before `speed`
+pub(crate) fn first_number(record: &Record, names: &[&str]) -> Option<f64> {
+    names.iter().find_map(|name| number(record, name))
+}
+
+/// Read a semicircle position field in degrees
+pub(crate) fn degrees(record: &Record, name: &str) -> Option<f64> {
+    number(record, name).map(|it| it * SEMICIRCLE_TO_DEGREES)
+}
+
+/// Read a `(latitude, longitude)` pair in degrees, e.g. `position_lat` and `position_long`
+pub(crate) fn position(record: &Record, prefix: &str) -> Option<(f64, f64)> {
+    Some((
+        degrees(record, &format!("{prefix}_lat"))?,
+        degrees(record, &format!("{prefix}_long"))?,
+    ))
+}
+
+/// Read a `date_time` field
+pub(crate) fn date_time(record: &Record, name: &str) -> Option<DateTime<Utc>> {
+    match record.get(name) {
+        Some(fit::Value::DateTime(val)) => Some(*val),
+        _ => None,
+    }
+}
+
+/// Read a field as text, e.g. the name of a profile type value
+pub(crate) fn text(record: &Record, name: &str) -> Option<String> {
+    record.get(name).map(|it| it.to_string())
+}
+
+/// Course name, falling back to the session sport profile name
+pub(crate) fn activity_name(messages: &Messages) -> Option<String> {
+    messages
+        .get("course")
+        .and_then(|it| it.first())
+        .and_then(|it| text(it, "name"))
+        .or_else(|| {
+            messages
+                .get("session")
+                .and_then(|it| it.first())
+                .and_then(|it| text(it, "sport_profile_name"))
+        })
+}
+
+/// The profile lists the common fields (`message_index`, `timestamp`, ...) first, then the
+/// message fields by number
+pub(crate) fn profile_order(message_type: &str, name: &str) -> (u8, u8) {
+    match messages::field_def(message_type, name) {
+        Some(field) if field.number >= 250 => (0, u8::MAX - field.number),
+        Some(field) => (1, field.number),
+        None => (2, 0),
+    }
+}
+
+/// Read a profile type field, e.g. `sport` as `types::Sport`
+pub(crate) fn profile_type<T>(record: &Record, name: &str) -> Option<T>
+where
+    T: for<'a> TryFrom<&'a fit::Value>,
+{
+    match record.get(name) {
+        Some(fit::Value::Typed(val)) => val.to_type(),
+        _ => None,
+    }
+}
+
+/// Format a time as RFC 3339 in UTC, keeping sub-second precision when present
+pub(crate) fn rfc3339(time: &DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// Escape text for XML content and attribute values
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+/// Split records into one group per lap, using the lap end times
+///
+/// Records after the last lap are added to it, without laps all records form a single group.
+pub(crate) fn split_by_laps<'a>(
+    records: impl IntoIterator<Item = &'a Record>,
+    laps: &[Record],
+) -> Vec<Vec<&'a Record>> {
+    let ends = laps
+        .iter()
+        .filter_map(|it| date_time(it, "timestamp"))
+        .collect::<Vec<_>>();
+    let mut groups = vec![Vec::new(); ends.len().max(1)];
+    let mut index = 0;
+    for record in records {
+        // records without a timestamp stay with the previous record
+        if let Some(time) = date_time(record, "timestamp") {
+            index = lap_index(&ends, time);
+        }
+        groups[index].push(record);
+    }
+    groups
+}
+
+/// Index of the first lap ending at or after `time`, the last lap for later times
+pub(crate) fn lap_index(ends: &[DateTime<Utc>], time: DateTime<Utc>) -> usize {
+    ends.iter()
+        .take_while(|end| **end < time)
+        .count()
+        .min(ends.len().saturating_sub(1))
+}
@@ -0,0 +1,39 @@
+//! Parquet files of the Arrow record batches, one file per message type
+
+use super::arrow::{to_record_batch, to_record_batches};
+use crate::decoder::{Messages, Record};
+use arrow_array::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Write a record batch as a Parquet file
+pub fn write_batch<W: Write + Send>(batch: &RecordBatch, writer: W) -> Result<(), ParquetError> {
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), None)?;
+    writer.write(batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+/// Write the records of one message type as a Parquet file
+pub fn write<W: Write + Send>(
+    message_type: &str,
+    records: &[Record],
+    writer: W,
+) -> Result<(), ParquetError> {
+    write_batch(&to_record_batch(message_type, records)?, writer)
+}
+
+/// Write every message type to `{message_type}.parquet` in the directory, returning the paths
+pub fn write_all(messages: &Messages, dir: &Path) -> Result<Vec<PathBuf>, ParquetError> {
+    to_record_batches(messages)?
+        .iter()
+        .map(|(message_type, batch)| {
+            let path = dir.join(format!("{message_type}.parquet"));
+            write_batch(batch, File::create(&path)?)?;
+            Ok(path)
+        })
+        .collect()
+}
@@ -0,0 +1,291 @@
+//! SQLite export of decoded files, for ad hoc SQL queries
+//!
+//! The `files` table has a row per file of a chained FIT file, holding the file header and the
+//! `file_id` fields. Every other message type gets a table of its own, whose rows link to their
+//! file by `file_index`. Column types derive from the profile field types:
+//!
+//! - `date_time` and `local_date_time` are RFC 3339 `TEXT`, read by the SQLite date functions
+//! - profile types (e.g. `sport`) are `TEXT` value names, bit masks and indices stay `INTEGER`
+//! - scaled fields are `REAL`, other numbers `INTEGER` or `REAL` by base type
+//! - byte arrays are `BLOB`, other array fields JSON `TEXT`, readable with `json_each`
+//!
+//! Writing to an existing database appends: file indices continue after the existing files and
+//! columns missing from existing tables are added.
+
+use super::{profile_order, rfc3339};
+use crate::decoder::{DecodedFile, Record};
+use crate::fit::{self, BaseType};
+use crate::profile::messages;
+use indexmap::IndexSet;
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params_from_iter, Connection, Transaction};
+use std::path::Path;
+
+/// Columns of the `files` table taken from the file header
+const HEADER_COLUMNS: [(&str, &str); 6] = [
+    ("header_size", "INTEGER"),
+    ("protocol_version", "INTEGER"),
+    ("profile_version", "INTEGER"),
+    ("data_size", "INTEGER"),
+    ("data_type", "TEXT"),
+    ("header_crc", "INTEGER"),
+];
+
+/// Write the files into the SQLite database at the path, creating it if needed
+pub fn write_file<P: AsRef<Path>>(path: P, files: &[DecodedFile]) -> rusqlite::Result<()> {
+    let mut connection = Connection::open(path)?;
+    write(&mut connection, files)
+}
+
+/// Write the files into the database in a single transaction
+pub fn write(connection: &mut Connection, files: &[DecodedFile]) -> rusqlite::Result<()> {
+    let transaction = connection.transaction()?;
+    let header_columns = HEADER_COLUMNS
+        .iter()
+        .map(|(name, ty)| format!(", {name} {ty}"))
+        .collect::<String>();
+    transaction.execute(
+        &format!(
+            "CREATE TABLE IF NOT EXISTS files (file_index INTEGER PRIMARY KEY{header_columns})"
+        ),
+        [],
+    )?;
+    let first_index: i64 = transaction.query_row(
+        "SELECT COALESCE(MAX(file_index) + 1, 0) FROM files",
+        [],
+        |row| row.get(0),
+    )?;
+
+    let file_ids = files
+        .iter()
+        .map(|it| it.messages.get("file_id").and_then(|it| it.first()))
+        .collect::<Vec<_>>();
+    let columns = add_columns(
+        &transaction,
+        "files",
+        "file_id",
+        file_ids.iter().flatten().copied(),
+    )?;
+    let rows = files
+        .iter()
+        .zip(&file_ids)
+        .enumerate()
+        .map(|(index, (file, file_id))| {
+            let header = &file.header;
+            let fixed = vec![
+                SqlValue::Integer(first_index + index as i64),
+                SqlValue::Integer(header.header_size as i64),
+                SqlValue::Integer(header.protocol_version as i64),
+                SqlValue::Integer(header.profile_version as i64),
+                SqlValue::Integer(header.data_size as i64),
+                SqlValue::Text(header.data_type.clone()),
+                SqlValue::Integer(header.header_crc as i64),
+            ];
+            (fixed, *file_id)
+        });
+    let fixed_columns = ["file_index"]
+        .into_iter()
+        .chain(HEADER_COLUMNS.iter().map(|(name, _)| *name))
+        .collect::<Vec<_>>();
+    insert(&transaction, "files", &fixed_columns, &columns, rows)?;
+
+    let message_types = files
+        .iter()
+        .flat_map(|it| it.messages.keys())
+        .filter(|it| *it != "file_id")
+        .collect::<IndexSet<_>>();
+    for message_type in message_types {
+        let records = files
+            .iter()
+            .enumerate()
+            .flat_map(|(index, file)| {
+                let file_index = first_index + index as i64;
+                file.messages
+                    .get(message_type)
+                    .into_iter()
+                    .flatten()
+                    .map(move |record| (file_index, record))
+            })
+            .collect::<Vec<_>>();
+        let table = quote(message_type);
+        transaction.execute(
+            &format!("CREATE TABLE IF NOT EXISTS {table} (file_index INTEGER NOT NULL REFERENCES files (file_index))"),
+            [],
+        )?;
+        transaction.execute(
+            &format!(
+                "CREATE INDEX IF NOT EXISTS {} ON {table} (file_index)",
+                quote(&format!("{message_type}_file_index"))
+            ),
+            [],
+        )?;
+        let columns = add_columns(
+            &transaction,
+            message_type,
+            message_type,
+            records.iter().map(|(_, record)| *record),
+        )?;
+        let rows = records
+            .iter()
+            .map(|(file_index, record)| (vec![SqlValue::Integer(*file_index)], Some(*record)));
+        insert(&transaction, message_type, &["file_index"], &columns, rows)?;
+    }
+    transaction.commit()
+}
+
+/// Add the fields of the records missing from the table, returning the field columns with their
+/// SQL types in profile order
+fn add_columns<'a>(
+    transaction: &Transaction,
+    table: &str,
+    message_type: &str,
+    records: impl Iterator<Item = &'a Record> + Clone,
+) -> rusqlite::Result<Vec<(&'static str, String)>> {
+    let mut names = records
+        .clone()
+        .flat_map(|it| it.keys().copied())
+        .collect::<IndexSet<_>>();
+    names.sort_by_cached_key(|name| profile_order(message_type, name));
+
+    let existing = transaction
+        .prepare(&format!("PRAGMA table_info({})", quote(table)))?
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(1)?, row.get::<_, String>(2)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    let mut columns = Vec::with_capacity(names.len());
+    for name in names {
+        if let Some((_, ty)) = existing.iter().find(|(it, _)| it == name) {
+            columns.push((name, ty.clone()));
+            continue;
+        }
+        let values = records
+            .clone()
+            .filter_map(|it| it.get(name))
+            .collect::<Vec<_>>();
+        let ty = column_type(message_type, name, &values);
+        transaction.execute(
+            &format!(
+                "ALTER TABLE {} ADD COLUMN {} {ty}",
+                quote(table),
+                quote(name)
+            ),
+            [],
+        )?;
+        columns.push((name, ty.to_string()));
+    }
+    Ok(columns)
+}
+
+fn insert<'a>(
+    transaction: &Transaction,
+    table: &str,
+    fixed_columns: &[&str],
+    columns: &[(&'static str, String)],
+    rows: impl Iterator<Item = (Vec<SqlValue>, Option<&'a Record>)>,
+) -> rusqlite::Result<()> {
+    let names = fixed_columns
+        .iter()
+        .copied()
+        .chain(columns.iter().map(|(name, _)| *name))
+        .map(quote)
+        .collect::<Vec<_>>();
+    let placeholders = (1..=names.len())
+        .map(|it| format!("?{it}"))
+        .collect::<Vec<_>>();
+    let mut statement = transaction.prepare(&format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        quote(table),
+        names.join(", "),
+        placeholders.join(", ")
+    ))?;
+    for (mut values, record) in rows {
+        values.extend(columns.iter().map(|(name, ty)| {
+            record
+                .and_then(|it| it.get(name))
+                .map(|it| sql_value(it, ty))
+                .unwrap_or(SqlValue::Null)
+        }));
+        statement.execute(params_from_iter(values))?;
+    }
+    Ok(())
+}
+
+fn column_type(message_type: &str, name: &str, values: &[&fit::Value]) -> &'static str {
+    if values.iter().any(|it| it.to_bytes().is_some()) {
+        return "BLOB";
+    }
+    if values.iter().any(|it| matches!(it, fit::Value::Array(_))) {
+        return "TEXT";
+    }
+    let field_def = if let Some(field_def) = messages::field_def(message_type, name) {
+        field_def
+    } else {
+        return values.first().map(|it| value_type(it)).unwrap_or("TEXT");
+    };
+    match field_def.field_ty {
+        "date_time" | "local_date_time" => return "TEXT",
+        "bool" => return "INTEGER",
+        _ => {}
+    }
+    if field_def.scale != 1.0 || field_def.offset != 0.0 {
+        return "REAL";
+    }
+    // types such as `message_index` only name some special values
+    let has_names = values
+        .iter()
+        .any(|it| matches!(it, fit::Value::Typed(val) if val.name.is_some()));
+    if has_names {
+        return "TEXT";
+    }
+    match field_def.base_type {
+        BaseType::Float32 | BaseType::Float64 => "REAL",
+        BaseType::String => "TEXT",
+        _ => "INTEGER",
+    }
+}
+
+/// Type of a value of a field the profile does not define
+fn value_type(value: &fit::Value) -> &'static str {
+    match value {
+        fit::Value::Float32(_) | fit::Value::Float64(_) => "REAL",
+        fit::Value::Bool(_) | fit::Value::Flags(_) => "INTEGER",
+        fit::Value::DateTime(_)
+        | fit::Value::LocalDateTime(_)
+        | fit::Value::Typed(_)
+        | fit::Value::String(_) => "TEXT",
+        _ if value.try_as_i64().is_ok() => "INTEGER",
+        _ => "TEXT",
+    }
+}
+
+fn sql_value(value: &fit::Value, column_type: &str) -> SqlValue {
+    if column_type == "BLOB" {
+        if let Some(bytes) = value.to_bytes() {
+            return SqlValue::Blob(bytes);
+        }
+    }
+    match value {
+        fit::Value::DateTime(val) => SqlValue::Text(rfc3339(val)),
+        fit::Value::LocalDateTime(val) => SqlValue::Text(val.to_rfc3339()),
+        fit::Value::Array(_) => SqlValue::Text(serde_json::to_string(value).unwrap_or_default()),
+        fit::Value::Bool(val) => SqlValue::Integer(*val as i64),
+        // bool fields decode as "0" and "1"
+        fit::Value::String(val) if column_type == "INTEGER" => val
+            .parse()
+            .map(SqlValue::Integer)
+            .unwrap_or_else(|_| SqlValue::Text(val.clone())),
+        fit::Value::String(val) => SqlValue::Text(val.clone()),
+        _ if column_type == "TEXT" => SqlValue::Text(value.to_string()),
+        _ => value
+            .try_as_i64()
+            .map(SqlValue::Integer)
+            .or_else(|_| value.try_as_f64().map(SqlValue::Real))
+            .unwrap_or_else(|_| SqlValue::Text(value.to_string())),
+    }
+}
+
+/// Quote an SQL identifier
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
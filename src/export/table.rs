@@ -0,0 +1,181 @@
+//! Flat CSV/TSV export, one table per message type
+//!
+//! Columns are the union of the field names of all records, in profile order, followed by
+//! fields the profile does not define (such as sub-fields) in order of first appearance.
+//! Units are appended to the column names, e.g. `heart_rate [bpm]`. Missing values are
+//! left empty.
+
+use super::{profile_order, rfc3339};
+use crate::decoder::{Messages, Record};
+use crate::fit;
+use crate::profile::messages;
+use indexmap::IndexMap;
+use std::io;
+
+/// Rendering of `date_time` and `local_date_time` values
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// RFC 3339, e.g. `2021-09-08T01:46:40Z`
+    #[default]
+    Rfc3339,
+    /// Seconds since the UNIX epoch
+    Unix,
+    /// Seconds since the FIT epoch (1989-12-31T00:00:00Z), as stored in the file
+    Fit,
+}
+
+/// Options of the table export, [`TableOptions::csv`] by default
+#[derive(Debug, Clone)]
+pub struct TableOptions {
+    /// Cell delimiter, `,` for CSV and `\t` for TSV
+    pub delimiter: char,
+    pub timestamp_format: TimestampFormat,
+    /// Split array fields into one column per element, e.g. `left_power_phase_0`,
+    /// otherwise elements are joined with `|`
+    pub flatten_arrays: bool,
+}
+
+impl TableOptions {
+    pub fn csv() -> Self {
+        TableOptions {
+            delimiter: ',',
+            timestamp_format: TimestampFormat::default(),
+            flatten_arrays: true,
+        }
+    }
+    pub fn tsv() -> Self {
+        TableOptions {
+            delimiter: '\t',
+            ..Self::csv()
+        }
+    }
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self::csv()
+    }
+}
+
+/// Write the records of one message type as a table
+pub fn write<W: io::Write>(
+    message_type: &str,
+    records: &[Record],
+    options: &TableOptions,
+    writer: &mut W,
+) -> io::Result<()> {
+    writer.write_all(to_string(message_type, records, options).as_bytes())
+}
+
+/// Render every message type as its own table, keyed by message type
+pub fn to_tables(messages: &Messages, options: &TableOptions) -> IndexMap<String, String> {
+    messages
+        .iter()
+        .map(|(message_type, records)| {
+            (
+                message_type.to_owned(),
+                to_string(message_type, records, options),
+            )
+        })
+        .collect()
+}
+
+/// Render the records of one message type as a table
+pub fn to_string(message_type: &str, records: &[Record], options: &TableOptions) -> String {
+    // column name and the number of array elements, 0 for scalar columns
+    let mut columns: IndexMap<&'static str, usize> = IndexMap::new();
+    for record in records {
+        for (name, value) in record {
+            let len = match value {
+                fit::Value::Array(values) if options.flatten_arrays => values.len(),
+                _ => 0,
+            };
+            let column = columns.entry(name).or_default();
+            *column = (*column).max(len);
+        }
+    }
+    columns.sort_by_cached_key(|name, _| profile_order(message_type, name));
+
+    let delimiter = options.delimiter.to_string();
+    let mut table = String::new();
+    let header = columns
+        .iter()
+        .flat_map(|(name, len)| {
+            let units = messages::field_def(message_type, name)
+                .map(|it| it.units)
+                .filter(|it| !it.is_empty())
+                .map(|it| format!(" [{it}]"))
+                .unwrap_or_default();
+            column_names(name, *len)
+                .into_iter()
+                .map(move |it| escape(&format!("{it}{units}"), options.delimiter))
+        })
+        .collect::<Vec<_>>();
+    table.push_str(&header.join(&delimiter));
+    table.push('\n');
+    for record in records {
+        let cells = columns
+            .iter()
+            .flat_map(|(name, len)| {
+                let value = record.get(name);
+                if *len == 0 {
+                    return vec![value.map(|it| cell(it, options)).unwrap_or_default()];
+                }
+                (0..*len)
+                    .map(|index| {
+                        let element = match value {
+                            Some(fit::Value::Array(values)) => values.get(index),
+                            // scalar values of an array column fill its first element
+                            Some(value) if index == 0 => Some(value),
+                            _ => None,
+                        };
+                        element.map(|it| cell(it, options)).unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .map(|it| escape(&it, options.delimiter))
+            .collect::<Vec<_>>();
+        table.push_str(&cells.join(&delimiter));
+        table.push('\n');
+    }
+    table
+}
+
+fn column_names(name: &str, len: usize) -> Vec<String> {
+    if len == 0 {
+        vec![name.to_string()]
+    } else {
+        (0..len).map(|index| format!("{name}_{index}")).collect()
+    }
+}
+
+fn cell(value: &fit::Value, options: &TableOptions) -> String {
+    match (value, options.timestamp_format) {
+        (fit::Value::DateTime(val), TimestampFormat::Rfc3339) => rfc3339(val),
+        (fit::Value::DateTime(val), TimestampFormat::Unix) => val.timestamp().to_string(),
+        (fit::Value::DateTime(val), TimestampFormat::Fit) => {
+            (val.timestamp() - fit::FIT_EPOCH_OFFSET).to_string()
+        }
+        (fit::Value::LocalDateTime(val), TimestampFormat::Rfc3339) => val.to_rfc3339(),
+        (fit::Value::LocalDateTime(val), TimestampFormat::Unix) => val.timestamp().to_string(),
+        // local times are stored as local seconds
+        (fit::Value::LocalDateTime(val), TimestampFormat::Fit) => {
+            (val.naive_local().and_utc().timestamp() - fit::FIT_EPOCH_OFFSET).to_string()
+        }
+        (fit::Value::Array(values), _) => values
+            .iter()
+            .map(|it| cell(it, options))
+            .collect::<Vec<_>>()
+            .join("|"),
+        _ => value.to_string(),
+    }
+}
+
+/// Quote a cell containing the delimiter, quotes or line breaks
+fn escape(text: &str, delimiter: char) -> String {
+    if text.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
@@ -0,0 +1,223 @@
+//! Training Center XML (TCX) export of activities
+//!
+//! Each `lap` message becomes a `Lap` holding the `record`s up to its end time as
+//! `Trackpoint`s. Files without laps are written as a single lap built from the `session`.
+//! Speed, running cadence and power are written to the Garmin ActivityExtension v2.
+
+use super::{date_time, first_number, number, position, profile_type, rfc3339, split_by_laps};
+use crate::decoder::{Messages, Record};
+use crate::profile::types;
+use chrono::{DateTime, Utc};
+use std::io;
+
+const HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<TrainingCenterDatabase xmlns="http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2" xmlns:ns3="http://www.garmin.com/xmlschemas/ActivityExtension/v2" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2 http://www.garmin.com/xmlschemas/TrainingCenterDatabasev2.xsd http://www.garmin.com/xmlschemas/ActivityExtension/v2 http://www.garmin.com/xmlschemas/ActivityExtensionv2.xsd">
+"#;
+
+/// Write the messages as a TCX document
+pub fn write<W: io::Write>(messages: &Messages, writer: &mut W) -> io::Result<()> {
+    writer.write_all(to_string(messages).as_bytes())
+}
+
+/// Render the messages as a TCX document
+pub fn to_string(messages: &Messages) -> String {
+    let mut tcx = String::from(HEADER);
+    let records = messages
+        .get("record")
+        .map(|it| it.as_slice())
+        .unwrap_or_default();
+    let laps = messages
+        .get("lap")
+        .map(|it| it.as_slice())
+        .unwrap_or_default();
+    let session = messages.get("session").and_then(|it| it.first());
+    // without laps the session stands in for the single lap
+    let laps = if laps.is_empty() {
+        session.map(std::slice::from_ref).unwrap_or_default()
+    } else {
+        laps
+    };
+    let start_time = session
+        .or(laps.first())
+        .and_then(|it| date_time(it, "start_time"))
+        .or_else(|| records.iter().find_map(|it| date_time(it, "timestamp")));
+    let start_time = if let Some(start_time) = start_time {
+        start_time
+    } else {
+        tcx.push_str("  <Activities/>\n</TrainingCenterDatabase>\n");
+        return tcx;
+    };
+    let sport = sport(messages);
+
+    tcx.push_str("  <Activities>\n");
+    tcx.push_str(&format!("    <Activity Sport=\"{sport}\">\n"));
+    tcx.push_str(&format!("      <Id>{}</Id>\n", rfc3339(&start_time)));
+    let segments = split_by_laps(records, laps);
+    for (index, segment) in segments.into_iter().enumerate() {
+        write_lap(&mut tcx, laps.get(index), &segment, start_time, sport);
+    }
+    tcx.push_str("    </Activity>\n");
+    tcx.push_str("  </Activities>\n");
+    tcx.push_str("</TrainingCenterDatabase>\n");
+    tcx
+}
+
+/// TCX only knows `Running`, `Biking` and `Other`
+fn sport(messages: &Messages) -> &'static str {
+    let sport = ["session", "sport", "course"]
+        .iter()
+        .filter_map(|name| messages.get(*name).and_then(|it| it.first()))
+        .find_map(|it| profile_type::<types::Sport>(it, "sport"));
+    match sport {
+        Some(types::Sport::Running) => "Running",
+        Some(types::Sport::Cycling | types::Sport::EBiking) => "Biking",
+        _ => "Other",
+    }
+}
+
+fn intensity(lap: &Record) -> &'static str {
+    match profile_type::<types::Intensity>(lap, "intensity") {
+        Some(types::Intensity::Rest | types::Intensity::Recovery) => "Resting",
+        _ => "Active",
+    }
+}
+
+fn trigger_method(lap: &Record) -> &'static str {
+    match profile_type::<types::LapTrigger>(lap, "lap_trigger") {
+        Some(types::LapTrigger::Time) => "Time",
+        Some(types::LapTrigger::Distance) => "Distance",
+        Some(
+            types::LapTrigger::PositionStart
+            | types::LapTrigger::PositionLap
+            | types::LapTrigger::PositionWaypoint
+            | types::LapTrigger::PositionMarked,
+        ) => "Location",
+        _ => "Manual",
+    }
+}
+
+fn write_lap(
+    tcx: &mut String,
+    lap: Option<&Record>,
+    records: &[&Record],
+    activity_start: DateTime<Utc>,
+    sport: &str,
+) {
+    let start_time = lap
+        .and_then(|it| date_time(it, "start_time"))
+        .or_else(|| records.iter().find_map(|it| date_time(it, "timestamp")))
+        .unwrap_or(activity_start);
+    let total = |name: &str| lap.and_then(|it| number(it, name)).unwrap_or(0.0);
+    tcx.push_str(&format!(
+        "      <Lap StartTime=\"{}\">\n",
+        rfc3339(&start_time)
+    ));
+    tcx.push_str(&format!(
+        "        <TotalTimeSeconds>{:.3}</TotalTimeSeconds>\n",
+        total("total_timer_time")
+    ));
+    tcx.push_str(&format!(
+        "        <DistanceMeters>{:.2}</DistanceMeters>\n",
+        total("total_distance")
+    ));
+    if let Some(speed) = lap.and_then(|it| first_number(it, &["enhanced_max_speed", "max_speed"])) {
+        tcx.push_str(&format!(
+            "        <MaximumSpeed>{speed:.3}</MaximumSpeed>\n"
+        ));
+    }
+    tcx.push_str(&format!(
+        "        <Calories>{}</Calories>\n",
+        total("total_calories")
+    ));
+    if let Some(hr) = lap.and_then(|it| number(it, "avg_heart_rate")) {
+        tcx.push_str(&format!(
+            "        <AverageHeartRateBpm>\n          <Value>{hr}</Value>\n        </AverageHeartRateBpm>\n"
+        ));
+    }
+    if let Some(hr) = lap.and_then(|it| number(it, "max_heart_rate")) {
+        tcx.push_str(&format!(
+            "        <MaximumHeartRateBpm>\n          <Value>{hr}</Value>\n        </MaximumHeartRateBpm>\n"
+        ));
+    }
+    tcx.push_str(&format!(
+        "        <Intensity>{}</Intensity>\n",
+        lap.map(intensity).unwrap_or("Active")
+    ));
+    if sport != "Running" {
+        if let Some(cadence) = lap.and_then(|it| number(it, "avg_cadence")) {
+            tcx.push_str(&format!("        <Cadence>{cadence}</Cadence>\n"));
+        }
+    }
+    tcx.push_str(&format!(
+        "        <TriggerMethod>{}</TriggerMethod>\n",
+        lap.map(trigger_method).unwrap_or("Manual")
+    ));
+    let points = records
+        .iter()
+        .filter_map(|it| track_point(it, sport))
+        .collect::<Vec<_>>();
+    if !points.is_empty() {
+        tcx.push_str("        <Track>\n");
+        for point in points {
+            tcx.push_str(&point);
+        }
+        tcx.push_str("        </Track>\n");
+    }
+    tcx.push_str("      </Lap>\n");
+}
+
+fn track_point(record: &Record, sport: &str) -> Option<String> {
+    let time = date_time(record, "timestamp")?;
+    let mut point = String::from("          <Trackpoint>\n");
+    point.push_str(&format!("            <Time>{}</Time>\n", rfc3339(&time)));
+    if let Some((lat, lon)) = position(record, "position") {
+        point.push_str(&format!(
+            "            <Position>\n              <LatitudeDegrees>{lat:.7}</LatitudeDegrees>\n              <LongitudeDegrees>{lon:.7}</LongitudeDegrees>\n            </Position>\n"
+        ));
+    }
+    if let Some(ele) = first_number(record, &["enhanced_altitude", "altitude"]) {
+        point.push_str(&format!(
+            "            <AltitudeMeters>{ele:.1}</AltitudeMeters>\n"
+        ));
+    }
+    if let Some(distance) = number(record, "distance") {
+        point.push_str(&format!(
+            "            <DistanceMeters>{distance:.2}</DistanceMeters>\n"
+        ));
+    }
+    if let Some(hr) = number(record, "heart_rate").filter(|it| *it > 0.0) {
+        point.push_str(&format!(
+            "            <HeartRateBpm>\n              <Value>{hr}</Value>\n            </HeartRateBpm>\n"
+        ));
+    }
+    let cadence = number(record, "cadence");
+    if sport != "Running" {
+        if let Some(cadence) = cadence {
+            point.push_str(&format!("            <Cadence>{cadence}</Cadence>\n"));
+        }
+    }
+
+    let mut tpx = String::new();
+    if let Some(speed) = first_number(record, &["enhanced_speed", "speed"]) {
+        tpx.push_str(&format!(
+            "                <ns3:Speed>{speed:.3}</ns3:Speed>\n"
+        ));
+    }
+    if sport == "Running" {
+        if let Some(cadence) = cadence {
+            tpx.push_str(&format!(
+                "                <ns3:RunCadence>{cadence}</ns3:RunCadence>\n"
+            ));
+        }
+    }
+    if let Some(power) = number(record, "power") {
+        tpx.push_str(&format!("                <ns3:Watts>{power}</ns3:Watts>\n"));
+    }
+    if !tpx.is_empty() {
+        point.push_str("            <Extensions>\n              <ns3:TPX>\n");
+        point.push_str(&tpx);
+        point.push_str("              </ns3:TPX>\n            </Extensions>\n");
+    }
+    point.push_str("          </Trackpoint>\n");
+    Some(point)
+}
@@ -1,7 +1,13 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Utc};
 use serde::ser::SerializeSeq;
 use serde::{Serialize, Serializer};
 use std::fmt;
+
+/// The second offset between UNIX and FIT Epochs.
+pub const FIT_EPOCH_OFFSET: i64 = 631065600;
+/// `date_time` values below this are system time (seconds from device power on).
+pub(crate) const SYSTEM_TIME_MAX: u32 = 0x10000000;
+
 #[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
 pub enum BaseType {
     Enum = 0x00,
@@ -168,6 +174,43 @@ impl fmt::Display for BaseType {
     }
 }
 
+/// A value of a profile type, keeps the raw value next to the resolved name
+#[derive(Clone, PartialEq, PartialOrd)]
+pub struct TypedValue {
+    /// Profile type name, e.g. `sport`
+    pub ty: &'static str,
+    /// Raw value as read from the file
+    pub value: Box<Value>,
+    /// Resolved value name, `None` if the profile does not define the raw value
+    pub name: Option<String>,
+}
+
+impl TypedValue {
+    /// Convert to the generated profile type, e.g. `types::Sport`
+    pub fn to_type<T>(&self) -> Option<T>
+    where
+        T: for<'a> TryFrom<&'a Value>,
+    {
+        T::try_from(&self.value).ok()
+    }
+}
+
+/// A value of a bit mask profile type, keeps the raw value next to the set flag names
+#[derive(Clone, PartialEq, PartialOrd, Serialize)]
+pub struct FlagsValue {
+    /// Profile type name, e.g. `sport_bits_0`
+    #[serde(skip)]
+    pub ty: &'static str,
+    /// Raw value as read from the file
+    pub value: Box<Value>,
+    /// Names of the set flags, bits the profile does not define are only kept in `value`
+    pub flags: Vec<String>,
+    /// Value under the value mask of types mixing a value with flags, e.g. the percent
+    /// contribution of `left_right_balance`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub masked_value: Option<u64>,
+}
+
 #[derive(Clone, PartialEq, PartialOrd)]
 pub enum Value {
     Enum(u8),
@@ -188,7 +231,11 @@ pub enum Value {
     UInt64(u64),
     UInt64z(u64),
     // Supplementary fields
-    DateTime(DateTime<Utc>), // Appears only after parsing
+    DateTime(DateTime<Utc>),              // Appears only after parsing
+    LocalDateTime(DateTime<FixedOffset>), // Appears only after applying a UTC offset
+    Typed(TypedValue),                    // Appears only after parsing
+    Flags(FlagsValue),                    // Appears only after parsing
+    Bytes(Vec<u8>),                       // Appears only after parsing
     Bool(bool),
     Array(Vec<Self>),
 }
@@ -216,7 +263,15 @@ impl Value {
             Value::UInt64z(val) => *val != 0x0,
             // Supplementary fields
             Value::DateTime(_) => true, // Appears only after parsing
+            Value::LocalDateTime(_) => true,
+            Value::Typed(val) => val.value.is_valid(),
+            Value::Flags(val) => val.value.is_valid(),
+            Value::Bytes(val) => val.iter().any(|it| *it != 0xFF),
             Value::Bool(_) => true,
+            // a byte array is only invalid when all of its bytes are
+            Value::Array(vals) if vals.iter().all(|v| matches!(v, Value::Byte(_))) => {
+                vals.iter().any(|v| v.is_valid())
+            }
             Value::Array(vals) => !vals.is_empty() && vals.iter().all(|v| v.is_valid()),
         }
     }
@@ -230,27 +285,50 @@ impl Value {
     //         _ => Err("Cannot be converted to 'u8' type."),
     //     }
     // }
-    // pub fn try_as_f64(&self) -> Result<f64, &'static str> {
-    //     match self {
-    //         Value::SInt8(v) => Ok(*v as f64),
-    //         Value::SInt16(v) => Ok(*v as f64),
-    //         Value::SInt32(v) => Ok(*v as f64),
-    //         // It will overflow.
-    //         // Value::SInt64(v) => Ok(*v as f64),
-    //         Value::UInt8(v) => Ok(*v as f64),
-    //         Value::UInt16(v) => Ok(*v as f64),
-    //         Value::UInt32(v) => Ok(*v as f64),
-    //         // Value::UInt64(v) => Ok(*v as f64),
-    //         Value::UInt8z(v) => Ok(*v as f64),
-    //         Value::UInt16z(v) => Ok(*v as f64),
-    //         Value::UInt32z(v) => Ok(*v as f64),
-    //         // Value::UInt64z(v) => Ok(*v as f64),
-    //         Value::Float32(v) => Ok(*v as f64),
-    //         Value::Float64(v) => Ok(*v),
-    //         Value::Byte(v) => Ok(*v as f64),
-    //         _ => Err("Cannot be converted to 'f64' type."),
-    //     }
-    // }
+    pub fn try_as_f64(&self) -> Result<f64, &'static str> {
+        match self {
+            Value::SInt8(v) => Ok(*v as f64),
+            Value::SInt16(v) => Ok(*v as f64),
+            Value::SInt32(v) => Ok(*v as f64),
+            Value::SInt64(v) => Ok(*v as f64),
+            Value::UInt8(v) => Ok(*v as f64),
+            Value::UInt16(v) => Ok(*v as f64),
+            Value::UInt32(v) => Ok(*v as f64),
+            Value::UInt64(v) => Ok(*v as f64),
+            Value::UInt8z(v) => Ok(*v as f64),
+            Value::UInt16z(v) => Ok(*v as f64),
+            Value::UInt32z(v) => Ok(*v as f64),
+            Value::UInt64z(v) => Ok(*v as f64),
+            Value::Float32(v) => Ok(*v as f64),
+            Value::Float64(v) => Ok(*v),
+            Value::Byte(v) => Ok(*v as f64),
+            Value::Typed(v) => v.value.try_as_f64(),
+            Value::Flags(v) => v.value.try_as_f64(),
+            _ => Err("Cannot be converted to 'f64' type."),
+        }
+    }
+    pub fn try_as_i64(&self) -> Result<i64, &'static str> {
+        const ERR: &str = "Cannot be converted to 'i64' type.";
+        match self {
+            Value::Enum(v) => Ok(*v as i64),
+            Value::SInt8(v) => Ok(*v as i64),
+            Value::SInt16(v) => Ok(*v as i64),
+            Value::SInt32(v) => Ok(*v as i64),
+            Value::SInt64(v) => Ok(*v),
+            Value::UInt8(v) => Ok(*v as i64),
+            Value::UInt16(v) => Ok(*v as i64),
+            Value::UInt32(v) => Ok(*v as i64),
+            Value::UInt64(v) => i64::try_from(*v).map_err(|_| ERR),
+            Value::UInt8z(v) => Ok(*v as i64),
+            Value::UInt16z(v) => Ok(*v as i64),
+            Value::UInt32z(v) => Ok(*v as i64),
+            Value::UInt64z(v) => i64::try_from(*v).map_err(|_| ERR),
+            Value::Byte(v) => Ok(*v as i64),
+            Value::Typed(v) => v.value.try_as_i64(),
+            Value::Flags(v) => v.value.try_as_i64(),
+            _ => Err(ERR),
+        }
+    }
     pub fn try_as_usize(&self) -> Result<usize, &'static str> {
         match self {
             Value::UInt8(v) => Ok(*v as usize),
@@ -261,10 +339,27 @@ impl Value {
             Value::UInt16z(v) => Ok(*v as usize),
             Value::UInt32z(v) => Ok(*v as usize),
             Value::Byte(v) => Ok(*v as usize),
+            Value::Typed(v) => v.value.try_as_usize(),
+            Value::Flags(v) => v.value.try_as_usize(),
             _ => Err("Cannot be converted to 'usize' type."),
         }
     }
 
+    /// Bytes of a `Bytes` value or of an array of `Byte` values
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            Value::Bytes(val) => Some(val.clone()),
+            Value::Array(vals) => vals
+                .iter()
+                .map(|it| match it {
+                    Value::Byte(val) => Some(*val),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+
     #[allow(unused)]
     pub fn to_base_type_str(&self) -> &str {
         match self {
@@ -282,10 +377,14 @@ impl Value {
             Value::UInt16z(_) => "uint16z",
             Value::UInt32z(_) => "uint32z",
             Value::Byte(_) => "byte",
+            Value::Bytes(_) => "byte",
             Value::SInt64(_) => "sint64",
             Value::UInt64(_) => "uint64",
             Value::UInt64z(_) => "uint64z",
             Value::DateTime(_) => "uint32",
+            Value::LocalDateTime(_) => "uint32",
+            Value::Typed(val) => val.value.to_base_type_str(),
+            Value::Flags(val) => val.value.to_base_type_str(),
             Value::Bool(_) => "byte",
             Value::Array(arr) => arr[0].to_base_type_str(),
         }
@@ -296,6 +395,19 @@ impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
             Value::DateTime(val) => val.to_string(),
+            Value::LocalDateTime(val) => val.to_string(),
+            Value::Typed(val) => match &val.name {
+                Some(name) => name.to_owned(),
+                None => val.value.to_string(),
+            },
+            Value::Flags(val) => val
+                .masked_value
+                .map(|it| it.to_string())
+                .into_iter()
+                .chain(val.flags.iter().cloned())
+                .collect::<Vec<_>>()
+                .join("|"),
+            Value::Bytes(val) => to_hex(val),
             Value::Enum(val) => val.to_string(),
             Value::SInt8(val) => val.to_string(),
             Value::UInt8(val) => val.to_string(),
@@ -323,6 +435,14 @@ impl fmt::Debug for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Value::DateTime(val) => write!(f, "DateTime({:?})", val),
+            Value::LocalDateTime(val) => write!(f, "LocalDateTime({:?})", val),
+            Value::Typed(val) => write!(f, "Typed({}, {:?}, {:?})", val.ty, val.value, val.name),
+            Value::Flags(val) => write!(
+                f,
+                "Flags({}, {:?}, {:?}, {:?})",
+                val.ty, val.value, val.flags, val.masked_value
+            ),
+            Value::Bytes(val) => write!(f, "Bytes({})", to_hex(val)),
             Value::Enum(val) => write!(f, "Enum({:?})", val),
             Value::SInt8(val) => write!(f, "SInt8({:?})", val),
             Value::UInt8(val) => write!(f, "UInt8({:?})", val),
@@ -374,6 +494,10 @@ impl From<&Value> for BaseType {
             Value::UInt64(_) => BaseType::UInt64,
             Value::UInt64z(_) => BaseType::UInt64z,
             Value::DateTime(_) => BaseType::UInt32,
+            Value::LocalDateTime(_) => BaseType::UInt32,
+            Value::Typed(val) => BaseType::from(val.value.as_ref()),
+            Value::Flags(val) => BaseType::from(val.value.as_ref()),
+            Value::Bytes(_) => BaseType::Byte,
             Value::Bool(_) => BaseType::Byte,
             Value::Array(arr) => BaseType::from(&arr[0]),
         }
@@ -404,6 +528,13 @@ impl Serialize for Value {
             Value::UInt64(val) => serializer.serialize_u64(*val),
             Value::UInt64z(val) => serializer.serialize_u64(*val),
             Value::DateTime(val) => serializer.serialize_str(&val.format("%+").to_string()),
+            Value::LocalDateTime(val) => serializer.serialize_str(&val.format("%+").to_string()),
+            Value::Typed(val) => match &val.name {
+                Some(name) => serializer.serialize_str(name),
+                None => val.value.serialize(serializer),
+            },
+            Value::Flags(val) => val.serialize(serializer),
+            Value::Bytes(val) => serializer.serialize_str(&to_hex(val)),
             Value::Bool(val) => serializer.serialize_bool(*val),
             Value::Array(arr) => {
                 let mut seq = serializer.serialize_seq(Some(arr.len()))?;
@@ -416,6 +547,27 @@ impl Serialize for Value {
     }
 }
 
+/// Lowercase hex rendering of a byte string
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|it| format!("{:02x}", it)).collect()
+}
+
+/// Render 16 bytes as a hyphenated UUID, e.g. `a1b2c3d4-...`
+pub(crate) fn to_uuid(bytes: &[u8]) -> Option<String> {
+    if bytes.len() != 16 {
+        return None;
+    }
+    let hex = to_hex(bytes);
+    Some(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -423,4 +575,20 @@ mod tests {
     fn it_works() {
         println!("{:?} {}", Value::UInt32(55), Value::UInt32(55))
     }
+    #[test]
+    fn typed_value_serialize() {
+        let known = Value::Typed(TypedValue {
+            ty: "sport",
+            value: Box::new(Value::Enum(2)),
+            name: Some("cycling".to_string()),
+        });
+        let unknown = Value::Typed(TypedValue {
+            ty: "sport",
+            value: Box::new(Value::Enum(254)),
+            name: None,
+        });
+        assert_eq!(serde_json::to_string(&known).unwrap(), "\"cycling\"");
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "254");
+        assert_eq!(unknown.to_string(), "254");
+    }
 }
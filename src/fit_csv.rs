@@ -0,0 +1,333 @@
+//! Conversion to and from the CSV format of Garmin's FitCSVTool
+//!
+//! Each definition or data message is a row of `Type,Local Number,Message` followed by
+//! `Field,Value,Units` triples. Definition rows hold the number of elements of each field,
+//! data rows hold the decoded values: scaled numbers, profile type names, and date times as
+//! raw FIT seconds. Array elements are joined with `|`.
+//!
+//! Reading maps every value back through the profile, so hand-edited files can be encoded
+//! again. Fields the profile does not know are written as `unknown` and dropped on reading,
+//! as are developer fields.
+
+use crate::decoder::{
+    Architecture, DecodedMessage, Decoder, FieldDefinition, FitDefinitionMessage, Messages,
+};
+use crate::encoder::Encoder;
+use crate::error::{ErrorKind, ParserResult};
+use crate::fit::{self, BaseType};
+use crate::profile::{messages, types};
+use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::io;
+
+/// Write the messages as FitCSVTool CSV
+pub fn write<W: io::Write>(messages: &[DecodedMessage], writer: &mut W) -> io::Result<()> {
+    writer.write_all(to_string(messages).as_bytes())
+}
+
+/// Render the messages as FitCSVTool CSV
+pub fn to_string(messages: &[DecodedMessage]) -> String {
+    let rows = messages
+        .iter()
+        .map(|message| match message {
+            DecodedMessage::Definition(def) => {
+                definition_row(&message_name(def.global_message_number), def)
+            }
+            DecodedMessage::Data {
+                local_message_number,
+                name,
+                record,
+            } => {
+                let mut row = vec![
+                    "Data".to_string(),
+                    local_message_number.to_string(),
+                    name.to_owned(),
+                ];
+                for (field, value) in record {
+                    let units = messages::field_def(name, field)
+                        .map(|it| it.units)
+                        .unwrap_or_default();
+                    row.push(field.to_string());
+                    row.push(quote(&csv_value(value)));
+                    row.push(units.to_string());
+                }
+                row
+            }
+        })
+        .collect::<Vec<_>>();
+    let triples = rows.iter().map(|it| (it.len() - 3) / 3).max().unwrap_or(0);
+    let mut csv = String::from("Type,Local Number,Message");
+    for index in 1..=triples {
+        csv.push_str(&format!(",Field {index},Value {index},Units {index}"));
+    }
+    csv.push('\n');
+    for row in rows {
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn message_name(global_message_number: u16) -> String {
+    types::MesgNum::try_from(&fit::Value::UInt16(global_message_number))
+        .map(|it| it.to_string())
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+fn definition_row(name: &str, def: &FitDefinitionMessage) -> Vec<String> {
+    let mut row = vec![
+        "Definition".to_string(),
+        def.local_message_number.to_string(),
+        name.to_string(),
+    ];
+    for field in &def.field_definitions {
+        let field_name =
+            messages::field_name(name, field.field_definition_number).unwrap_or("unknown");
+        let count = if field.base_type == BaseType::String {
+            field.size
+        } else {
+            field.size / field.base_type.size()
+        };
+        row.push(field_name.to_string());
+        row.push(count.to_string());
+        row.push(String::new());
+    }
+    row
+}
+
+/// Value as written to a data row
+fn csv_value(value: &fit::Value) -> String {
+    match value {
+        fit::Value::DateTime(val) => (val.timestamp() - fit::FIT_EPOCH_OFFSET).to_string(),
+        fit::Value::LocalDateTime(val) => {
+            (val.naive_local().and_utc().timestamp() - fit::FIT_EPOCH_OFFSET).to_string()
+        }
+        fit::Value::Flags(val) => val.value.to_string(),
+        fit::Value::Bytes(val) => val
+            .iter()
+            .map(|it| it.to_string())
+            .collect::<Vec<_>>()
+            .join("|"),
+        fit::Value::Array(values) => values.iter().map(csv_value).collect::<Vec<_>>().join("|"),
+        _ => value.to_string(),
+    }
+}
+
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\"\""))
+}
+
+/// Encode FitCSVTool CSV into a FIT file
+pub fn to_fit(csv: &str) -> ParserResult<Vec<u8>> {
+    let mut encoder = Encoder::new();
+    // `None` for definitions of messages the profile does not know
+    let mut locals: HashMap<u8, Option<(String, Vec<messages::FieldDef>)>> = HashMap::new();
+    for (line, row) in parse_rows(csv)? {
+        let invalid = |reason: String| ErrorKind::InvalidCsv { line, reason };
+        let kind = row.first().map(|it| it.as_str()).unwrap_or_default();
+        if kind == "Type" || row.iter().all(|it| it.is_empty()) {
+            continue;
+        }
+        let local_message_number = row
+            .get(1)
+            .and_then(|it| it.parse::<u8>().ok())
+            .filter(|it| *it < 16)
+            .ok_or_else(|| invalid("invalid local message number".to_string()))?;
+        let name = row.get(2).map(|it| it.as_str()).unwrap_or_default();
+        let triples = row.get(3..).unwrap_or_default().chunks(3);
+        match kind {
+            "Definition" => {
+                let global_message_number = match types::MesgNum::try_from(name) {
+                    Ok(it) => it.value().try_as_usize().unwrap_or_default() as u16,
+                    Err(_) => {
+                        locals.insert(local_message_number, None);
+                        continue;
+                    }
+                };
+                let mut fields = Vec::new();
+                let mut field_definitions = Vec::new();
+                for triple in triples {
+                    let field = if let Some(field) =
+                        triple.first().and_then(|it| messages::field_def(name, it))
+                    {
+                        field
+                    } else {
+                        continue;
+                    };
+                    let count = triple
+                        .get(1)
+                        .and_then(|it| it.parse::<usize>().ok())
+                        .ok_or_else(|| {
+                            invalid(format!("invalid count of field '{}'", field.name))
+                        })?;
+                    let size = if field.base_type == BaseType::String {
+                        count
+                    } else {
+                        count * field.base_type.size() as usize
+                    };
+                    let size = u8::try_from(size)
+                        .map_err(|_| invalid(format!("field '{}' is too large", field.name)))?;
+                    field_definitions.push(FieldDefinition {
+                        field_definition_number: field.number,
+                        size,
+                        base_type: field.base_type,
+                    });
+                    fields.push(field);
+                }
+                encoder.write_definition(&FitDefinitionMessage {
+                    architecture: Architecture::LittleEndian,
+                    local_message_number,
+                    global_message_number,
+                    field_definitions,
+                    developer_field_definitions: Vec::new(),
+                });
+                locals.insert(local_message_number, Some((name.to_string(), fields)));
+            }
+            "Data" => {
+                let fields = match locals.get(&local_message_number) {
+                    Some(Some((_, fields))) => fields,
+                    Some(None) => continue,
+                    None => {
+                        return Err(invalid(format!(
+                            "no definition for local message number {local_message_number}"
+                        )))
+                    }
+                };
+                let mut values = IndexMap::new();
+                for triple in triples {
+                    let (field_name, text) = match triple {
+                        [field_name, text, ..] => (field_name, text),
+                        _ => continue,
+                    };
+                    let field = if let Some(field) = fields.iter().find(|it| it.name == field_name)
+                    {
+                        field
+                    } else {
+                        continue;
+                    };
+                    if text.is_empty() {
+                        continue;
+                    }
+                    values.insert(field.number, parse_value(field, text).map_err(invalid)?);
+                }
+                encoder.write_data(local_message_number, &values)?;
+            }
+            _ => return Err(invalid(format!("unknown row type '{kind}'"))),
+        }
+    }
+    Ok(encoder.finish())
+}
+
+/// Decode FitCSVTool CSV into messages
+pub fn read(csv: &str) -> ParserResult<Messages> {
+    let bytes = to_fit(csv)?;
+    Decoder::new(&bytes).decode().map(|(_, messages)| messages)
+}
+
+/// Raw value of a data row value
+fn parse_value(field: &messages::FieldDef, text: &str) -> Result<fit::Value, String> {
+    if field.base_type == BaseType::String {
+        let mut values = text
+            .split('|')
+            .map(|it| fit::Value::String(it.to_string()))
+            .collect::<Vec<_>>();
+        return Ok(if values.len() == 1 {
+            values.swap_remove(0)
+        } else {
+            fit::Value::Array(values)
+        });
+    }
+    // developer and application ids are written as UUIDs
+    if field.base_type == BaseType::Byte && !text.contains('|') && text.parse::<u8>().is_err() {
+        return parse_hex(text)
+            .map(fit::Value::Bytes)
+            .ok_or_else(|| format!("invalid value '{text}' of field '{}'", field.name));
+    }
+    let mut values = text
+        .split('|')
+        .map(|it| parse_element(field, it))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(if values.len() == 1 {
+        values.swap_remove(0)
+    } else {
+        fit::Value::Array(values)
+    })
+}
+
+fn parse_element(field: &messages::FieldDef, text: &str) -> Result<fit::Value, String> {
+    if let Some(value) = types::value_of(field.field_ty, text) {
+        return Ok(value);
+    }
+    match text {
+        "true" => return Ok(fit::Value::Enum(1)),
+        "false" => return Ok(fit::Value::Enum(0)),
+        _ => {}
+    }
+    if field.scale == 1.0 && field.offset == 0.0 {
+        if let Ok(value) = text.parse::<i64>() {
+            return Ok(fit::Value::SInt64(value));
+        }
+        if let Ok(value) = text.parse::<u64>() {
+            return Ok(fit::Value::UInt64(value));
+        }
+    }
+    text.parse::<f64>()
+        .map(|it| fit::Value::Float64((it + field.offset) * field.scale))
+        .map_err(|_| format!("invalid value '{text}' of field '{}'", field.name))
+}
+
+fn parse_hex(text: &str) -> Option<Vec<u8>> {
+    let hex = text.replace('-', "");
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|it| u8::from_str_radix(hex.get(it..it + 2)?, 16).ok())
+        .collect()
+}
+
+/// Split CSV text into rows with their line numbers, quoted cells may contain `,`, `"` and newlines
+fn parse_rows(csv: &str) -> ParserResult<Vec<(usize, Vec<String>)>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut row_line = 1;
+    let mut chars = csv.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                chars.next();
+                cell.push('"');
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => row.push(std::mem::take(&mut cell)),
+            '\r' if !in_quotes => {}
+            '\n' if !in_quotes => {
+                row.push(std::mem::take(&mut cell));
+                rows.push((row_line, std::mem::take(&mut row)));
+                line += 1;
+                row_line = line;
+            }
+            _ => {
+                if char == '\n' {
+                    line += 1;
+                }
+                cell.push(char);
+            }
+        }
+    }
+    if in_quotes {
+        return Err(ErrorKind::InvalidCsv {
+            line: row_line,
+            reason: "unterminated quoted value".to_string(),
+        });
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push((row_line, row));
+    }
+    Ok(rows)
+}
@@ -0,0 +1,105 @@
+//! GPX 1.0/1.1 import
+//!
+//! Each track segment (`trkseg`) and each route (`rte`) becomes a lap, waypoints (`wpt`) become
+//! course points typed by their `type` or `sym`. Heart rate, cadence and power are read from the
+//! Garmin `TrackPointExtension` (`hr`, `cad`) and the common `power` extension.
+
+use super::{
+    child, child_text, course_point_type, descendant_text, no_points, parse_time, parse_xml, sport,
+    Course, CoursePoint, TrackPoint,
+};
+use crate::error::ParserResult;
+use std::str::FromStr;
+
+/// Read the tracks, routes and waypoints of a GPX document
+pub fn read(gpx: &str) -> ParserResult<Course> {
+    let document = parse_xml(gpx)?;
+    let root = document.root_element();
+    let elements = |name: &'static str| {
+        root.children()
+            .filter(move |it| it.is_element() && it.tag_name().name() == name)
+    };
+
+    let mut course = Course::default();
+    for track in elements("trk") {
+        course.name = course
+            .name
+            .or_else(|| child_text(track, "name").map(|it| it.to_string()));
+        course.sport = course
+            .sport
+            .or_else(|| child_text(track, "type").and_then(sport));
+        for segment in track
+            .children()
+            .filter(|it| it.tag_name().name() == "trkseg")
+        {
+            course.laps.push(points(segment, "trkpt"));
+        }
+    }
+    for route in elements("rte") {
+        course.name = course
+            .name
+            .or_else(|| child_text(route, "name").map(|it| it.to_string()));
+        course.sport = course
+            .sport
+            .or_else(|| child_text(route, "type").and_then(sport));
+        course.laps.push(points(route, "rtept"));
+    }
+    course.laps.retain(|it| !it.is_empty());
+    if course.laps.is_empty() {
+        return Err(no_points());
+    }
+    course.name = course.name.or_else(|| {
+        child(root, "metadata")
+            .and_then(|it| child_text(it, "name"))
+            .map(|it| it.to_string())
+    });
+    course.course_points = elements("wpt")
+        .filter_map(|waypoint| {
+            let (lat, lon) = position(waypoint)?;
+            Some(CoursePoint {
+                lat,
+                lon,
+                time: child_text(waypoint, "time").and_then(parse_time),
+                name: child_text(waypoint, "name").unwrap_or_default().to_string(),
+                ty: course_point_type(
+                    child_text(waypoint, "type")
+                        .or_else(|| child_text(waypoint, "sym"))
+                        .unwrap_or_default(),
+                ),
+            })
+        })
+        .collect();
+    Ok(course)
+}
+
+fn points(parent: roxmltree::Node, name: &str) -> Vec<TrackPoint> {
+    parent
+        .children()
+        .filter(|it| it.tag_name().name() == name)
+        .filter_map(|point| {
+            let (lat, lon) = position(point)?;
+            Some(TrackPoint {
+                lat,
+                lon,
+                time: child_text(point, "time").and_then(parse_time),
+                altitude: child_text(point, "ele").and_then(|it| it.parse().ok()),
+                heart_rate: extension(point, "hr"),
+                cadence: extension(point, "cad"),
+                power: extension(point, "power"),
+            })
+        })
+        .collect()
+}
+
+fn extension<T: FromStr>(point: roxmltree::Node, name: &str) -> Option<T> {
+    descendant_text(child(point, "extensions")?, name)?
+        .parse()
+        .ok()
+}
+
+fn position(node: roxmltree::Node) -> Option<(f64, f64)> {
+    Some((
+        node.attribute("lat")?.trim().parse().ok()?,
+        node.attribute("lon")?.trim().parse().ok()?,
+    ))
+}
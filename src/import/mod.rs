@@ -0,0 +1,439 @@
+//! Import of GPX and TCX tracks into FIT course files
+//!
+//! [`gpx::read`] and [`tcx::read`] parse a document into a [`Course`], which
+//! [`Course::to_fit`] encodes as a Course file: `file_id`, `course`, one `lap` per track
+//! segment, a timer start `event`, the `record` messages, the `course_point` messages and a
+//! timer stop `event`. Distances are computed from the positions. Only Course files are written,
+//! TCX activities are imported as courses too.
+
+pub mod gpx;
+pub mod tcx;
+
+use crate::decoder::{Architecture, FieldDefinition, FitDefinitionMessage};
+use crate::encoder::Encoder;
+use crate::error::{ErrorKind, ParserResult};
+use crate::fit::{self, BaseType};
+use crate::profile::{messages, types};
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use indexmap::IndexMap;
+
+/// Mean earth radius in metres
+const EARTH_RADIUS: f64 = 6_371_000.0;
+
+/// Semicircles per degree, `2^31 / 180`
+const DEGREES_TO_SEMICIRCLES: f64 = 2147483648.0 / 180.0;
+
+/// Largest string field, 254 bytes of text and the terminating NUL
+const MAX_STRING_SIZE: usize = u8::MAX as usize;
+
+/// A point of an imported track or route
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrackPoint {
+    pub lat: f64,
+    pub lon: f64,
+    pub time: Option<DateTime<Utc>>,
+    /// Metres
+    pub altitude: Option<f64>,
+    pub heart_rate: Option<u8>,
+    pub cadence: Option<u8>,
+    /// Watts
+    pub power: Option<u16>,
+}
+
+/// A named point along the course, e.g. a GPX waypoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoursePoint {
+    pub lat: f64,
+    pub lon: f64,
+    pub time: Option<DateTime<Utc>>,
+    pub name: String,
+    pub ty: types::CoursePoint,
+}
+
+/// A course read from GPX or TCX, with the points of each lap
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Course {
+    pub name: Option<String>,
+    pub sport: Option<types::Sport>,
+    pub laps: Vec<Vec<TrackPoint>>,
+    pub course_points: Vec<CoursePoint>,
+}
+
+/// Options of [`Course::to_fit`]
+#[derive(Debug, Clone)]
+pub struct CourseOptions {
+    /// Speed in m/s used to time points without a time, such as the points of a GPX route
+    pub speed: f64,
+    /// Time of the first point if it has no time, the current time by default
+    pub start_time: DateTime<Utc>,
+}
+
+impl Default for CourseOptions {
+    fn default() -> Self {
+        CourseOptions {
+            speed: 5.0,
+            start_time: Utc::now().trunc_subsecs(0),
+        }
+    }
+}
+
+/// A track point with its course distance and time
+struct Timed<'a> {
+    point: &'a TrackPoint,
+    distance: f64,
+    time: DateTime<Utc>,
+}
+
+/// A `record` field with the value of a point
+type RecordColumn = (&'static str, fn(&Timed) -> Option<fit::Value>);
+
+impl Course {
+    /// Encode the course as a FIT Course file
+    pub fn to_fit(&self, options: &CourseOptions) -> ParserResult<Vec<u8>> {
+        let laps = self.timed_laps(options);
+        let records = laps.iter().flatten().collect::<Vec<_>>();
+        let start_time = records
+            .first()
+            .map(|it| it.time)
+            .unwrap_or(options.start_time);
+        let end_time = records.last().map(|it| it.time).unwrap_or(start_time);
+
+        let mut writer = MessageWriter::default();
+        let file_id = writer.define(
+            "file_id",
+            &["type", "manufacturer", "product", "time_created"],
+            0,
+        )?;
+        writer.write(
+            file_id,
+            vec![
+                Some(types::File::Course.value()),
+                Some(types::Manufacturer::Development.value()),
+                Some(fit::Value::UInt16(0)),
+                Some(fit::Value::DateTime(start_time)),
+            ],
+        )?;
+
+        let name = self.name.clone().unwrap_or_default();
+        let course = writer.define("course", &["sport", "name"], name.len() + 1)?;
+        writer.write(
+            course,
+            vec![
+                Some(self.sport.unwrap_or(types::Sport::Generic).value()),
+                Some(fit::Value::String(name)),
+            ],
+        )?;
+
+        let lap = writer.define(
+            "lap",
+            &[
+                "message_index",
+                "timestamp",
+                "start_time",
+                "start_position_lat",
+                "start_position_long",
+                "end_position_lat",
+                "end_position_long",
+                "total_elapsed_time",
+                "total_timer_time",
+                "total_distance",
+            ],
+            0,
+        )?;
+        for (index, points) in laps.iter().enumerate() {
+            let (first, last) = match (points.first(), points.last()) {
+                (Some(first), Some(last)) => (first, last),
+                _ => continue,
+            };
+            let elapsed = (last.time - first.time).num_milliseconds() as f64 / 1000.0;
+            writer.write(
+                lap,
+                vec![
+                    Some(fit::Value::UInt16(index as u16)),
+                    Some(fit::Value::DateTime(last.time)),
+                    Some(fit::Value::DateTime(first.time)),
+                    Some(semicircles(first.point.lat)),
+                    Some(semicircles(first.point.lon)),
+                    Some(semicircles(last.point.lat)),
+                    Some(semicircles(last.point.lon)),
+                    Some(fit::Value::Float64(elapsed)),
+                    Some(fit::Value::Float64(elapsed)),
+                    Some(fit::Value::Float64(last.distance - first.distance)),
+                ],
+            )?;
+        }
+
+        let event = writer.define("event", &["timestamp", "event", "event_type"], 0)?;
+        writer.write(
+            event,
+            vec![
+                Some(fit::Value::DateTime(start_time)),
+                Some(types::Event::Timer.value()),
+                Some(types::EventType::Start.value()),
+            ],
+        )?;
+
+        // optional fields are only defined if some point has them
+        let columns: [RecordColumn; 8] = [
+            ("timestamp", |it| Some(fit::Value::DateTime(it.time))),
+            ("position_lat", |it| Some(semicircles(it.point.lat))),
+            ("position_long", |it| Some(semicircles(it.point.lon))),
+            ("altitude", |it| it.point.altitude.map(fit::Value::Float64)),
+            ("distance", |it| Some(fit::Value::Float64(it.distance))),
+            ("heart_rate", |it| {
+                it.point.heart_rate.map(fit::Value::UInt8)
+            }),
+            ("cadence", |it| it.point.cadence.map(fit::Value::UInt8)),
+            ("power", |it| it.point.power.map(fit::Value::UInt16)),
+        ];
+        let columns = columns
+            .into_iter()
+            .filter(|(_, value)| records.iter().any(|it| value(it).is_some()))
+            .collect::<Vec<_>>();
+        let names = columns.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        let record = writer.define("record", &names, 0)?;
+        for timed in &records {
+            let values = columns.iter().map(|(_, value)| value(timed)).collect();
+            writer.write(record, values)?;
+        }
+
+        let name_size = self
+            .course_points
+            .iter()
+            .map(|it| it.name.len() + 1)
+            .max()
+            .unwrap_or(1);
+        let course_point = writer.define(
+            "course_point",
+            &[
+                "message_index",
+                "timestamp",
+                "position_lat",
+                "position_long",
+                "distance",
+                "type",
+                "name",
+            ],
+            name_size,
+        )?;
+        for (index, point) in self.course_points.iter().enumerate() {
+            // course points take the distance of the nearest record
+            let nearest = records.iter().min_by(|a, b| {
+                let a = haversine(a.point.lat, a.point.lon, point.lat, point.lon);
+                let b = haversine(b.point.lat, b.point.lon, point.lat, point.lon);
+                a.total_cmp(&b)
+            });
+            let time = point
+                .time
+                .or(nearest.map(|it| it.time))
+                .unwrap_or(start_time);
+            writer.write(
+                course_point,
+                vec![
+                    Some(fit::Value::UInt16(index as u16)),
+                    Some(fit::Value::DateTime(time)),
+                    Some(semicircles(point.lat)),
+                    Some(semicircles(point.lon)),
+                    Some(fit::Value::Float64(
+                        nearest.map(|it| it.distance).unwrap_or(0.0),
+                    )),
+                    Some(point.ty.value()),
+                    Some(fit::Value::String(point.name.clone())),
+                ],
+            )?;
+        }
+
+        writer.write(
+            event,
+            vec![
+                Some(fit::Value::DateTime(end_time)),
+                Some(types::Event::Timer.value()),
+                Some(types::EventType::StopDisableAll.value()),
+            ],
+        )?;
+        Ok(writer.encoder.finish())
+    }
+
+    /// Points of each lap with their cumulative distance, timing points without a time from the
+    /// previous point at the option speed
+    fn timed_laps(&self, options: &CourseOptions) -> Vec<Vec<Timed<'_>>> {
+        let mut previous: Option<(&TrackPoint, f64, DateTime<Utc>)> = None;
+        self.laps
+            .iter()
+            .map(|lap| {
+                lap.iter()
+                    .map(|point| {
+                        let (distance, time) = match previous {
+                            Some((last, distance, time)) => {
+                                let step = haversine(last.lat, last.lon, point.lat, point.lon);
+                                let seconds = step / options.speed.max(f64::EPSILON);
+                                (
+                                    distance + step,
+                                    time + Duration::milliseconds((seconds * 1000.0) as i64),
+                                )
+                            }
+                            None => (0.0, options.start_time),
+                        };
+                        let time = point.time.unwrap_or(time);
+                        previous = Some((point, distance, time));
+                        Timed {
+                            point,
+                            distance,
+                            time,
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Encodes messages by field name, one local message number per message type
+#[derive(Default)]
+struct MessageWriter {
+    encoder: Encoder,
+    fields: Vec<Vec<messages::FieldDef>>,
+}
+
+impl MessageWriter {
+    /// Define a message with the fields, strings of `string_size` bytes
+    fn define(&mut self, message: &str, names: &[&str], string_size: usize) -> ParserResult<u8> {
+        let global_message_number = types::MesgNum::try_from(message)
+            .map_err(|_| ErrorKind::UnknownMessage(message.to_string()))?
+            .value()
+            .try_as_usize()
+            .unwrap_or_default() as u16;
+        let fields = names
+            .iter()
+            .map(|name| {
+                messages::field_def(message, name)
+                    .ok_or_else(|| ErrorKind::UnknownMessage(format!("{message}.{name}")))
+            })
+            .collect::<ParserResult<Vec<_>>>()?;
+        let local_message_number = self.fields.len() as u8;
+        self.encoder.write_definition(&FitDefinitionMessage {
+            architecture: Architecture::LittleEndian,
+            local_message_number,
+            global_message_number,
+            field_definitions: fields
+                .iter()
+                .map(|field| FieldDefinition {
+                    field_definition_number: field.number,
+                    size: if field.base_type == BaseType::String {
+                        string_size.min(MAX_STRING_SIZE) as u8
+                    } else {
+                        field.base_type.size()
+                    },
+                    base_type: field.base_type,
+                })
+                .collect(),
+            developer_field_definitions: Vec::new(),
+        });
+        self.fields.push(fields);
+        Ok(local_message_number)
+    }
+    /// Write the values of the defined fields, applying scale and offset, missing values are
+    /// written as invalid
+    fn write(
+        &mut self,
+        local_message_number: u8,
+        values: Vec<Option<fit::Value>>,
+    ) -> ParserResult<()> {
+        let fields = &self.fields[local_message_number as usize];
+        let raw = fields
+            .iter()
+            .zip(values)
+            .filter_map(|(field, value)| Some((field, value?)))
+            .map(|(field, value)| {
+                let value = match value {
+                    fit::Value::Float64(val) if field.scale != 1.0 || field.offset != 0.0 => {
+                        fit::Value::Float64((val + field.offset) * field.scale)
+                    }
+                    _ => value,
+                };
+                (field.number, value)
+            })
+            .collect::<IndexMap<_, _>>();
+        self.encoder.write_data(local_message_number, &raw)
+    }
+}
+
+fn semicircles(degrees: f64) -> fit::Value {
+    fit::Value::SInt32((degrees * DEGREES_TO_SEMICIRCLES).round() as i32)
+}
+
+/// Great circle distance in metres
+fn haversine(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS * a.sqrt().asin()
+}
+
+/// Sport of a GPX `type` or TCX `Sport`, e.g. `Biking` or `running`
+fn sport(name: &str) -> Option<types::Sport> {
+    let name = name.trim().to_lowercase().replace([' ', '-'], "_");
+    match name.as_str() {
+        "biking" | "road_biking" | "mountain_biking" | "ride" => Some(types::Sport::Cycling),
+        "run" | "trail_running" => Some(types::Sport::Running),
+        "walk" => Some(types::Sport::Walking),
+        "hike" => Some(types::Sport::Hiking),
+        _ => types::Sport::try_from(name.as_str()).ok(),
+    }
+}
+
+/// Course point type of a GPX `type` or `sym`, or a TCX `PointType`, generic if unknown
+fn course_point_type(name: &str) -> types::CoursePoint {
+    let name = name.trim().to_lowercase().replace([' ', '-'], "_");
+    match name.as_str() {
+        "4th_category" => types::CoursePoint::FourthCategory,
+        "3rd_category" => types::CoursePoint::ThirdCategory,
+        "2nd_category" => types::CoursePoint::SecondCategory,
+        "1st_category" => types::CoursePoint::FirstCategory,
+        _ => types::CoursePoint::try_from(name.as_str()).unwrap_or(types::CoursePoint::Generic),
+    }
+}
+
+fn parse_time(text: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(text.trim())
+        .ok()
+        .map(|it| it.with_timezone(&Utc))
+}
+
+/// Parse an XML document, ignoring namespaces by matching local tag names
+fn parse_xml(xml: &str) -> ParserResult<roxmltree::Document<'_>> {
+    roxmltree::Document::parse(xml).map_err(|e| ErrorKind::InvalidXml {
+        reason: e.to_string(),
+    })
+}
+
+/// First child element with the local name
+fn child<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    name: &str,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    node.children()
+        .find(|it| it.is_element() && it.tag_name().name() == name)
+}
+
+/// Text of the first child element with the local name
+fn child_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Option<&'a str> {
+    child(node, name)
+        .and_then(|it| it.text())
+        .map(|it| it.trim())
+}
+
+/// Text of the first descendant element with the local name, e.g. in extensions
+fn descendant_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.descendants()
+        .find(|it| it.is_element() && it.tag_name().name() == name)
+        .and_then(|it| it.text())
+        .map(|it| it.trim())
+}
+
+fn no_points() -> ErrorKind {
+    ErrorKind::InvalidXml {
+        reason: "no track points with a position".to_string(),
+    }
+}
@@ -0,0 +1,88 @@
+//! TCX import of courses, and of activities as courses
+//!
+//! Each `Track` of a course becomes a lap, the tracks of an activity lap are joined into one
+//! lap. `CoursePoint` elements become course points typed by their `PointType`. Power is read
+//! from the `Watts` of the `TPX` extension.
+
+use super::{
+    child, child_text, course_point_type, descendant_text, no_points, parse_time, parse_xml, sport,
+    Course, CoursePoint, TrackPoint,
+};
+use crate::error::ParserResult;
+
+/// Read the courses, or the activities, of a TCX document as a course
+pub fn read(tcx: &str) -> ParserResult<Course> {
+    let document = parse_xml(tcx)?;
+    let elements = |name: &'static str| {
+        document
+            .descendants()
+            .filter(move |it| it.is_element() && it.tag_name().name() == name)
+    };
+
+    let mut course = Course {
+        name: elements("Course")
+            .find_map(|it| child_text(it, "Name"))
+            .map(|it| it.to_string()),
+        sport: elements("Activity")
+            .find_map(|it| it.attribute("Sport"))
+            .and_then(sport),
+        ..Course::default()
+    };
+    // tracks of the same activity lap share a lap
+    let mut last_parent = None;
+    for track in elements("Track") {
+        let parent = track.parent_element().filter(|it| it.has_tag_name("Lap"));
+        let points = points(track);
+        match (parent, course.laps.last_mut()) {
+            (Some(parent), Some(lap)) if Some(parent.id()) == last_parent => lap.extend(points),
+            _ => course.laps.push(points),
+        }
+        last_parent = parent.map(|it| it.id());
+    }
+    course.laps.retain(|it| !it.is_empty());
+    if course.laps.is_empty() {
+        return Err(no_points());
+    }
+    course.course_points = elements("CoursePoint")
+        .filter_map(|point| {
+            let (lat, lon) = position(point)?;
+            Some(CoursePoint {
+                lat,
+                lon,
+                time: child_text(point, "Time").and_then(parse_time),
+                name: child_text(point, "Name").unwrap_or_default().to_string(),
+                ty: course_point_type(child_text(point, "PointType").unwrap_or_default()),
+            })
+        })
+        .collect();
+    Ok(course)
+}
+
+fn points(track: roxmltree::Node) -> Vec<TrackPoint> {
+    track
+        .children()
+        .filter(|it| it.tag_name().name() == "Trackpoint")
+        .filter_map(|point| {
+            let (lat, lon) = position(point)?;
+            Some(TrackPoint {
+                lat,
+                lon,
+                time: child_text(point, "Time").and_then(parse_time),
+                altitude: child_text(point, "AltitudeMeters").and_then(|it| it.parse().ok()),
+                heart_rate: child(point, "HeartRateBpm")
+                    .and_then(|it| child_text(it, "Value")?.parse().ok()),
+                cadence: child_text(point, "Cadence").and_then(|it| it.parse().ok()),
+                power: child(point, "Extensions")
+                    .and_then(|it| descendant_text(it, "Watts")?.parse().ok()),
+            })
+        })
+        .collect()
+}
+
+fn position(node: roxmltree::Node) -> Option<(f64, f64)> {
+    let position = child(node, "Position")?;
+    Some((
+        child_text(position, "LatitudeDegrees")?.parse().ok()?,
+        child_text(position, "LongitudeDegrees")?.parse().ok()?,
+    ))
+}
@@ -1,13 +1,24 @@
 mod accumulator;
+pub mod activity;
 mod bit_reader;
 mod byte_reader;
+#[cfg(feature = "catalog")]
+pub mod catalog;
 mod crc;
 pub mod decoder;
+pub mod diff;
+pub mod encoder;
 pub mod error;
+pub mod export;
 mod fit;
+pub mod fit_csv;
+pub mod import;
+pub mod memo;
+pub mod pedaling;
 pub mod profile;
+pub mod timezone;
 
-pub use fit::Value;
+pub use fit::{FlagsValue, TypedValue, Value, FIT_EPOCH_OFFSET};
 pub use profile::VERSION as PROFILE_VERSION;
 
 #[allow(unused_imports)]
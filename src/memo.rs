@@ -0,0 +1,94 @@
+use crate::decoder::{Messages, Record};
+use crate::fit;
+use crate::profile::messages;
+use std::collections::BTreeMap;
+
+/// `message_index` bits holding the index, the upper bits are flags
+const MESSAGE_INDEX_MASK: usize = 0x0FFF;
+
+/// Target of a memo, `(message, parent_index, field_num)`
+type MemoTarget = (String, usize, u8);
+
+/// Reassemble `memo_glob` fragments and attach the text to the referenced field
+///
+/// Fragments are joined in `part_index` order and decoded as UTF-8. The parent record is
+/// matched on its `message_index`, or on its position within the message type when none of
+/// the records of that type have a `message_index`.
+/// Memos whose parent message or field cannot be resolved are left as fragments only.
+pub fn reassemble(messages: &mut Messages) {
+    let mut memos: BTreeMap<MemoTarget, Vec<(usize, Vec<u8>)>> = BTreeMap::new();
+    for glob in messages.get("memo_glob").into_iter().flatten() {
+        let target = match (
+            glob.get("mesg_num"),
+            read_usize(glob, "parent_index"),
+            read_usize(glob, "field_num"),
+        ) {
+            (Some(mesg_num), Some(parent_index), Some(field_num)) => (
+                mesg_num.to_string(),
+                parent_index & MESSAGE_INDEX_MASK,
+                field_num as u8,
+            ),
+            _ => continue,
+        };
+        let part_index = read_usize(glob, "part_index").unwrap_or(0);
+        let bytes = glob
+            .get("data")
+            .or_else(|| glob.get("memo"))
+            .map(fragment_bytes)
+            .unwrap_or_default();
+        memos.entry(target).or_default().push((part_index, bytes));
+    }
+
+    for ((message, parent_index, field_num), mut parts) in memos {
+        let field = if let Some(field) = messages::field_name(&message, field_num) {
+            field
+        } else {
+            continue;
+        };
+        let records = if let Some(records) = messages.get_mut(&message) {
+            records
+        } else {
+            continue;
+        };
+        let has_message_index = records.iter().any(|it| it.contains_key("message_index"));
+        let position = if has_message_index {
+            records.iter().position(|it| {
+                read_usize(it, "message_index").map(|it| it & MESSAGE_INDEX_MASK)
+                    == Some(parent_index)
+            })
+        } else {
+            Some(parent_index)
+        };
+        let parent = if let Some(parent) = position.and_then(|it| records.get_mut(it)) {
+            parent
+        } else {
+            continue;
+        };
+        parts.sort_by_key(|(part_index, _)| *part_index);
+        let mut bytes = parts.into_iter().flat_map(|(_, it)| it).collect::<Vec<_>>();
+        while bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        let text = String::from_utf8_lossy(&bytes).into_owned();
+        parent.insert(field, fit::Value::String(text));
+    }
+}
+
+fn read_usize(record: &Record, name: &str) -> Option<usize> {
+    record.get(name).and_then(|it| it.try_as_usize().ok())
+}
+
+fn fragment_bytes(value: &fit::Value) -> Vec<u8> {
+    match value {
+        fit::Value::Bytes(bytes) => bytes.clone(),
+        fit::Value::Array(values) => values
+            .iter()
+            .filter_map(|it| it.try_as_usize().ok())
+            .map(|it| it as u8)
+            .collect(),
+        _ => value
+            .try_as_usize()
+            .map(|it| vec![it as u8])
+            .unwrap_or_default(),
+    }
+}
@@ -0,0 +1,200 @@
+use crate::decoder::Record;
+use crate::fit;
+use serde::Serialize;
+
+/// Left/right power contribution in percent
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Balance {
+    pub left: f64,
+    pub right: f64,
+}
+
+impl Balance {
+    /// Decode a `left_right_balance` value
+    ///
+    /// - bit 7: the contribution belongs to the right side
+    /// - bit 6..0: contribution in percent
+    ///
+    /// Returns `None` when the side is unknown (bit 7 unset).
+    pub fn from_left_right_balance(value: u8) -> Option<Self> {
+        Self::from_right_side(value & 0x80 == 0x80, (value & 0x7F) as f64)
+    }
+    /// Decode a `left_right_balance_100` value
+    ///
+    /// - bit 15: the contribution belongs to the right side
+    /// - bit 13..0: contribution in 0.01 percent
+    ///
+    /// Returns `None` when the side is unknown (bit 15 unset).
+    pub fn from_left_right_balance_100(value: u16) -> Option<Self> {
+        Self::from_right_side(value & 0x8000 == 0x8000, (value & 0x3FFF) as f64 / 100.0)
+    }
+    fn from_right_side(is_right: bool, percent: f64) -> Option<Self> {
+        if !is_right || percent > 100.0 {
+            return None;
+        }
+        Some(Balance {
+            left: 100.0 - percent,
+            right: percent,
+        })
+    }
+}
+
+/// Crank angles in degrees, measured clockwise from top dead center
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PowerPhase {
+    pub start: f64,
+    pub end: f64,
+}
+
+impl PowerPhase {
+    fn from_value(value: &fit::Value) -> Option<Self> {
+        match value {
+            fit::Value::Array(arr) if arr.len() == 2 => Some(PowerPhase {
+                start: arr[0].try_as_f64().ok()?,
+                end: arr[1].try_as_f64().ok()?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Dynamics of a single pedal
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PedalDynamics {
+    /// Crank angles where the pedal produces positive power
+    pub power_phase: Option<PowerPhase>,
+    /// Crank angles where the pedal produces its peak power
+    pub power_phase_peak: Option<PowerPhase>,
+    /// Platform center offset in millimeters, positive towards the outside of the pedal
+    pub platform_center_offset: Option<f64>,
+    /// Torque effectiveness in percent
+    pub torque_effectiveness: Option<f64>,
+    /// Pedal smoothness in percent
+    pub pedal_smoothness: Option<f64>,
+}
+
+impl PedalDynamics {
+    fn is_empty(&self) -> bool {
+        self == &PedalDynamics::default()
+    }
+}
+
+/// Cycling dynamics of a `record`, or the averages of a `lap` or `session`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PedalingDynamics {
+    pub balance: Option<Balance>,
+    pub left: PedalDynamics,
+    pub right: PedalDynamics,
+    /// Combined pedal smoothness in percent
+    pub combined_pedal_smoothness: Option<f64>,
+}
+
+/// Field names of the dynamics of one pedal
+struct PedalFields {
+    power_phase: &'static str,
+    power_phase_peak: &'static str,
+    platform_center_offset: &'static str,
+    torque_effectiveness: &'static str,
+    pedal_smoothness: &'static str,
+}
+
+const RECORD_LEFT: PedalFields = PedalFields {
+    power_phase: "left_power_phase",
+    power_phase_peak: "left_power_phase_peak",
+    platform_center_offset: "left_pco",
+    torque_effectiveness: "left_torque_effectiveness",
+    pedal_smoothness: "left_pedal_smoothness",
+};
+const RECORD_RIGHT: PedalFields = PedalFields {
+    power_phase: "right_power_phase",
+    power_phase_peak: "right_power_phase_peak",
+    platform_center_offset: "right_pco",
+    torque_effectiveness: "right_torque_effectiveness",
+    pedal_smoothness: "right_pedal_smoothness",
+};
+const SUMMARY_LEFT: PedalFields = PedalFields {
+    power_phase: "avg_left_power_phase",
+    power_phase_peak: "avg_left_power_phase_peak",
+    platform_center_offset: "avg_left_pco",
+    torque_effectiveness: "avg_left_torque_effectiveness",
+    pedal_smoothness: "avg_left_pedal_smoothness",
+};
+const SUMMARY_RIGHT: PedalFields = PedalFields {
+    power_phase: "avg_right_power_phase",
+    power_phase_peak: "avg_right_power_phase_peak",
+    platform_center_offset: "avg_right_pco",
+    torque_effectiveness: "avg_right_torque_effectiveness",
+    pedal_smoothness: "avg_right_pedal_smoothness",
+};
+
+impl PedalingDynamics {
+    /// Read the dynamics of a `record` message, `None` if it carries no dynamics fields
+    pub fn from_record(record: &Record) -> Option<Self> {
+        let balance = record
+            .get("left_right_balance")
+            .and_then(|it| it.try_as_usize().ok())
+            .and_then(|it| Balance::from_left_right_balance(it as u8));
+        Self::new(
+            record,
+            balance,
+            RECORD_LEFT,
+            RECORD_RIGHT,
+            "combined_pedal_smoothness",
+        )
+    }
+    /// Read the average dynamics of a `lap` or `session` message, `None` if it carries no dynamics fields
+    pub fn from_summary(record: &Record) -> Option<Self> {
+        let balance = record
+            .get("left_right_balance")
+            .and_then(|it| it.try_as_usize().ok())
+            .and_then(|it| Balance::from_left_right_balance_100(it as u16));
+        Self::new(
+            record,
+            balance,
+            SUMMARY_LEFT,
+            SUMMARY_RIGHT,
+            "avg_combined_pedal_smoothness",
+        )
+    }
+    fn new(
+        record: &Record,
+        balance: Option<Balance>,
+        left: PedalFields,
+        right: PedalFields,
+        combined_pedal_smoothness: &str,
+    ) -> Option<Self> {
+        let dynamics = PedalingDynamics {
+            balance,
+            left: read_pedal(record, left),
+            right: read_pedal(record, right),
+            combined_pedal_smoothness: read_number(record, combined_pedal_smoothness),
+        };
+        if dynamics.balance.is_none()
+            && dynamics.left.is_empty()
+            && dynamics.right.is_empty()
+            && dynamics.combined_pedal_smoothness.is_none()
+        {
+            None
+        } else {
+            Some(dynamics)
+        }
+    }
+}
+
+fn read_pedal(record: &Record, fields: PedalFields) -> PedalDynamics {
+    PedalDynamics {
+        power_phase: record
+            .get(fields.power_phase)
+            .and_then(PowerPhase::from_value),
+        power_phase_peak: record
+            .get(fields.power_phase_peak)
+            .and_then(PowerPhase::from_value),
+        platform_center_offset: read_number(record, fields.platform_center_offset),
+        torque_effectiveness: read_number(record, fields.torque_effectiveness),
+        pedal_smoothness: read_number(record, fields.pedal_smoothness),
+    }
+}
+
+fn read_number(record: &Record, name: &str) -> Option<f64> {
+    record.get(name).and_then(|it| it.try_as_f64().ok())
+}
@@ -11,8 +11,8 @@ use super::types;
 use crate::bit_reader::BitReader;
 use crate::fit;
 use chrono::{TimeZone, Utc};
+use indexmap::IndexMap;
 use std::borrow::Cow;
-use std::collections::HashMap;
 use std::fmt;
 use std::ops::{Deref, Div, Sub};
 
@@ -23,6 +23,18 @@ pub struct Field {
     pub is_subfield: bool,
     pub units: &'static str,
 }
+/// Profile definition of a message field
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldDef {
+    pub number: u8,
+    pub name: &'static str,
+    /// Profile type or base type name, e.g. `date_time` or `uint16`
+    pub field_ty: &'static str,
+    pub base_type: fit::BaseType,
+    pub scale: f64,
+    pub offset: f64,
+    pub units: &'static str,
+}
 impl fmt::Display for Field {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.value.fmt(f)
@@ -34,7 +46,7 @@ pub struct MessageDecodeArgs<'input> {
     pub msg_no: u16,
     pub field_no: u8,
     pub value: &'input fit::Value,
-    pub fields: &'input HashMap<u8, fit::Value>,
+    pub fields: &'input IndexMap<u8, fit::Value>,
 }
 
 struct TransformValueArgs<'input, R: ToString> {
@@ -100,6 +112,14 @@ impl Div<f64> for fit::Value {
     }
 }
 
+/// Compare a reference field by numeric value, regardless of its on-wire base type
+fn ref_field_eq(fields: &IndexMap<u8, fit::Value>, field_no: u8, value: i64) -> bool {
+    fields
+        .get(&field_no)
+        .and_then(|it| it.try_as_i64().ok())
+        .is_some_and(|it| it == value)
+}
+
 fn transform_value<R: ToString>(
     value: Cow<fit::Value>,
     args: TransformValueArgs<R>,
@@ -111,6 +131,10 @@ fn transform_value<R: ToString>(
     ) {
         return Ok(value.clone());
     }
+    // null-separated string arrays are kept whole
+    if args.field_ty == "string" {
+        return Ok(value.clone());
+    }
     if let fit::Value::Array(arr) = value {
         if let Some(len) = args.array {
             if len != 0 && len != arr.len() {
@@ -127,10 +151,8 @@ actual= arr.len()
             ));
         }
     }
-    if args.field_ty == "string" {
-        Ok(value.clone())
-    } else if args.field_ty == "date_time" {
-        return if let fit::Value::UInt32(timestamp) = value {
+    if args.field_ty == "date_time" {
+        if let fit::Value::UInt32(timestamp) = value {
             // The second offset between UNIX and FIT Epochs (631065600).
             Ok(fit::Value::DateTime(
                 Utc.timestamp_opt(*timestamp as i64 + 631065600, 0).unwrap(),
@@ -141,14 +163,20 @@ actual= arr.len()
                 types::DateTime::base_type(),
                 value
             ))
-        };
+        }
+    } else if args.field_ty == "byte" && matches!(value, fit::Value::Array(_)) {
+        // byte arrays are kept as a single byte string
+        Ok(value
+            .to_bytes()
+            .map(fit::Value::Bytes)
+            .unwrap_or(value.clone()))
     } else if args.is_base_type
         && fit::BaseType::try_from(args.field_ty)
             .ok()
             .filter(|ty| ty.is_numeric())
             .is_some()
     {
-        return if let fit::Value::Array(arr) = value {
+        if let fit::Value::Array(arr) = value {
             Ok(fit::Value::Array(
                 arr.iter()
                     .map(|it| {
@@ -165,33 +193,49 @@ actual= arr.len()
                 .div(args.scale)
                 .and_then(|it| it.sub(args.offset))
                 .unwrap())
-        };
+        }
     } else {
         let ty_convert = |value: &fit::Value| {
-            args.ty_to_str.deref()(value)
-                .map(|it| fit::Value::String(it.to_string()))
-                .unwrap_or(value.clone())
+            let name = args.ty_to_str.deref()(value).map(|it| it.to_string());
+            if args.is_base_type {
+                return name.map(fit::Value::String).unwrap_or(value.clone());
+            }
+            if let Some(flags) = types::bit_flags(args.field_ty, value) {
+                return fit::Value::Flags(fit::FlagsValue {
+                    ty: args.field_ty,
+                    value: Box::new(value.clone()),
+                    flags,
+                    masked_value: types::value_mask(args.field_ty)
+                        .zip(value.try_as_usize().ok())
+                        .map(|(mask, raw)| raw as u64 & mask),
+                });
+            }
+            fit::Value::Typed(fit::TypedValue {
+                ty: args.field_ty,
+                value: Box::new(value.clone()),
+                name,
+            })
         };
-        return if let fit::Value::Array(arr) = value {
+        if let fit::Value::Array(arr) = value {
             Ok(fit::Value::Array(
                 arr.iter().map(ty_convert).collect::<Vec<_>>(),
             ))
         } else {
             Ok(ty_convert(value))
-        };
+        }
     }
 }
 
 pub type MessageDecoder = Box<
     dyn Fn(
-        &mut HashMap<&'static str, Field>,
+        &mut IndexMap<&'static str, Field>,
         &mut crate::accumulator::Accumulator,
         MessageDecodeArgs,
     ) -> Result<(), String>,
 >;
 /// Must be first message in file.
 fn _file_id(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -267,7 +311,7 @@ fn _file_id(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&1u8) == Some(&fit::Value::UInt16(263)) {
+            if ref_field_eq(args.fields, 1u8, 263) {
                 message_map.insert(
                     "favero_product",
                     Field {
@@ -290,7 +334,7 @@ fn _file_id(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::UInt16(1)) {
+            if ref_field_eq(args.fields, 1u8, 1) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -313,7 +357,7 @@ fn _file_id(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::UInt16(15)) {
+            if ref_field_eq(args.fields, 1u8, 15) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -336,7 +380,7 @@ fn _file_id(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::UInt16(13)) {
+            if ref_field_eq(args.fields, 1u8, 13) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -359,7 +403,7 @@ fn _file_id(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::UInt16(89)) {
+            if ref_field_eq(args.fields, 1u8, 89) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -487,7 +531,7 @@ fn _file_id(
     }
 }
 fn _file_creator(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -547,7 +591,7 @@ fn _file_creator(
     }
 }
 fn _timestamp_correlation(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -727,7 +771,7 @@ fn _timestamp_correlation(
     }
 }
 fn _software(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -811,7 +855,7 @@ fn _software(
     }
 }
 fn _slave_device(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -863,7 +907,7 @@ fn _slave_device(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&0u8) == Some(&fit::Value::UInt16(263)) {
+            if ref_field_eq(args.fields, 0u8, 263) {
                 message_map.insert(
                     "favero_product",
                     Field {
@@ -886,7 +930,7 @@ fn _slave_device(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::UInt16(1)) {
+            if ref_field_eq(args.fields, 0u8, 1) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -909,7 +953,7 @@ fn _slave_device(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::UInt16(15)) {
+            if ref_field_eq(args.fields, 0u8, 15) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -932,7 +976,7 @@ fn _slave_device(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::UInt16(13)) {
+            if ref_field_eq(args.fields, 0u8, 13) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -955,7 +999,7 @@ fn _slave_device(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::UInt16(89)) {
+            if ref_field_eq(args.fields, 0u8, 89) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -987,7 +1031,7 @@ fn _slave_device(
     }
 }
 fn _capabilities(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -1099,7 +1143,7 @@ fn _capabilities(
     }
 }
 fn _file_capabilities(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -1255,7 +1299,7 @@ fn _file_capabilities(
     }
 }
 fn _mesg_capabilities(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -1379,7 +1423,7 @@ fn _mesg_capabilities(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&2u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 2u8, 0) {
                 message_map.insert(
                     "num_per_file",
                     Field {
@@ -1402,7 +1446,7 @@ fn _mesg_capabilities(
                     },
                 );
             }
-            if args.fields.get(&2u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 2u8, 1) {
                 message_map.insert(
                     "max_per_file",
                     Field {
@@ -1425,7 +1469,7 @@ fn _mesg_capabilities(
                     },
                 );
             }
-            if args.fields.get(&2u8) == Some(&fit::Value::Enum(2)) {
+            if ref_field_eq(args.fields, 2u8, 2) {
                 message_map.insert(
                     "max_per_file_type",
                     Field {
@@ -1457,7 +1501,7 @@ fn _mesg_capabilities(
     }
 }
 fn _field_capabilities(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -1589,7 +1633,7 @@ fn _field_capabilities(
     }
 }
 fn _device_settings(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -2183,7 +2227,7 @@ fn _device_settings(
     }
 }
 fn _user_profile(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -2891,7 +2935,7 @@ fn _user_profile(
     }
 }
 fn _hrm_profile(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -3023,7 +3067,7 @@ fn _hrm_profile(
     }
 }
 fn _sdm_profile(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -3227,7 +3271,7 @@ fn _sdm_profile(
     }
 }
 fn _bike_profile(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -4007,7 +4051,7 @@ fn _bike_profile(
     }
 }
 fn _connectivity(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -4331,7 +4375,7 @@ fn _connectivity(
     }
 }
 fn _watchface_settings(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -4407,9 +4451,9 @@ fn _watchface_settings(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 0u8, 0) {
                 let value = if let fit::Value::Byte(v) = args.value {
-                    Cow::Owned(fit::Value::Enum(*v as u8))
+                    Cow::Owned(fit::Value::Enum(*v))
                 } else {
                     Cow::Borrowed(args.value)
                 };
@@ -4437,9 +4481,9 @@ fn _watchface_settings(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 0u8, 1) {
                 let value = if let fit::Value::Byte(v) = args.value {
-                    Cow::Owned(fit::Value::Enum(*v as u8))
+                    Cow::Owned(fit::Value::Enum(*v))
                 } else {
                     Cow::Borrowed(args.value)
                 };
@@ -4476,7 +4520,7 @@ fn _watchface_settings(
     }
 }
 fn _ohr_settings(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -4536,7 +4580,7 @@ fn _ohr_settings(
     }
 }
 fn _time_in_zone(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -4956,7 +5000,7 @@ fn _time_in_zone(
     }
 }
 fn _zones_target(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -5088,7 +5132,7 @@ fn _zones_target(
     }
 }
 fn _sport(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -5172,7 +5216,7 @@ fn _sport(
     }
 }
 fn _hr_zone(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -5256,7 +5300,7 @@ fn _hr_zone(
     }
 }
 fn _speed_zone(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -5340,7 +5384,7 @@ fn _speed_zone(
     }
 }
 fn _cadence_zone(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -5424,7 +5468,7 @@ fn _cadence_zone(
     }
 }
 fn _power_zone(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -5508,7 +5552,7 @@ fn _power_zone(
     }
 }
 fn _met_zone(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -5616,7 +5660,7 @@ fn _met_zone(
     }
 }
 fn _dive_settings(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -6172,7 +6216,7 @@ fn _dive_settings(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 19u8, 1) {
                 message_map.insert(
                     "heart_rate_antplus_device_type",
                     Field {
@@ -6197,7 +6241,7 @@ fn _dive_settings(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(5)) {
+            if ref_field_eq(args.fields, 19u8, 5) {
                 message_map.insert(
                     "heart_rate_local_device_type",
                     Field {
@@ -6525,7 +6569,7 @@ fn _dive_settings(
     }
 }
 fn _dive_alarm(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -6849,7 +6893,7 @@ fn _dive_alarm(
     }
 }
 fn _dive_apnea_alarm(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -7173,7 +7217,7 @@ fn _dive_apnea_alarm(
     }
 }
 fn _dive_gas(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -7305,7 +7349,7 @@ fn _dive_gas(
     }
 }
 fn _goal(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -7629,7 +7673,7 @@ fn _goal(
     }
 }
 fn _activity(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -7833,7 +7877,7 @@ fn _activity(
     }
 }
 fn _session(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -8149,7 +8193,7 @@ fn _session(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 5u8, 1) {
                 message_map.insert(
                     "total_strides",
                     Field {
@@ -8172,7 +8216,7 @@ fn _session(
                     },
                 );
             }
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(11)) {
+            if ref_field_eq(args.fields, 5u8, 11) {
                 message_map.insert(
                     "total_strides",
                     Field {
@@ -8195,7 +8239,7 @@ fn _session(
                     },
                 );
             }
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(2)) {
+            if ref_field_eq(args.fields, 5u8, 2) {
                 message_map.insert(
                     "total_strokes",
                     Field {
@@ -8218,7 +8262,7 @@ fn _session(
                     },
                 );
             }
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(5)) {
+            if ref_field_eq(args.fields, 5u8, 5) {
                 message_map.insert(
                     "total_strokes",
                     Field {
@@ -8241,7 +8285,7 @@ fn _session(
                     },
                 );
             }
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(15)) {
+            if ref_field_eq(args.fields, 5u8, 15) {
                 message_map.insert(
                     "total_strokes",
                     Field {
@@ -8264,7 +8308,7 @@ fn _session(
                     },
                 );
             }
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(37)) {
+            if ref_field_eq(args.fields, 5u8, 37) {
                 message_map.insert(
                     "total_strokes",
                     Field {
@@ -8516,7 +8560,7 @@ fn _session(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 5u8, 1) {
                 message_map.insert(
                     "avg_running_cadence",
                     Field {
@@ -8564,7 +8608,7 @@ fn _session(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 5u8, 1) {
                 message_map.insert(
                     "max_running_cadence",
                     Field {
@@ -11970,7 +12014,7 @@ fn _session(
     }
 }
 fn _lap(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -12286,7 +12330,7 @@ fn _lap(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 25u8, 1) {
                 message_map.insert(
                     "total_strides",
                     Field {
@@ -12309,7 +12353,7 @@ fn _lap(
                     },
                 );
             }
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(11)) {
+            if ref_field_eq(args.fields, 25u8, 11) {
                 message_map.insert(
                     "total_strides",
                     Field {
@@ -12332,7 +12376,7 @@ fn _lap(
                     },
                 );
             }
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(2)) {
+            if ref_field_eq(args.fields, 25u8, 2) {
                 message_map.insert(
                     "total_strokes",
                     Field {
@@ -12355,7 +12399,7 @@ fn _lap(
                     },
                 );
             }
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(5)) {
+            if ref_field_eq(args.fields, 25u8, 5) {
                 message_map.insert(
                     "total_strokes",
                     Field {
@@ -12378,7 +12422,7 @@ fn _lap(
                     },
                 );
             }
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(15)) {
+            if ref_field_eq(args.fields, 25u8, 15) {
                 message_map.insert(
                     "total_strokes",
                     Field {
@@ -12401,7 +12445,7 @@ fn _lap(
                     },
                 );
             }
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(37)) {
+            if ref_field_eq(args.fields, 25u8, 37) {
                 message_map.insert(
                     "total_strokes",
                     Field {
@@ -12653,7 +12697,7 @@ fn _lap(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 25u8, 1) {
                 message_map.insert(
                     "avg_running_cadence",
                     Field {
@@ -12701,7 +12745,7 @@ fn _lap(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 25u8, 1) {
                 message_map.insert(
                     "max_running_cadence",
                     Field {
@@ -15333,7 +15377,7 @@ fn _lap(
     }
 }
 fn _length(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -15933,7 +15977,7 @@ fn _length(
     }
 }
 fn _record(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -18184,7 +18228,7 @@ fn _record(
     }
 }
 fn _event(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -18338,7 +18382,7 @@ fn _event(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 0u8, 0) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::Enum(*v as u8))
                 } else {
@@ -18366,7 +18410,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(10)) {
+            if ref_field_eq(args.fields, 0u8, 10) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt16(*v as u16))
                 } else {
@@ -18394,7 +18438,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(11)) {
+            if ref_field_eq(args.fields, 0u8, 11) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt16(*v as u16))
                 } else {
@@ -18422,7 +18466,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(12)) {
+            if ref_field_eq(args.fields, 0u8, 12) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt16(*v as u16))
                 } else {
@@ -18450,7 +18494,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(13)) {
+            if ref_field_eq(args.fields, 0u8, 13) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt8(*v as u8))
                 } else {
@@ -18478,7 +18522,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(14)) {
+            if ref_field_eq(args.fields, 0u8, 14) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt8(*v as u8))
                 } else {
@@ -18506,7 +18550,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(15)) {
+            if ref_field_eq(args.fields, 0u8, 15) {
                 message_map.insert(
                     "speed_high_alert",
                     Field {
@@ -18529,7 +18573,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(16)) {
+            if ref_field_eq(args.fields, 0u8, 16) {
                 message_map.insert(
                     "speed_low_alert",
                     Field {
@@ -18552,7 +18596,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(17)) {
+            if ref_field_eq(args.fields, 0u8, 17) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt16(*v as u16))
                 } else {
@@ -18580,7 +18624,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(18)) {
+            if ref_field_eq(args.fields, 0u8, 18) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt16(*v as u16))
                 } else {
@@ -18608,7 +18652,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(19)) {
+            if ref_field_eq(args.fields, 0u8, 19) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt16(*v as u16))
                 } else {
@@ -18636,7 +18680,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(20)) {
+            if ref_field_eq(args.fields, 0u8, 20) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt16(*v as u16))
                 } else {
@@ -18664,7 +18708,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(23)) {
+            if ref_field_eq(args.fields, 0u8, 23) {
                 message_map.insert(
                     "time_duration_alert",
                     Field {
@@ -18687,7 +18731,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(24)) {
+            if ref_field_eq(args.fields, 0u8, 24) {
                 message_map.insert(
                     "distance_duration_alert",
                     Field {
@@ -18710,7 +18754,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(25)) {
+            if ref_field_eq(args.fields, 0u8, 25) {
                 message_map.insert(
                     "calorie_duration_alert",
                     Field {
@@ -18733,7 +18777,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(27)) {
+            if ref_field_eq(args.fields, 0u8, 27) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::Enum(*v as u8))
                 } else {
@@ -18763,7 +18807,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(33)) {
+            if ref_field_eq(args.fields, 0u8, 33) {
                 message_map.insert(
                     "sport_point",
                     Field {
@@ -18843,7 +18887,7 @@ fn _event(
                     is_subfield: false,
                 },
             );
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(42)) {
+            if ref_field_eq(args.fields, 0u8, 42) {
                 message_map.insert(
                     "gear_change_data",
                     Field {
@@ -18979,7 +19023,7 @@ fn _event(
                     is_subfield: false,
                 },
             );
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(43)) {
+            if ref_field_eq(args.fields, 0u8, 43) {
                 message_map.insert(
                     "gear_change_data",
                     Field {
@@ -19115,7 +19159,7 @@ fn _event(
                     is_subfield: false,
                 },
             );
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(44)) {
+            if ref_field_eq(args.fields, 0u8, 44) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::Enum(*v as u8))
                 } else {
@@ -19145,7 +19189,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(47)) {
+            if ref_field_eq(args.fields, 0u8, 47) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt16(*v as u16))
                 } else {
@@ -19175,7 +19219,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(56)) {
+            if ref_field_eq(args.fields, 0u8, 56) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::Enum(*v as u8))
                 } else {
@@ -19203,7 +19247,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(54)) {
+            if ref_field_eq(args.fields, 0u8, 54) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::UInt16(*v as u16))
                 } else {
@@ -19231,7 +19275,7 @@ fn _event(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(75)) {
+            if ref_field_eq(args.fields, 0u8, 75) {
                 message_map.insert(
                     "radar_threat_alert",
                     Field {
@@ -19610,7 +19654,7 @@ fn _event(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(54)) {
+            if ref_field_eq(args.fields, 0u8, 54) {
                 message_map.insert(
                     "auto_activity_detect_start_timestamp",
                     Field {
@@ -19740,7 +19784,7 @@ fn _event(
     }
 }
 fn _device_info(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -19816,7 +19860,7 @@ fn _device_info(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(3)) {
+            if ref_field_eq(args.fields, 25u8, 3) {
                 message_map.insert(
                     "ble_device_type",
                     Field {
@@ -19839,7 +19883,7 @@ fn _device_info(
                     },
                 );
             }
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 25u8, 1) {
                 message_map.insert(
                     "antplus_device_type",
                     Field {
@@ -19864,7 +19908,7 @@ fn _device_info(
                     },
                 );
             }
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 25u8, 0) {
                 message_map.insert(
                     "ant_device_type",
                     Field {
@@ -19887,7 +19931,7 @@ fn _device_info(
                     },
                 );
             }
-            if args.fields.get(&25u8) == Some(&fit::Value::Enum(5)) {
+            if ref_field_eq(args.fields, 25u8, 5) {
                 message_map.insert(
                     "local_device_type",
                     Field {
@@ -19985,7 +20029,7 @@ fn _device_info(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&2u8) == Some(&fit::Value::UInt16(263)) {
+            if ref_field_eq(args.fields, 2u8, 263) {
                 message_map.insert(
                     "favero_product",
                     Field {
@@ -20008,7 +20052,7 @@ fn _device_info(
                     },
                 );
             }
-            if args.fields.get(&2u8) == Some(&fit::Value::UInt16(1)) {
+            if ref_field_eq(args.fields, 2u8, 1) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -20031,7 +20075,7 @@ fn _device_info(
                     },
                 );
             }
-            if args.fields.get(&2u8) == Some(&fit::Value::UInt16(15)) {
+            if ref_field_eq(args.fields, 2u8, 15) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -20054,7 +20098,7 @@ fn _device_info(
                     },
                 );
             }
-            if args.fields.get(&2u8) == Some(&fit::Value::UInt16(13)) {
+            if ref_field_eq(args.fields, 2u8, 13) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -20077,7 +20121,7 @@ fn _device_info(
                     },
                 );
             }
-            if args.fields.get(&2u8) == Some(&fit::Value::UInt16(89)) {
+            if ref_field_eq(args.fields, 2u8, 89) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -20421,7 +20465,7 @@ fn _device_info(
     }
 }
 fn _device_aux_battery_info(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -20554,7 +20598,7 @@ fn _device_aux_battery_info(
 }
 /// Corresponds to file_id of workout or course.
 fn _training_file(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -20654,7 +20698,7 @@ fn _training_file(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&1u8) == Some(&fit::Value::UInt16(263)) {
+            if ref_field_eq(args.fields, 1u8, 263) {
                 message_map.insert(
                     "favero_product",
                     Field {
@@ -20677,7 +20721,7 @@ fn _training_file(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::UInt16(1)) {
+            if ref_field_eq(args.fields, 1u8, 1) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -20700,7 +20744,7 @@ fn _training_file(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::UInt16(15)) {
+            if ref_field_eq(args.fields, 1u8, 15) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -20723,7 +20767,7 @@ fn _training_file(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::UInt16(13)) {
+            if ref_field_eq(args.fields, 1u8, 13) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -20746,7 +20790,7 @@ fn _training_file(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::UInt16(89)) {
+            if ref_field_eq(args.fields, 1u8, 89) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -20826,7 +20870,7 @@ fn _training_file(
     }
 }
 fn _weather_conditions(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -21222,7 +21266,7 @@ fn _weather_conditions(
     }
 }
 fn _weather_alert(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -21378,7 +21422,7 @@ fn _weather_alert(
     }
 }
 fn _gps_metadata(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -21606,7 +21650,7 @@ fn _gps_metadata(
     }
 }
 fn _camera_event(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -21740,7 +21784,7 @@ fn _camera_event(
     }
 }
 fn _gyroscope_data(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -21968,7 +22012,7 @@ fn _gyroscope_data(
     }
 }
 fn _accelerometer_data(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -22268,7 +22312,7 @@ fn _accelerometer_data(
     }
 }
 fn _magnetometer_data(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -22496,7 +22540,7 @@ fn _magnetometer_data(
     }
 }
 fn _barometer_data(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -22604,7 +22648,7 @@ fn _barometer_data(
     }
 }
 fn _three_d_sensor_calibration(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -22680,7 +22724,7 @@ fn _three_d_sensor_calibration(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 0u8, 0) {
                 message_map.insert(
                     "accel_cal_factor",
                     Field {
@@ -22703,7 +22747,7 @@ fn _three_d_sensor_calibration(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 0u8, 1) {
                 message_map.insert(
                     "gyro_cal_factor",
                     Field {
@@ -22831,7 +22875,7 @@ fn _three_d_sensor_calibration(
     }
 }
 fn _one_d_sensor_calibration(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -22907,7 +22951,7 @@ fn _one_d_sensor_calibration(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&0u8) == Some(&fit::Value::Enum(3)) {
+            if ref_field_eq(args.fields, 0u8, 3) {
                 message_map.insert(
                     "baro_cal_factor",
                     Field {
@@ -23011,7 +23055,7 @@ fn _one_d_sensor_calibration(
     }
 }
 fn _video_frame(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -23095,7 +23139,7 @@ fn _video_frame(
     }
 }
 fn _obdii_data(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -23323,7 +23367,7 @@ fn _obdii_data(
     }
 }
 fn _nmea_sentence(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -23407,7 +23451,7 @@ fn _nmea_sentence(
     }
 }
 fn _aviation_attitude(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -23707,7 +23751,7 @@ fn _aviation_attitude(
     }
 }
 fn _video(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -23791,7 +23835,7 @@ fn _video(
     }
 }
 fn _video_title(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -23875,7 +23919,7 @@ fn _video_title(
     }
 }
 fn _video_description(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -23959,7 +24003,7 @@ fn _video_description(
     }
 }
 fn _video_clip(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -24139,7 +24183,7 @@ fn _video_clip(
     }
 }
 fn _set(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -24415,7 +24459,7 @@ fn _set(
     }
 }
 fn _jump(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -24697,7 +24741,7 @@ fn _jump(
     }
 }
 fn _split(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -25165,7 +25209,7 @@ fn _split(
     }
 }
 fn _split_summary(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -25513,7 +25557,7 @@ fn _split_summary(
     }
 }
 fn _climb_pro(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -25694,7 +25738,7 @@ fn _climb_pro(
 }
 /// Must be logged before developer field is used
 fn _field_description(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -26043,7 +26087,7 @@ fn _field_description(
 }
 /// Must be logged before field description
 fn _developer_data_id(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -26175,7 +26219,7 @@ fn _developer_data_id(
     }
 }
 fn _course(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -26285,7 +26329,7 @@ fn _course(
     }
 }
 fn _course_point(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -26490,7 +26534,7 @@ fn _course_point(
 }
 /// Unique Identification data for a segment file
 fn _segment_id(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -26723,7 +26767,7 @@ fn _segment_id(
 }
 /// Unique Identification data for an individual segment leader within a segment file
 fn _segment_leaderboard_entry(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -26906,7 +26950,7 @@ fn _segment_leaderboard_entry(
 }
 /// Navigation and race evaluation point for a segment decribing a point along the segment path and time it took each segment leader to reach that point
 fn _segment_point(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -27116,7 +27160,7 @@ fn _segment_point(
     }
 }
 fn _segment_lap(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -27432,7 +27476,7 @@ fn _segment_lap(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&23u8) == Some(&fit::Value::Enum(2)) {
+            if ref_field_eq(args.fields, 23u8, 2) {
                 message_map.insert(
                     "total_strokes",
                     Field {
@@ -29525,7 +29569,7 @@ fn _segment_lap(
 }
 /// Summary of the unique segment and leaderboard information associated with a segment file. This message is used to compile a segment list file describing all segment files on a device. The segment list file is used when refreshing the contents of a segment file with the latest available leaderboard information.
 fn _segment_file(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -29755,7 +29799,7 @@ fn _segment_file(
     }
 }
 fn _workout(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -29961,7 +30005,7 @@ fn _workout(
     }
 }
 fn _workout_session(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -30141,7 +30185,7 @@ fn _workout_session(
     }
 }
 fn _workout_step(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -30241,7 +30285,7 @@ fn _workout_step(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 1u8, 0) {
                 message_map.insert(
                     "duration_time",
                     Field {
@@ -30264,7 +30308,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(28)) {
+            if ref_field_eq(args.fields, 1u8, 28) {
                 message_map.insert(
                     "duration_time",
                     Field {
@@ -30287,7 +30331,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 1u8, 1) {
                 message_map.insert(
                     "duration_distance",
                     Field {
@@ -30310,7 +30354,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(2)) {
+            if ref_field_eq(args.fields, 1u8, 2) {
                 message_map.insert(
                     "duration_hr",
                     Field {
@@ -30333,7 +30377,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(3)) {
+            if ref_field_eq(args.fields, 1u8, 3) {
                 message_map.insert(
                     "duration_hr",
                     Field {
@@ -30356,7 +30400,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(4)) {
+            if ref_field_eq(args.fields, 1u8, 4) {
                 message_map.insert(
                     "duration_calories",
                     Field {
@@ -30379,7 +30423,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(6)) {
+            if ref_field_eq(args.fields, 1u8, 6) {
                 message_map.insert(
                     "duration_step",
                     Field {
@@ -30402,7 +30446,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(7)) {
+            if ref_field_eq(args.fields, 1u8, 7) {
                 message_map.insert(
                     "duration_step",
                     Field {
@@ -30425,7 +30469,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(8)) {
+            if ref_field_eq(args.fields, 1u8, 8) {
                 message_map.insert(
                     "duration_step",
                     Field {
@@ -30448,7 +30492,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(9)) {
+            if ref_field_eq(args.fields, 1u8, 9) {
                 message_map.insert(
                     "duration_step",
                     Field {
@@ -30471,7 +30515,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(10)) {
+            if ref_field_eq(args.fields, 1u8, 10) {
                 message_map.insert(
                     "duration_step",
                     Field {
@@ -30494,7 +30538,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(11)) {
+            if ref_field_eq(args.fields, 1u8, 11) {
                 message_map.insert(
                     "duration_step",
                     Field {
@@ -30517,7 +30561,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(12)) {
+            if ref_field_eq(args.fields, 1u8, 12) {
                 message_map.insert(
                     "duration_step",
                     Field {
@@ -30540,7 +30584,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(13)) {
+            if ref_field_eq(args.fields, 1u8, 13) {
                 message_map.insert(
                     "duration_step",
                     Field {
@@ -30563,7 +30607,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(14)) {
+            if ref_field_eq(args.fields, 1u8, 14) {
                 message_map.insert(
                     "duration_power",
                     Field {
@@ -30586,7 +30630,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(15)) {
+            if ref_field_eq(args.fields, 1u8, 15) {
                 message_map.insert(
                     "duration_power",
                     Field {
@@ -30609,7 +30653,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(29)) {
+            if ref_field_eq(args.fields, 1u8, 29) {
                 message_map.insert(
                     "duration_reps",
                     Field {
@@ -30681,7 +30725,7 @@ fn _workout_step(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 3u8, 0) {
                 message_map.insert(
                     "target_speed_zone",
                     Field {
@@ -30704,7 +30748,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 3u8, 1) {
                 message_map.insert(
                     "target_hr_zone",
                     Field {
@@ -30727,7 +30771,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(3)) {
+            if ref_field_eq(args.fields, 3u8, 3) {
                 message_map.insert(
                     "target_cadence_zone",
                     Field {
@@ -30750,7 +30794,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(4)) {
+            if ref_field_eq(args.fields, 3u8, 4) {
                 message_map.insert(
                     "target_power_zone",
                     Field {
@@ -30773,7 +30817,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(6)) {
+            if ref_field_eq(args.fields, 1u8, 6) {
                 message_map.insert(
                     "repeat_steps",
                     Field {
@@ -30796,7 +30840,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(7)) {
+            if ref_field_eq(args.fields, 1u8, 7) {
                 message_map.insert(
                     "repeat_time",
                     Field {
@@ -30819,7 +30863,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(8)) {
+            if ref_field_eq(args.fields, 1u8, 8) {
                 message_map.insert(
                     "repeat_distance",
                     Field {
@@ -30842,7 +30886,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(9)) {
+            if ref_field_eq(args.fields, 1u8, 9) {
                 message_map.insert(
                     "repeat_calories",
                     Field {
@@ -30865,7 +30909,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(10)) {
+            if ref_field_eq(args.fields, 1u8, 10) {
                 message_map.insert(
                     "repeat_hr",
                     Field {
@@ -30888,7 +30932,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(11)) {
+            if ref_field_eq(args.fields, 1u8, 11) {
                 message_map.insert(
                     "repeat_hr",
                     Field {
@@ -30911,7 +30955,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(12)) {
+            if ref_field_eq(args.fields, 1u8, 12) {
                 message_map.insert(
                     "repeat_power",
                     Field {
@@ -30934,7 +30978,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&1u8) == Some(&fit::Value::Enum(13)) {
+            if ref_field_eq(args.fields, 1u8, 13) {
                 message_map.insert(
                     "repeat_power",
                     Field {
@@ -30957,7 +31001,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(11)) {
+            if ref_field_eq(args.fields, 3u8, 11) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::Enum(*v as u8))
                 } else {
@@ -31010,7 +31054,7 @@ fn _workout_step(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 3u8, 0) {
                 message_map.insert(
                     "custom_target_speed_low",
                     Field {
@@ -31033,7 +31077,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 3u8, 1) {
                 message_map.insert(
                     "custom_target_heart_rate_low",
                     Field {
@@ -31056,7 +31100,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(3)) {
+            if ref_field_eq(args.fields, 3u8, 3) {
                 message_map.insert(
                     "custom_target_cadence_low",
                     Field {
@@ -31079,7 +31123,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(4)) {
+            if ref_field_eq(args.fields, 3u8, 4) {
                 message_map.insert(
                     "custom_target_power_low",
                     Field {
@@ -31127,7 +31171,7 @@ fn _workout_step(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 3u8, 0) {
                 message_map.insert(
                     "custom_target_speed_high",
                     Field {
@@ -31150,7 +31194,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 3u8, 1) {
                 message_map.insert(
                     "custom_target_heart_rate_high",
                     Field {
@@ -31173,7 +31217,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(3)) {
+            if ref_field_eq(args.fields, 3u8, 3) {
                 message_map.insert(
                     "custom_target_cadence_high",
                     Field {
@@ -31196,7 +31240,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&3u8) == Some(&fit::Value::Enum(4)) {
+            if ref_field_eq(args.fields, 3u8, 4) {
                 message_map.insert(
                     "custom_target_power_high",
                     Field {
@@ -31436,7 +31480,7 @@ fn _workout_step(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 19u8, 0) {
                 message_map.insert(
                     "secondary_target_speed_zone",
                     Field {
@@ -31459,7 +31503,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 19u8, 1) {
                 message_map.insert(
                     "secondary_target_hr_zone",
                     Field {
@@ -31482,7 +31526,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(3)) {
+            if ref_field_eq(args.fields, 19u8, 3) {
                 message_map.insert(
                     "secondary_target_cadence_zone",
                     Field {
@@ -31505,7 +31549,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(4)) {
+            if ref_field_eq(args.fields, 19u8, 4) {
                 message_map.insert(
                     "secondary_target_power_zone",
                     Field {
@@ -31528,7 +31572,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(11)) {
+            if ref_field_eq(args.fields, 19u8, 11) {
                 let value = if let fit::Value::UInt32(v) = args.value {
                     Cow::Owned(fit::Value::Enum(*v as u8))
                 } else {
@@ -31581,7 +31625,7 @@ fn _workout_step(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 19u8, 0) {
                 message_map.insert(
                     "secondary_custom_target_speed_low",
                     Field {
@@ -31604,7 +31648,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 19u8, 1) {
                 message_map.insert(
                     "secondary_custom_target_heart_rate_low",
                     Field {
@@ -31627,7 +31671,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(3)) {
+            if ref_field_eq(args.fields, 19u8, 3) {
                 message_map.insert(
                     "secondary_custom_target_cadence_low",
                     Field {
@@ -31650,7 +31694,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(4)) {
+            if ref_field_eq(args.fields, 19u8, 4) {
                 message_map.insert(
                     "secondary_custom_target_power_low",
                     Field {
@@ -31698,7 +31742,7 @@ fn _workout_step(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(0)) {
+            if ref_field_eq(args.fields, 19u8, 0) {
                 message_map.insert(
                     "secondary_custom_target_speed_high",
                     Field {
@@ -31721,7 +31765,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 19u8, 1) {
                 message_map.insert(
                     "secondary_custom_target_heart_rate_high",
                     Field {
@@ -31744,7 +31788,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(3)) {
+            if ref_field_eq(args.fields, 19u8, 3) {
                 message_map.insert(
                     "secondary_custom_target_cadence_high",
                     Field {
@@ -31767,7 +31811,7 @@ fn _workout_step(
                     },
                 );
             }
-            if args.fields.get(&19u8) == Some(&fit::Value::Enum(4)) {
+            if ref_field_eq(args.fields, 19u8, 4) {
                 message_map.insert(
                     "secondary_custom_target_power_high",
                     Field {
@@ -31799,7 +31843,7 @@ fn _workout_step(
     }
 }
 fn _exercise_title(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -31907,7 +31951,7 @@ fn _exercise_title(
     }
 }
 fn _schedule(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -31959,7 +32003,7 @@ fn _schedule(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&0u8) == Some(&fit::Value::UInt16(263)) {
+            if ref_field_eq(args.fields, 0u8, 263) {
                 message_map.insert(
                     "favero_product",
                     Field {
@@ -31982,7 +32026,7 @@ fn _schedule(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::UInt16(1)) {
+            if ref_field_eq(args.fields, 0u8, 1) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -32005,7 +32049,7 @@ fn _schedule(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::UInt16(15)) {
+            if ref_field_eq(args.fields, 0u8, 15) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -32028,7 +32072,7 @@ fn _schedule(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::UInt16(13)) {
+            if ref_field_eq(args.fields, 0u8, 13) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -32051,7 +32095,7 @@ fn _schedule(
                     },
                 );
             }
-            if args.fields.get(&0u8) == Some(&fit::Value::UInt16(89)) {
+            if ref_field_eq(args.fields, 0u8, 89) {
                 message_map.insert(
                     "garmin_product",
                     Field {
@@ -32203,7 +32247,7 @@ fn _schedule(
     }
 }
 fn _totals(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -32455,7 +32499,7 @@ fn _totals(
     }
 }
 fn _weight_scale(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -32803,7 +32847,7 @@ fn _weight_scale(
     }
 }
 fn _blood_pressure(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -33079,7 +33123,7 @@ fn _blood_pressure(
     }
 }
 fn _monitoring_info(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -33235,7 +33279,7 @@ fn _monitoring_info(
     }
 }
 fn _monitoring(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -33359,7 +33403,7 @@ fn _monitoring(
                 },
             );
             // expansion sub fields
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(6)) {
+            if ref_field_eq(args.fields, 5u8, 6) {
                 message_map.insert(
                     "steps",
                     Field {
@@ -33382,7 +33426,7 @@ fn _monitoring(
                     },
                 );
             }
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(1)) {
+            if ref_field_eq(args.fields, 5u8, 1) {
                 message_map.insert(
                     "steps",
                     Field {
@@ -33405,7 +33449,7 @@ fn _monitoring(
                     },
                 );
             }
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(2)) {
+            if ref_field_eq(args.fields, 5u8, 2) {
                 message_map.insert(
                     "strokes",
                     Field {
@@ -33428,7 +33472,7 @@ fn _monitoring(
                     },
                 );
             }
-            if args.fields.get(&5u8) == Some(&fit::Value::Enum(5)) {
+            if ref_field_eq(args.fields, 5u8, 5) {
                 message_map.insert(
                     "strokes",
                     Field {
@@ -34094,7 +34138,7 @@ fn _monitoring(
     }
 }
 fn _monitoring_hr_data(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -34178,7 +34222,7 @@ fn _monitoring_hr_data(
     }
 }
 fn _spo2_data(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -34288,7 +34332,7 @@ fn _spo2_data(
     }
 }
 fn _hr(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -34522,7 +34566,7 @@ fn _hr(
 }
 /// Value from 1 to 100 calculated by FirstBeat
 fn _stress_level(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -34582,7 +34626,7 @@ fn _stress_level(
     }
 }
 fn _max_met_data(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -34788,7 +34832,7 @@ fn _max_met_data(
     }
 }
 fn _memo_glob(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -34944,7 +34988,7 @@ fn _memo_glob(
     }
 }
 fn _sleep_level(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -35004,7 +35048,7 @@ fn _sleep_level(
     }
 }
 fn _ant_channel_id(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -35136,7 +35180,7 @@ fn _ant_channel_id(
     }
 }
 fn _ant_rx(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -35360,7 +35404,7 @@ fn _ant_rx(
     }
 }
 fn _ant_tx(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -35584,7 +35628,7 @@ fn _ant_tx(
     }
 }
 fn _exd_screen_configuration(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -35692,7 +35736,7 @@ fn _exd_screen_configuration(
     }
 }
 fn _exd_data_field_configuration(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -35906,7 +35950,7 @@ fn _exd_data_field_configuration(
     }
 }
 fn _exd_data_concept_configuration(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -36240,7 +36284,7 @@ fn _exd_data_concept_configuration(
     }
 }
 fn _dive_summary(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -36805,7 +36849,7 @@ fn _dive_summary(
 }
 /// Heart rate variability
 fn _hrv(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -36842,7 +36886,7 @@ fn _hrv(
 }
 /// Array of heart beat intervals
 fn _beat_intervals(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -36926,7 +36970,7 @@ fn _beat_intervals(
     }
 }
 fn _hrv_status_summary(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -37130,7 +37174,7 @@ fn _hrv_status_summary(
     }
 }
 fn _hrv_value(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -37190,7 +37234,7 @@ fn _hrv_value(
     }
 }
 fn _respiration_rate(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -37250,7 +37294,7 @@ fn _respiration_rate(
     }
 }
 fn _tank_update(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -37334,7 +37378,7 @@ fn _tank_update(
     }
 }
 fn _tank_summary(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -37466,7 +37510,7 @@ fn _tank_summary(
     }
 }
 fn _sleep_assessment(
-    message_map: &mut HashMap<&'static str, Field>,
+    message_map: &mut IndexMap<&'static str, Field>,
     accumulator: &mut crate::accumulator::Accumulator,
     args: MessageDecodeArgs,
 ) -> Result<(), String> {
@@ -37921,3 +37965,13085 @@ pub fn from_message_type(message_type: &str) -> Option<MessageDecoder> {
         _ => None,
     }
 }
+/// Profile name of a message field, e.g. `("workout_step", 8)` is `notes`
+pub fn field_name(message_type: &str, field_no: u8) -> Option<&'static str> {
+    match (message_type, field_no) {
+        ("file_id", 0) => Some("type"),
+        ("file_id", 1) => Some("manufacturer"),
+        ("file_id", 2) => Some("product"),
+        ("file_id", 3) => Some("serial_number"),
+        ("file_id", 4) => Some("time_created"),
+        ("file_id", 5) => Some("number"),
+        ("file_id", 8) => Some("product_name"),
+        ("file_creator", 0) => Some("software_version"),
+        ("file_creator", 1) => Some("hardware_version"),
+        ("timestamp_correlation", 253) => Some("timestamp"),
+        ("timestamp_correlation", 0) => Some("fractional_timestamp"),
+        ("timestamp_correlation", 1) => Some("system_timestamp"),
+        ("timestamp_correlation", 2) => Some("fractional_system_timestamp"),
+        ("timestamp_correlation", 3) => Some("local_timestamp"),
+        ("timestamp_correlation", 4) => Some("timestamp_ms"),
+        ("timestamp_correlation", 5) => Some("system_timestamp_ms"),
+        ("software", 254) => Some("message_index"),
+        ("software", 3) => Some("version"),
+        ("software", 5) => Some("part_number"),
+        ("slave_device", 0) => Some("manufacturer"),
+        ("slave_device", 1) => Some("product"),
+        ("capabilities", 0) => Some("languages"),
+        ("capabilities", 1) => Some("sports"),
+        ("capabilities", 21) => Some("workouts_supported"),
+        ("capabilities", 23) => Some("connectivity_supported"),
+        ("file_capabilities", 254) => Some("message_index"),
+        ("file_capabilities", 0) => Some("type"),
+        ("file_capabilities", 1) => Some("flags"),
+        ("file_capabilities", 2) => Some("directory"),
+        ("file_capabilities", 3) => Some("max_count"),
+        ("file_capabilities", 4) => Some("max_size"),
+        ("mesg_capabilities", 254) => Some("message_index"),
+        ("mesg_capabilities", 0) => Some("file"),
+        ("mesg_capabilities", 1) => Some("mesg_num"),
+        ("mesg_capabilities", 2) => Some("count_type"),
+        ("mesg_capabilities", 3) => Some("count"),
+        ("field_capabilities", 254) => Some("message_index"),
+        ("field_capabilities", 0) => Some("file"),
+        ("field_capabilities", 1) => Some("mesg_num"),
+        ("field_capabilities", 2) => Some("field_num"),
+        ("field_capabilities", 3) => Some("count"),
+        ("device_settings", 0) => Some("active_time_zone"),
+        ("device_settings", 1) => Some("utc_offset"),
+        ("device_settings", 2) => Some("time_offset"),
+        ("device_settings", 4) => Some("time_mode"),
+        ("device_settings", 5) => Some("time_zone_offset"),
+        ("device_settings", 12) => Some("backlight_mode"),
+        ("device_settings", 36) => Some("activity_tracker_enabled"),
+        ("device_settings", 39) => Some("clock_time"),
+        ("device_settings", 40) => Some("pages_enabled"),
+        ("device_settings", 46) => Some("move_alert_enabled"),
+        ("device_settings", 47) => Some("date_mode"),
+        ("device_settings", 55) => Some("display_orientation"),
+        ("device_settings", 56) => Some("mounting_side"),
+        ("device_settings", 57) => Some("default_page"),
+        ("device_settings", 58) => Some("autosync_min_steps"),
+        ("device_settings", 59) => Some("autosync_min_time"),
+        ("device_settings", 80) => Some("lactate_threshold_autodetect_enabled"),
+        ("device_settings", 86) => Some("ble_auto_upload_enabled"),
+        ("device_settings", 89) => Some("auto_sync_frequency"),
+        ("device_settings", 90) => Some("auto_activity_detect"),
+        ("device_settings", 94) => Some("number_of_screens"),
+        ("device_settings", 95) => Some("smart_notification_display_orientation"),
+        ("device_settings", 134) => Some("tap_interface"),
+        ("device_settings", 174) => Some("tap_sensitivity"),
+        ("user_profile", 254) => Some("message_index"),
+        ("user_profile", 0) => Some("friendly_name"),
+        ("user_profile", 1) => Some("gender"),
+        ("user_profile", 2) => Some("age"),
+        ("user_profile", 3) => Some("height"),
+        ("user_profile", 4) => Some("weight"),
+        ("user_profile", 5) => Some("language"),
+        ("user_profile", 6) => Some("elev_setting"),
+        ("user_profile", 7) => Some("weight_setting"),
+        ("user_profile", 8) => Some("resting_heart_rate"),
+        ("user_profile", 9) => Some("default_max_running_heart_rate"),
+        ("user_profile", 10) => Some("default_max_biking_heart_rate"),
+        ("user_profile", 11) => Some("default_max_heart_rate"),
+        ("user_profile", 12) => Some("hr_setting"),
+        ("user_profile", 13) => Some("speed_setting"),
+        ("user_profile", 14) => Some("dist_setting"),
+        ("user_profile", 16) => Some("power_setting"),
+        ("user_profile", 17) => Some("activity_class"),
+        ("user_profile", 18) => Some("position_setting"),
+        ("user_profile", 21) => Some("temperature_setting"),
+        ("user_profile", 22) => Some("local_id"),
+        ("user_profile", 23) => Some("global_id"),
+        ("user_profile", 28) => Some("wake_time"),
+        ("user_profile", 29) => Some("sleep_time"),
+        ("user_profile", 30) => Some("height_setting"),
+        ("user_profile", 31) => Some("user_running_step_length"),
+        ("user_profile", 32) => Some("user_walking_step_length"),
+        ("user_profile", 47) => Some("depth_setting"),
+        ("user_profile", 49) => Some("dive_count"),
+        ("hrm_profile", 254) => Some("message_index"),
+        ("hrm_profile", 0) => Some("enabled"),
+        ("hrm_profile", 1) => Some("hrm_ant_id"),
+        ("hrm_profile", 2) => Some("log_hrv"),
+        ("hrm_profile", 3) => Some("hrm_ant_id_trans_type"),
+        ("sdm_profile", 254) => Some("message_index"),
+        ("sdm_profile", 0) => Some("enabled"),
+        ("sdm_profile", 1) => Some("sdm_ant_id"),
+        ("sdm_profile", 2) => Some("sdm_cal_factor"),
+        ("sdm_profile", 3) => Some("odometer"),
+        ("sdm_profile", 4) => Some("speed_source"),
+        ("sdm_profile", 5) => Some("sdm_ant_id_trans_type"),
+        ("sdm_profile", 7) => Some("odometer_rollover"),
+        ("bike_profile", 254) => Some("message_index"),
+        ("bike_profile", 0) => Some("name"),
+        ("bike_profile", 1) => Some("sport"),
+        ("bike_profile", 2) => Some("sub_sport"),
+        ("bike_profile", 3) => Some("odometer"),
+        ("bike_profile", 4) => Some("bike_spd_ant_id"),
+        ("bike_profile", 5) => Some("bike_cad_ant_id"),
+        ("bike_profile", 6) => Some("bike_spdcad_ant_id"),
+        ("bike_profile", 7) => Some("bike_power_ant_id"),
+        ("bike_profile", 8) => Some("custom_wheelsize"),
+        ("bike_profile", 9) => Some("auto_wheelsize"),
+        ("bike_profile", 10) => Some("bike_weight"),
+        ("bike_profile", 11) => Some("power_cal_factor"),
+        ("bike_profile", 12) => Some("auto_wheel_cal"),
+        ("bike_profile", 13) => Some("auto_power_zero"),
+        ("bike_profile", 14) => Some("id"),
+        ("bike_profile", 15) => Some("spd_enabled"),
+        ("bike_profile", 16) => Some("cad_enabled"),
+        ("bike_profile", 17) => Some("spdcad_enabled"),
+        ("bike_profile", 18) => Some("power_enabled"),
+        ("bike_profile", 19) => Some("crank_length"),
+        ("bike_profile", 20) => Some("enabled"),
+        ("bike_profile", 21) => Some("bike_spd_ant_id_trans_type"),
+        ("bike_profile", 22) => Some("bike_cad_ant_id_trans_type"),
+        ("bike_profile", 23) => Some("bike_spdcad_ant_id_trans_type"),
+        ("bike_profile", 24) => Some("bike_power_ant_id_trans_type"),
+        ("bike_profile", 37) => Some("odometer_rollover"),
+        ("bike_profile", 38) => Some("front_gear_num"),
+        ("bike_profile", 39) => Some("front_gear"),
+        ("bike_profile", 40) => Some("rear_gear_num"),
+        ("bike_profile", 41) => Some("rear_gear"),
+        ("bike_profile", 44) => Some("shimano_di2_enabled"),
+        ("connectivity", 0) => Some("bluetooth_enabled"),
+        ("connectivity", 1) => Some("bluetooth_le_enabled"),
+        ("connectivity", 2) => Some("ant_enabled"),
+        ("connectivity", 3) => Some("name"),
+        ("connectivity", 4) => Some("live_tracking_enabled"),
+        ("connectivity", 5) => Some("weather_conditions_enabled"),
+        ("connectivity", 6) => Some("weather_alerts_enabled"),
+        ("connectivity", 7) => Some("auto_activity_upload_enabled"),
+        ("connectivity", 8) => Some("course_download_enabled"),
+        ("connectivity", 9) => Some("workout_download_enabled"),
+        ("connectivity", 10) => Some("gps_ephemeris_download_enabled"),
+        ("connectivity", 11) => Some("incident_detection_enabled"),
+        ("connectivity", 12) => Some("grouptrack_enabled"),
+        ("watchface_settings", 254) => Some("message_index"),
+        ("watchface_settings", 0) => Some("mode"),
+        ("watchface_settings", 1) => Some("layout"),
+        ("ohr_settings", 253) => Some("timestamp"),
+        ("ohr_settings", 0) => Some("enabled"),
+        ("time_in_zone", 253) => Some("timestamp"),
+        ("time_in_zone", 0) => Some("reference_mesg"),
+        ("time_in_zone", 1) => Some("reference_index"),
+        ("time_in_zone", 2) => Some("time_in_hr_zone"),
+        ("time_in_zone", 3) => Some("time_in_speed_zone"),
+        ("time_in_zone", 4) => Some("time_in_cadence_zone"),
+        ("time_in_zone", 5) => Some("time_in_power_zone"),
+        ("time_in_zone", 6) => Some("hr_zone_high_boundary"),
+        ("time_in_zone", 7) => Some("speed_zone_high_boundary"),
+        ("time_in_zone", 8) => Some("cadence_zone_high_bondary"),
+        ("time_in_zone", 9) => Some("power_zone_high_boundary"),
+        ("time_in_zone", 10) => Some("hr_calc_type"),
+        ("time_in_zone", 11) => Some("max_heart_rate"),
+        ("time_in_zone", 12) => Some("resting_heart_rate"),
+        ("time_in_zone", 13) => Some("threshold_heart_rate"),
+        ("time_in_zone", 14) => Some("pwr_calc_type"),
+        ("time_in_zone", 15) => Some("functional_threshold_power"),
+        ("zones_target", 1) => Some("max_heart_rate"),
+        ("zones_target", 2) => Some("threshold_heart_rate"),
+        ("zones_target", 3) => Some("functional_threshold_power"),
+        ("zones_target", 5) => Some("hr_calc_type"),
+        ("zones_target", 7) => Some("pwr_calc_type"),
+        ("sport", 0) => Some("sport"),
+        ("sport", 1) => Some("sub_sport"),
+        ("sport", 3) => Some("name"),
+        ("hr_zone", 254) => Some("message_index"),
+        ("hr_zone", 1) => Some("high_bpm"),
+        ("hr_zone", 2) => Some("name"),
+        ("speed_zone", 254) => Some("message_index"),
+        ("speed_zone", 0) => Some("high_value"),
+        ("speed_zone", 1) => Some("name"),
+        ("cadence_zone", 254) => Some("message_index"),
+        ("cadence_zone", 0) => Some("high_value"),
+        ("cadence_zone", 1) => Some("name"),
+        ("power_zone", 254) => Some("message_index"),
+        ("power_zone", 1) => Some("high_value"),
+        ("power_zone", 2) => Some("name"),
+        ("met_zone", 254) => Some("message_index"),
+        ("met_zone", 1) => Some("high_bpm"),
+        ("met_zone", 2) => Some("calories"),
+        ("met_zone", 3) => Some("fat_calories"),
+        ("dive_settings", 253) => Some("timestamp"),
+        ("dive_settings", 254) => Some("message_index"),
+        ("dive_settings", 0) => Some("name"),
+        ("dive_settings", 1) => Some("model"),
+        ("dive_settings", 2) => Some("gf_low"),
+        ("dive_settings", 3) => Some("gf_high"),
+        ("dive_settings", 4) => Some("water_type"),
+        ("dive_settings", 5) => Some("water_density"),
+        ("dive_settings", 6) => Some("po2_warn"),
+        ("dive_settings", 7) => Some("po2_critical"),
+        ("dive_settings", 8) => Some("po2_deco"),
+        ("dive_settings", 9) => Some("safety_stop_enabled"),
+        ("dive_settings", 10) => Some("bottom_depth"),
+        ("dive_settings", 11) => Some("bottom_time"),
+        ("dive_settings", 12) => Some("apnea_countdown_enabled"),
+        ("dive_settings", 13) => Some("apnea_countdown_time"),
+        ("dive_settings", 14) => Some("backlight_mode"),
+        ("dive_settings", 15) => Some("backlight_brightness"),
+        ("dive_settings", 16) => Some("backlight_timeout"),
+        ("dive_settings", 17) => Some("repeat_dive_interval"),
+        ("dive_settings", 18) => Some("safety_stop_time"),
+        ("dive_settings", 19) => Some("heart_rate_source_type"),
+        ("dive_settings", 20) => Some("heart_rate_source"),
+        ("dive_settings", 21) => Some("travel_gas"),
+        ("dive_settings", 22) => Some("ccr_low_setpoint_switch_mode"),
+        ("dive_settings", 23) => Some("ccr_low_setpoint"),
+        ("dive_settings", 24) => Some("ccr_low_setpoint_depth"),
+        ("dive_settings", 25) => Some("ccr_high_setpoint_switch_mode"),
+        ("dive_settings", 26) => Some("ccr_high_setpoint"),
+        ("dive_settings", 27) => Some("ccr_high_setpoint_depth"),
+        ("dive_settings", 29) => Some("gas_consumption_display"),
+        ("dive_settings", 30) => Some("up_key_enabled"),
+        ("dive_settings", 35) => Some("dive_sounds"),
+        ("dive_settings", 36) => Some("last_stop_multiple"),
+        ("dive_settings", 37) => Some("no_fly_time_mode"),
+        ("dive_alarm", 254) => Some("message_index"),
+        ("dive_alarm", 0) => Some("depth"),
+        ("dive_alarm", 1) => Some("time"),
+        ("dive_alarm", 2) => Some("enabled"),
+        ("dive_alarm", 3) => Some("alarm_type"),
+        ("dive_alarm", 4) => Some("sound"),
+        ("dive_alarm", 5) => Some("dive_types"),
+        ("dive_alarm", 6) => Some("id"),
+        ("dive_alarm", 7) => Some("popup_enabled"),
+        ("dive_alarm", 8) => Some("trigger_on_descent"),
+        ("dive_alarm", 9) => Some("trigger_on_ascent"),
+        ("dive_alarm", 10) => Some("repeating"),
+        ("dive_alarm", 11) => Some("speed"),
+        ("dive_apnea_alarm", 254) => Some("message_index"),
+        ("dive_apnea_alarm", 0) => Some("depth"),
+        ("dive_apnea_alarm", 1) => Some("time"),
+        ("dive_apnea_alarm", 2) => Some("enabled"),
+        ("dive_apnea_alarm", 3) => Some("alarm_type"),
+        ("dive_apnea_alarm", 4) => Some("sound"),
+        ("dive_apnea_alarm", 5) => Some("dive_types"),
+        ("dive_apnea_alarm", 6) => Some("id"),
+        ("dive_apnea_alarm", 7) => Some("popup_enabled"),
+        ("dive_apnea_alarm", 8) => Some("trigger_on_descent"),
+        ("dive_apnea_alarm", 9) => Some("trigger_on_ascent"),
+        ("dive_apnea_alarm", 10) => Some("repeating"),
+        ("dive_apnea_alarm", 11) => Some("speed"),
+        ("dive_gas", 254) => Some("message_index"),
+        ("dive_gas", 0) => Some("helium_content"),
+        ("dive_gas", 1) => Some("oxygen_content"),
+        ("dive_gas", 2) => Some("status"),
+        ("dive_gas", 3) => Some("mode"),
+        ("goal", 254) => Some("message_index"),
+        ("goal", 0) => Some("sport"),
+        ("goal", 1) => Some("sub_sport"),
+        ("goal", 2) => Some("start_date"),
+        ("goal", 3) => Some("end_date"),
+        ("goal", 4) => Some("type"),
+        ("goal", 5) => Some("value"),
+        ("goal", 6) => Some("repeat"),
+        ("goal", 7) => Some("target_value"),
+        ("goal", 8) => Some("recurrence"),
+        ("goal", 9) => Some("recurrence_value"),
+        ("goal", 10) => Some("enabled"),
+        ("goal", 11) => Some("source"),
+        ("activity", 253) => Some("timestamp"),
+        ("activity", 0) => Some("total_timer_time"),
+        ("activity", 1) => Some("num_sessions"),
+        ("activity", 2) => Some("type"),
+        ("activity", 3) => Some("event"),
+        ("activity", 4) => Some("event_type"),
+        ("activity", 5) => Some("local_timestamp"),
+        ("activity", 6) => Some("event_group"),
+        ("session", 254) => Some("message_index"),
+        ("session", 253) => Some("timestamp"),
+        ("session", 0) => Some("event"),
+        ("session", 1) => Some("event_type"),
+        ("session", 2) => Some("start_time"),
+        ("session", 3) => Some("start_position_lat"),
+        ("session", 4) => Some("start_position_long"),
+        ("session", 5) => Some("sport"),
+        ("session", 6) => Some("sub_sport"),
+        ("session", 7) => Some("total_elapsed_time"),
+        ("session", 8) => Some("total_timer_time"),
+        ("session", 9) => Some("total_distance"),
+        ("session", 10) => Some("total_cycles"),
+        ("session", 11) => Some("total_calories"),
+        ("session", 13) => Some("total_fat_calories"),
+        ("session", 14) => Some("avg_speed"),
+        ("session", 15) => Some("max_speed"),
+        ("session", 16) => Some("avg_heart_rate"),
+        ("session", 17) => Some("max_heart_rate"),
+        ("session", 18) => Some("avg_cadence"),
+        ("session", 19) => Some("max_cadence"),
+        ("session", 20) => Some("avg_power"),
+        ("session", 21) => Some("max_power"),
+        ("session", 22) => Some("total_ascent"),
+        ("session", 23) => Some("total_descent"),
+        ("session", 24) => Some("total_training_effect"),
+        ("session", 25) => Some("first_lap_index"),
+        ("session", 26) => Some("num_laps"),
+        ("session", 27) => Some("event_group"),
+        ("session", 28) => Some("trigger"),
+        ("session", 29) => Some("nec_lat"),
+        ("session", 30) => Some("nec_long"),
+        ("session", 31) => Some("swc_lat"),
+        ("session", 32) => Some("swc_long"),
+        ("session", 33) => Some("num_lengths"),
+        ("session", 34) => Some("normalized_power"),
+        ("session", 35) => Some("training_stress_score"),
+        ("session", 36) => Some("intensity_factor"),
+        ("session", 37) => Some("left_right_balance"),
+        ("session", 38) => Some("end_position_lat"),
+        ("session", 39) => Some("end_position_long"),
+        ("session", 41) => Some("avg_stroke_count"),
+        ("session", 42) => Some("avg_stroke_distance"),
+        ("session", 43) => Some("swim_stroke"),
+        ("session", 44) => Some("pool_length"),
+        ("session", 45) => Some("threshold_power"),
+        ("session", 46) => Some("pool_length_unit"),
+        ("session", 47) => Some("num_active_lengths"),
+        ("session", 48) => Some("total_work"),
+        ("session", 49) => Some("avg_altitude"),
+        ("session", 50) => Some("max_altitude"),
+        ("session", 51) => Some("gps_accuracy"),
+        ("session", 52) => Some("avg_grade"),
+        ("session", 53) => Some("avg_pos_grade"),
+        ("session", 54) => Some("avg_neg_grade"),
+        ("session", 55) => Some("max_pos_grade"),
+        ("session", 56) => Some("max_neg_grade"),
+        ("session", 57) => Some("avg_temperature"),
+        ("session", 58) => Some("max_temperature"),
+        ("session", 59) => Some("total_moving_time"),
+        ("session", 60) => Some("avg_pos_vertical_speed"),
+        ("session", 61) => Some("avg_neg_vertical_speed"),
+        ("session", 62) => Some("max_pos_vertical_speed"),
+        ("session", 63) => Some("max_neg_vertical_speed"),
+        ("session", 64) => Some("min_heart_rate"),
+        ("session", 65) => Some("time_in_hr_zone"),
+        ("session", 66) => Some("time_in_speed_zone"),
+        ("session", 67) => Some("time_in_cadence_zone"),
+        ("session", 68) => Some("time_in_power_zone"),
+        ("session", 69) => Some("avg_lap_time"),
+        ("session", 70) => Some("best_lap_index"),
+        ("session", 71) => Some("min_altitude"),
+        ("session", 82) => Some("player_score"),
+        ("session", 83) => Some("opponent_score"),
+        ("session", 84) => Some("opponent_name"),
+        ("session", 85) => Some("stroke_count"),
+        ("session", 86) => Some("zone_count"),
+        ("session", 87) => Some("max_ball_speed"),
+        ("session", 88) => Some("avg_ball_speed"),
+        ("session", 89) => Some("avg_vertical_oscillation"),
+        ("session", 90) => Some("avg_stance_time_percent"),
+        ("session", 91) => Some("avg_stance_time"),
+        ("session", 92) => Some("avg_fractional_cadence"),
+        ("session", 93) => Some("max_fractional_cadence"),
+        ("session", 94) => Some("total_fractional_cycles"),
+        ("session", 95) => Some("avg_total_hemoglobin_conc"),
+        ("session", 96) => Some("min_total_hemoglobin_conc"),
+        ("session", 97) => Some("max_total_hemoglobin_conc"),
+        ("session", 98) => Some("avg_saturated_hemoglobin_percent"),
+        ("session", 99) => Some("min_saturated_hemoglobin_percent"),
+        ("session", 100) => Some("max_saturated_hemoglobin_percent"),
+        ("session", 101) => Some("avg_left_torque_effectiveness"),
+        ("session", 102) => Some("avg_right_torque_effectiveness"),
+        ("session", 103) => Some("avg_left_pedal_smoothness"),
+        ("session", 104) => Some("avg_right_pedal_smoothness"),
+        ("session", 105) => Some("avg_combined_pedal_smoothness"),
+        ("session", 110) => Some("sport_profile_name"),
+        ("session", 111) => Some("sport_index"),
+        ("session", 112) => Some("time_standing"),
+        ("session", 113) => Some("stand_count"),
+        ("session", 114) => Some("avg_left_pco"),
+        ("session", 115) => Some("avg_right_pco"),
+        ("session", 116) => Some("avg_left_power_phase"),
+        ("session", 117) => Some("avg_left_power_phase_peak"),
+        ("session", 118) => Some("avg_right_power_phase"),
+        ("session", 119) => Some("avg_right_power_phase_peak"),
+        ("session", 120) => Some("avg_power_position"),
+        ("session", 121) => Some("max_power_position"),
+        ("session", 122) => Some("avg_cadence_position"),
+        ("session", 123) => Some("max_cadence_position"),
+        ("session", 124) => Some("enhanced_avg_speed"),
+        ("session", 125) => Some("enhanced_max_speed"),
+        ("session", 126) => Some("enhanced_avg_altitude"),
+        ("session", 127) => Some("enhanced_min_altitude"),
+        ("session", 128) => Some("enhanced_max_altitude"),
+        ("session", 129) => Some("avg_lev_motor_power"),
+        ("session", 130) => Some("max_lev_motor_power"),
+        ("session", 131) => Some("lev_battery_consumption"),
+        ("session", 132) => Some("avg_vertical_ratio"),
+        ("session", 133) => Some("avg_stance_time_balance"),
+        ("session", 134) => Some("avg_step_length"),
+        ("session", 137) => Some("total_anaerobic_training_effect"),
+        ("session", 139) => Some("avg_vam"),
+        ("session", 140) => Some("avg_depth"),
+        ("session", 141) => Some("max_depth"),
+        ("session", 142) => Some("surface_interval"),
+        ("session", 143) => Some("start_cns"),
+        ("session", 144) => Some("end_cns"),
+        ("session", 145) => Some("start_n2"),
+        ("session", 146) => Some("end_n2"),
+        ("session", 147) => Some("avg_respiration_rate"),
+        ("session", 148) => Some("max_respiration_rate"),
+        ("session", 149) => Some("min_respiration_rate"),
+        ("session", 150) => Some("min_temperature"),
+        ("session", 155) => Some("o2_toxicity"),
+        ("session", 156) => Some("dive_number"),
+        ("session", 168) => Some("training_load_peak"),
+        ("session", 169) => Some("enhanced_avg_respiration_rate"),
+        ("session", 170) => Some("enhanced_max_respiration_rate"),
+        ("session", 180) => Some("enhanced_min_respiration_rate"),
+        ("session", 181) => Some("total_grit"),
+        ("session", 182) => Some("total_flow"),
+        ("session", 183) => Some("jump_count"),
+        ("session", 186) => Some("avg_grit"),
+        ("session", 187) => Some("avg_flow"),
+        ("session", 194) => Some("avg_spo2"),
+        ("session", 195) => Some("avg_stress"),
+        ("session", 197) => Some("sdrr_hrv"),
+        ("session", 198) => Some("rmssd_hrv"),
+        ("session", 199) => Some("total_fractional_ascent"),
+        ("session", 200) => Some("total_fractional_descent"),
+        ("session", 208) => Some("avg_core_temperature"),
+        ("session", 209) => Some("min_core_temperature"),
+        ("session", 210) => Some("max_core_temperature"),
+        ("lap", 254) => Some("message_index"),
+        ("lap", 253) => Some("timestamp"),
+        ("lap", 0) => Some("event"),
+        ("lap", 1) => Some("event_type"),
+        ("lap", 2) => Some("start_time"),
+        ("lap", 3) => Some("start_position_lat"),
+        ("lap", 4) => Some("start_position_long"),
+        ("lap", 5) => Some("end_position_lat"),
+        ("lap", 6) => Some("end_position_long"),
+        ("lap", 7) => Some("total_elapsed_time"),
+        ("lap", 8) => Some("total_timer_time"),
+        ("lap", 9) => Some("total_distance"),
+        ("lap", 10) => Some("total_cycles"),
+        ("lap", 11) => Some("total_calories"),
+        ("lap", 12) => Some("total_fat_calories"),
+        ("lap", 13) => Some("avg_speed"),
+        ("lap", 14) => Some("max_speed"),
+        ("lap", 15) => Some("avg_heart_rate"),
+        ("lap", 16) => Some("max_heart_rate"),
+        ("lap", 17) => Some("avg_cadence"),
+        ("lap", 18) => Some("max_cadence"),
+        ("lap", 19) => Some("avg_power"),
+        ("lap", 20) => Some("max_power"),
+        ("lap", 21) => Some("total_ascent"),
+        ("lap", 22) => Some("total_descent"),
+        ("lap", 23) => Some("intensity"),
+        ("lap", 24) => Some("lap_trigger"),
+        ("lap", 25) => Some("sport"),
+        ("lap", 26) => Some("event_group"),
+        ("lap", 32) => Some("num_lengths"),
+        ("lap", 33) => Some("normalized_power"),
+        ("lap", 34) => Some("left_right_balance"),
+        ("lap", 35) => Some("first_length_index"),
+        ("lap", 37) => Some("avg_stroke_distance"),
+        ("lap", 38) => Some("swim_stroke"),
+        ("lap", 39) => Some("sub_sport"),
+        ("lap", 40) => Some("num_active_lengths"),
+        ("lap", 41) => Some("total_work"),
+        ("lap", 42) => Some("avg_altitude"),
+        ("lap", 43) => Some("max_altitude"),
+        ("lap", 44) => Some("gps_accuracy"),
+        ("lap", 45) => Some("avg_grade"),
+        ("lap", 46) => Some("avg_pos_grade"),
+        ("lap", 47) => Some("avg_neg_grade"),
+        ("lap", 48) => Some("max_pos_grade"),
+        ("lap", 49) => Some("max_neg_grade"),
+        ("lap", 50) => Some("avg_temperature"),
+        ("lap", 51) => Some("max_temperature"),
+        ("lap", 52) => Some("total_moving_time"),
+        ("lap", 53) => Some("avg_pos_vertical_speed"),
+        ("lap", 54) => Some("avg_neg_vertical_speed"),
+        ("lap", 55) => Some("max_pos_vertical_speed"),
+        ("lap", 56) => Some("max_neg_vertical_speed"),
+        ("lap", 57) => Some("time_in_hr_zone"),
+        ("lap", 58) => Some("time_in_speed_zone"),
+        ("lap", 59) => Some("time_in_cadence_zone"),
+        ("lap", 60) => Some("time_in_power_zone"),
+        ("lap", 61) => Some("repetition_num"),
+        ("lap", 62) => Some("min_altitude"),
+        ("lap", 63) => Some("min_heart_rate"),
+        ("lap", 71) => Some("wkt_step_index"),
+        ("lap", 74) => Some("opponent_score"),
+        ("lap", 75) => Some("stroke_count"),
+        ("lap", 76) => Some("zone_count"),
+        ("lap", 77) => Some("avg_vertical_oscillation"),
+        ("lap", 78) => Some("avg_stance_time_percent"),
+        ("lap", 79) => Some("avg_stance_time"),
+        ("lap", 80) => Some("avg_fractional_cadence"),
+        ("lap", 81) => Some("max_fractional_cadence"),
+        ("lap", 82) => Some("total_fractional_cycles"),
+        ("lap", 83) => Some("player_score"),
+        ("lap", 84) => Some("avg_total_hemoglobin_conc"),
+        ("lap", 85) => Some("min_total_hemoglobin_conc"),
+        ("lap", 86) => Some("max_total_hemoglobin_conc"),
+        ("lap", 87) => Some("avg_saturated_hemoglobin_percent"),
+        ("lap", 88) => Some("min_saturated_hemoglobin_percent"),
+        ("lap", 89) => Some("max_saturated_hemoglobin_percent"),
+        ("lap", 91) => Some("avg_left_torque_effectiveness"),
+        ("lap", 92) => Some("avg_right_torque_effectiveness"),
+        ("lap", 93) => Some("avg_left_pedal_smoothness"),
+        ("lap", 94) => Some("avg_right_pedal_smoothness"),
+        ("lap", 95) => Some("avg_combined_pedal_smoothness"),
+        ("lap", 98) => Some("time_standing"),
+        ("lap", 99) => Some("stand_count"),
+        ("lap", 100) => Some("avg_left_pco"),
+        ("lap", 101) => Some("avg_right_pco"),
+        ("lap", 102) => Some("avg_left_power_phase"),
+        ("lap", 103) => Some("avg_left_power_phase_peak"),
+        ("lap", 104) => Some("avg_right_power_phase"),
+        ("lap", 105) => Some("avg_right_power_phase_peak"),
+        ("lap", 106) => Some("avg_power_position"),
+        ("lap", 107) => Some("max_power_position"),
+        ("lap", 108) => Some("avg_cadence_position"),
+        ("lap", 109) => Some("max_cadence_position"),
+        ("lap", 110) => Some("enhanced_avg_speed"),
+        ("lap", 111) => Some("enhanced_max_speed"),
+        ("lap", 112) => Some("enhanced_avg_altitude"),
+        ("lap", 113) => Some("enhanced_min_altitude"),
+        ("lap", 114) => Some("enhanced_max_altitude"),
+        ("lap", 115) => Some("avg_lev_motor_power"),
+        ("lap", 116) => Some("max_lev_motor_power"),
+        ("lap", 117) => Some("lev_battery_consumption"),
+        ("lap", 118) => Some("avg_vertical_ratio"),
+        ("lap", 119) => Some("avg_stance_time_balance"),
+        ("lap", 120) => Some("avg_step_length"),
+        ("lap", 121) => Some("avg_vam"),
+        ("lap", 122) => Some("avg_depth"),
+        ("lap", 123) => Some("max_depth"),
+        ("lap", 124) => Some("min_temperature"),
+        ("lap", 136) => Some("enhanced_avg_respiration_rate"),
+        ("lap", 137) => Some("enhanced_max_respiration_rate"),
+        ("lap", 147) => Some("avg_respiration_rate"),
+        ("lap", 148) => Some("max_respiration_rate"),
+        ("lap", 149) => Some("total_grit"),
+        ("lap", 150) => Some("total_flow"),
+        ("lap", 151) => Some("jump_count"),
+        ("lap", 153) => Some("avg_grit"),
+        ("lap", 154) => Some("avg_flow"),
+        ("lap", 156) => Some("total_fractional_ascent"),
+        ("lap", 157) => Some("total_fractional_descent"),
+        ("lap", 158) => Some("avg_core_temperature"),
+        ("lap", 159) => Some("min_core_temperature"),
+        ("lap", 160) => Some("max_core_temperature"),
+        ("length", 254) => Some("message_index"),
+        ("length", 253) => Some("timestamp"),
+        ("length", 0) => Some("event"),
+        ("length", 1) => Some("event_type"),
+        ("length", 2) => Some("start_time"),
+        ("length", 3) => Some("total_elapsed_time"),
+        ("length", 4) => Some("total_timer_time"),
+        ("length", 5) => Some("total_strokes"),
+        ("length", 6) => Some("avg_speed"),
+        ("length", 7) => Some("swim_stroke"),
+        ("length", 9) => Some("avg_swimming_cadence"),
+        ("length", 10) => Some("event_group"),
+        ("length", 11) => Some("total_calories"),
+        ("length", 12) => Some("length_type"),
+        ("length", 18) => Some("player_score"),
+        ("length", 19) => Some("opponent_score"),
+        ("length", 20) => Some("stroke_count"),
+        ("length", 21) => Some("zone_count"),
+        ("length", 22) => Some("enhanced_avg_respiration_rate"),
+        ("length", 23) => Some("enhanced_max_respiration_rate"),
+        ("length", 24) => Some("avg_respiration_rate"),
+        ("length", 25) => Some("max_respiration_rate"),
+        ("record", 253) => Some("timestamp"),
+        ("record", 0) => Some("position_lat"),
+        ("record", 1) => Some("position_long"),
+        ("record", 2) => Some("altitude"),
+        ("record", 3) => Some("heart_rate"),
+        ("record", 4) => Some("cadence"),
+        ("record", 5) => Some("distance"),
+        ("record", 6) => Some("speed"),
+        ("record", 7) => Some("power"),
+        ("record", 8) => Some("compressed_speed_distance"),
+        ("record", 9) => Some("grade"),
+        ("record", 10) => Some("resistance"),
+        ("record", 11) => Some("time_from_course"),
+        ("record", 12) => Some("cycle_length"),
+        ("record", 13) => Some("temperature"),
+        ("record", 17) => Some("speed_1s"),
+        ("record", 18) => Some("cycles"),
+        ("record", 19) => Some("total_cycles"),
+        ("record", 28) => Some("compressed_accumulated_power"),
+        ("record", 29) => Some("accumulated_power"),
+        ("record", 30) => Some("left_right_balance"),
+        ("record", 31) => Some("gps_accuracy"),
+        ("record", 32) => Some("vertical_speed"),
+        ("record", 33) => Some("calories"),
+        ("record", 39) => Some("vertical_oscillation"),
+        ("record", 40) => Some("stance_time_percent"),
+        ("record", 41) => Some("stance_time"),
+        ("record", 42) => Some("activity_type"),
+        ("record", 43) => Some("left_torque_effectiveness"),
+        ("record", 44) => Some("right_torque_effectiveness"),
+        ("record", 45) => Some("left_pedal_smoothness"),
+        ("record", 46) => Some("right_pedal_smoothness"),
+        ("record", 47) => Some("combined_pedal_smoothness"),
+        ("record", 48) => Some("time128"),
+        ("record", 49) => Some("stroke_type"),
+        ("record", 50) => Some("zone"),
+        ("record", 51) => Some("ball_speed"),
+        ("record", 52) => Some("cadence256"),
+        ("record", 53) => Some("fractional_cadence"),
+        ("record", 54) => Some("total_hemoglobin_conc"),
+        ("record", 55) => Some("total_hemoglobin_conc_min"),
+        ("record", 56) => Some("total_hemoglobin_conc_max"),
+        ("record", 57) => Some("saturated_hemoglobin_percent"),
+        ("record", 58) => Some("saturated_hemoglobin_percent_min"),
+        ("record", 59) => Some("saturated_hemoglobin_percent_max"),
+        ("record", 62) => Some("device_index"),
+        ("record", 67) => Some("left_pco"),
+        ("record", 68) => Some("right_pco"),
+        ("record", 69) => Some("left_power_phase"),
+        ("record", 70) => Some("left_power_phase_peak"),
+        ("record", 71) => Some("right_power_phase"),
+        ("record", 72) => Some("right_power_phase_peak"),
+        ("record", 73) => Some("enhanced_speed"),
+        ("record", 78) => Some("enhanced_altitude"),
+        ("record", 81) => Some("battery_soc"),
+        ("record", 82) => Some("motor_power"),
+        ("record", 83) => Some("vertical_ratio"),
+        ("record", 84) => Some("stance_time_balance"),
+        ("record", 85) => Some("step_length"),
+        ("record", 87) => Some("cycle_length16"),
+        ("record", 91) => Some("absolute_pressure"),
+        ("record", 92) => Some("depth"),
+        ("record", 93) => Some("next_stop_depth"),
+        ("record", 94) => Some("next_stop_time"),
+        ("record", 95) => Some("time_to_surface"),
+        ("record", 96) => Some("ndl_time"),
+        ("record", 97) => Some("cns_load"),
+        ("record", 98) => Some("n2_load"),
+        ("record", 99) => Some("respiration_rate"),
+        ("record", 108) => Some("enhanced_respiration_rate"),
+        ("record", 114) => Some("grit"),
+        ("record", 115) => Some("flow"),
+        ("record", 116) => Some("current_stress"),
+        ("record", 117) => Some("ebike_travel_range"),
+        ("record", 118) => Some("ebike_battery_level"),
+        ("record", 119) => Some("ebike_assist_mode"),
+        ("record", 120) => Some("ebike_assist_level_percent"),
+        ("record", 123) => Some("air_time_remaining"),
+        ("record", 124) => Some("pressure_sac"),
+        ("record", 125) => Some("volume_sac"),
+        ("record", 126) => Some("rmv"),
+        ("record", 127) => Some("ascent_rate"),
+        ("record", 129) => Some("po2"),
+        ("record", 139) => Some("core_temperature"),
+        ("event", 253) => Some("timestamp"),
+        ("event", 0) => Some("event"),
+        ("event", 1) => Some("event_type"),
+        ("event", 2) => Some("data16"),
+        ("event", 3) => Some("data"),
+        ("event", 4) => Some("event_group"),
+        ("event", 7) => Some("score"),
+        ("event", 8) => Some("opponent_score"),
+        ("event", 9) => Some("front_gear_num"),
+        ("event", 10) => Some("front_gear"),
+        ("event", 11) => Some("rear_gear_num"),
+        ("event", 12) => Some("rear_gear"),
+        ("event", 13) => Some("device_index"),
+        ("event", 14) => Some("activity_type"),
+        ("event", 15) => Some("start_timestamp"),
+        ("event", 21) => Some("radar_threat_level_max"),
+        ("event", 22) => Some("radar_threat_count"),
+        ("event", 23) => Some("radar_threat_avg_approach_speed"),
+        ("event", 24) => Some("radar_threat_max_approach_speed"),
+        ("device_info", 253) => Some("timestamp"),
+        ("device_info", 0) => Some("device_index"),
+        ("device_info", 1) => Some("device_type"),
+        ("device_info", 2) => Some("manufacturer"),
+        ("device_info", 3) => Some("serial_number"),
+        ("device_info", 4) => Some("product"),
+        ("device_info", 5) => Some("software_version"),
+        ("device_info", 6) => Some("hardware_version"),
+        ("device_info", 7) => Some("cum_operating_time"),
+        ("device_info", 10) => Some("battery_voltage"),
+        ("device_info", 11) => Some("battery_status"),
+        ("device_info", 18) => Some("sensor_position"),
+        ("device_info", 19) => Some("descriptor"),
+        ("device_info", 20) => Some("ant_transmission_type"),
+        ("device_info", 21) => Some("ant_device_number"),
+        ("device_info", 22) => Some("ant_network"),
+        ("device_info", 25) => Some("source_type"),
+        ("device_info", 27) => Some("product_name"),
+        ("device_info", 32) => Some("battery_level"),
+        ("device_aux_battery_info", 253) => Some("timestamp"),
+        ("device_aux_battery_info", 0) => Some("device_index"),
+        ("device_aux_battery_info", 1) => Some("battery_voltage"),
+        ("device_aux_battery_info", 2) => Some("battery_status"),
+        ("device_aux_battery_info", 3) => Some("battery_identifier"),
+        ("training_file", 253) => Some("timestamp"),
+        ("training_file", 0) => Some("type"),
+        ("training_file", 1) => Some("manufacturer"),
+        ("training_file", 2) => Some("product"),
+        ("training_file", 3) => Some("serial_number"),
+        ("training_file", 4) => Some("time_created"),
+        ("weather_conditions", 253) => Some("timestamp"),
+        ("weather_conditions", 0) => Some("weather_report"),
+        ("weather_conditions", 1) => Some("temperature"),
+        ("weather_conditions", 2) => Some("condition"),
+        ("weather_conditions", 3) => Some("wind_direction"),
+        ("weather_conditions", 4) => Some("wind_speed"),
+        ("weather_conditions", 5) => Some("precipitation_probability"),
+        ("weather_conditions", 6) => Some("temperature_feels_like"),
+        ("weather_conditions", 7) => Some("relative_humidity"),
+        ("weather_conditions", 8) => Some("location"),
+        ("weather_conditions", 9) => Some("observed_at_time"),
+        ("weather_conditions", 10) => Some("observed_location_lat"),
+        ("weather_conditions", 11) => Some("observed_location_long"),
+        ("weather_conditions", 12) => Some("day_of_week"),
+        ("weather_conditions", 13) => Some("high_temperature"),
+        ("weather_conditions", 14) => Some("low_temperature"),
+        ("weather_alert", 253) => Some("timestamp"),
+        ("weather_alert", 0) => Some("report_id"),
+        ("weather_alert", 1) => Some("issue_time"),
+        ("weather_alert", 2) => Some("expire_time"),
+        ("weather_alert", 3) => Some("severity"),
+        ("weather_alert", 4) => Some("type"),
+        ("gps_metadata", 253) => Some("timestamp"),
+        ("gps_metadata", 0) => Some("timestamp_ms"),
+        ("gps_metadata", 1) => Some("position_lat"),
+        ("gps_metadata", 2) => Some("position_long"),
+        ("gps_metadata", 3) => Some("enhanced_altitude"),
+        ("gps_metadata", 4) => Some("enhanced_speed"),
+        ("gps_metadata", 5) => Some("heading"),
+        ("gps_metadata", 6) => Some("utc_timestamp"),
+        ("gps_metadata", 7) => Some("velocity"),
+        ("camera_event", 253) => Some("timestamp"),
+        ("camera_event", 0) => Some("timestamp_ms"),
+        ("camera_event", 1) => Some("camera_event_type"),
+        ("camera_event", 2) => Some("camera_file_uuid"),
+        ("camera_event", 3) => Some("camera_orientation"),
+        ("gyroscope_data", 253) => Some("timestamp"),
+        ("gyroscope_data", 0) => Some("timestamp_ms"),
+        ("gyroscope_data", 1) => Some("sample_time_offset"),
+        ("gyroscope_data", 2) => Some("gyro_x"),
+        ("gyroscope_data", 3) => Some("gyro_y"),
+        ("gyroscope_data", 4) => Some("gyro_z"),
+        ("gyroscope_data", 5) => Some("calibrated_gyro_x"),
+        ("gyroscope_data", 6) => Some("calibrated_gyro_y"),
+        ("gyroscope_data", 7) => Some("calibrated_gyro_z"),
+        ("accelerometer_data", 253) => Some("timestamp"),
+        ("accelerometer_data", 0) => Some("timestamp_ms"),
+        ("accelerometer_data", 1) => Some("sample_time_offset"),
+        ("accelerometer_data", 2) => Some("accel_x"),
+        ("accelerometer_data", 3) => Some("accel_y"),
+        ("accelerometer_data", 4) => Some("accel_z"),
+        ("accelerometer_data", 5) => Some("calibrated_accel_x"),
+        ("accelerometer_data", 6) => Some("calibrated_accel_y"),
+        ("accelerometer_data", 7) => Some("calibrated_accel_z"),
+        ("accelerometer_data", 8) => Some("compressed_calibrated_accel_x"),
+        ("accelerometer_data", 9) => Some("compressed_calibrated_accel_y"),
+        ("accelerometer_data", 10) => Some("compressed_calibrated_accel_z"),
+        ("magnetometer_data", 253) => Some("timestamp"),
+        ("magnetometer_data", 0) => Some("timestamp_ms"),
+        ("magnetometer_data", 1) => Some("sample_time_offset"),
+        ("magnetometer_data", 2) => Some("mag_x"),
+        ("magnetometer_data", 3) => Some("mag_y"),
+        ("magnetometer_data", 4) => Some("mag_z"),
+        ("magnetometer_data", 5) => Some("calibrated_mag_x"),
+        ("magnetometer_data", 6) => Some("calibrated_mag_y"),
+        ("magnetometer_data", 7) => Some("calibrated_mag_z"),
+        ("barometer_data", 253) => Some("timestamp"),
+        ("barometer_data", 0) => Some("timestamp_ms"),
+        ("barometer_data", 1) => Some("sample_time_offset"),
+        ("barometer_data", 2) => Some("baro_pres"),
+        ("three_d_sensor_calibration", 253) => Some("timestamp"),
+        ("three_d_sensor_calibration", 0) => Some("sensor_type"),
+        ("three_d_sensor_calibration", 1) => Some("calibration_factor"),
+        ("three_d_sensor_calibration", 2) => Some("calibration_divisor"),
+        ("three_d_sensor_calibration", 3) => Some("level_shift"),
+        ("three_d_sensor_calibration", 4) => Some("offset_cal"),
+        ("three_d_sensor_calibration", 5) => Some("orientation_matrix"),
+        ("one_d_sensor_calibration", 253) => Some("timestamp"),
+        ("one_d_sensor_calibration", 0) => Some("sensor_type"),
+        ("one_d_sensor_calibration", 1) => Some("calibration_factor"),
+        ("one_d_sensor_calibration", 2) => Some("calibration_divisor"),
+        ("one_d_sensor_calibration", 3) => Some("level_shift"),
+        ("one_d_sensor_calibration", 4) => Some("offset_cal"),
+        ("video_frame", 253) => Some("timestamp"),
+        ("video_frame", 0) => Some("timestamp_ms"),
+        ("video_frame", 1) => Some("frame_number"),
+        ("obdii_data", 253) => Some("timestamp"),
+        ("obdii_data", 0) => Some("timestamp_ms"),
+        ("obdii_data", 1) => Some("time_offset"),
+        ("obdii_data", 2) => Some("pid"),
+        ("obdii_data", 3) => Some("raw_data"),
+        ("obdii_data", 4) => Some("pid_data_size"),
+        ("obdii_data", 5) => Some("system_time"),
+        ("obdii_data", 6) => Some("start_timestamp"),
+        ("obdii_data", 7) => Some("start_timestamp_ms"),
+        ("nmea_sentence", 253) => Some("timestamp"),
+        ("nmea_sentence", 0) => Some("timestamp_ms"),
+        ("nmea_sentence", 1) => Some("sentence"),
+        ("aviation_attitude", 253) => Some("timestamp"),
+        ("aviation_attitude", 0) => Some("timestamp_ms"),
+        ("aviation_attitude", 1) => Some("system_time"),
+        ("aviation_attitude", 2) => Some("pitch"),
+        ("aviation_attitude", 3) => Some("roll"),
+        ("aviation_attitude", 4) => Some("accel_lateral"),
+        ("aviation_attitude", 5) => Some("accel_normal"),
+        ("aviation_attitude", 6) => Some("turn_rate"),
+        ("aviation_attitude", 7) => Some("stage"),
+        ("aviation_attitude", 8) => Some("attitude_stage_complete"),
+        ("aviation_attitude", 9) => Some("track"),
+        ("aviation_attitude", 10) => Some("validity"),
+        ("video", 0) => Some("url"),
+        ("video", 1) => Some("hosting_provider"),
+        ("video", 2) => Some("duration"),
+        ("video_title", 254) => Some("message_index"),
+        ("video_title", 0) => Some("message_count"),
+        ("video_title", 1) => Some("text"),
+        ("video_description", 254) => Some("message_index"),
+        ("video_description", 0) => Some("message_count"),
+        ("video_description", 1) => Some("text"),
+        ("video_clip", 0) => Some("clip_number"),
+        ("video_clip", 1) => Some("start_timestamp"),
+        ("video_clip", 2) => Some("start_timestamp_ms"),
+        ("video_clip", 3) => Some("end_timestamp"),
+        ("video_clip", 4) => Some("end_timestamp_ms"),
+        ("video_clip", 6) => Some("clip_start"),
+        ("video_clip", 7) => Some("clip_end"),
+        ("set", 254) => Some("timestamp"),
+        ("set", 0) => Some("duration"),
+        ("set", 3) => Some("repetitions"),
+        ("set", 4) => Some("weight"),
+        ("set", 5) => Some("set_type"),
+        ("set", 6) => Some("start_time"),
+        ("set", 7) => Some("category"),
+        ("set", 8) => Some("category_subtype"),
+        ("set", 9) => Some("weight_display_unit"),
+        ("set", 10) => Some("message_index"),
+        ("set", 11) => Some("wkt_step_index"),
+        ("jump", 253) => Some("timestamp"),
+        ("jump", 0) => Some("distance"),
+        ("jump", 1) => Some("height"),
+        ("jump", 2) => Some("rotations"),
+        ("jump", 3) => Some("hang_time"),
+        ("jump", 4) => Some("score"),
+        ("jump", 5) => Some("position_lat"),
+        ("jump", 6) => Some("position_long"),
+        ("jump", 7) => Some("speed"),
+        ("jump", 8) => Some("enhanced_speed"),
+        ("split", 254) => Some("message_index"),
+        ("split", 0) => Some("split_type"),
+        ("split", 1) => Some("total_elapsed_time"),
+        ("split", 2) => Some("total_timer_time"),
+        ("split", 3) => Some("total_distance"),
+        ("split", 4) => Some("avg_speed"),
+        ("split", 9) => Some("start_time"),
+        ("split", 13) => Some("total_ascent"),
+        ("split", 14) => Some("total_descent"),
+        ("split", 21) => Some("start_position_lat"),
+        ("split", 22) => Some("start_position_long"),
+        ("split", 23) => Some("end_position_lat"),
+        ("split", 24) => Some("end_position_long"),
+        ("split", 25) => Some("max_speed"),
+        ("split", 26) => Some("avg_vert_speed"),
+        ("split", 27) => Some("end_time"),
+        ("split", 28) => Some("total_calories"),
+        ("split", 74) => Some("start_elevation"),
+        ("split", 110) => Some("total_moving_time"),
+        ("split_summary", 254) => Some("message_index"),
+        ("split_summary", 0) => Some("split_type"),
+        ("split_summary", 3) => Some("num_splits"),
+        ("split_summary", 4) => Some("total_timer_time"),
+        ("split_summary", 5) => Some("total_distance"),
+        ("split_summary", 6) => Some("avg_speed"),
+        ("split_summary", 7) => Some("max_speed"),
+        ("split_summary", 8) => Some("total_ascent"),
+        ("split_summary", 9) => Some("total_descent"),
+        ("split_summary", 10) => Some("avg_heart_rate"),
+        ("split_summary", 11) => Some("max_heart_rate"),
+        ("split_summary", 12) => Some("avg_vert_speed"),
+        ("split_summary", 13) => Some("total_calories"),
+        ("split_summary", 77) => Some("total_moving_time"),
+        ("climb_pro", 253) => Some("timestamp"),
+        ("climb_pro", 0) => Some("position_lat"),
+        ("climb_pro", 1) => Some("position_long"),
+        ("climb_pro", 2) => Some("climb_pro_event"),
+        ("climb_pro", 3) => Some("climb_number"),
+        ("climb_pro", 4) => Some("climb_category"),
+        ("climb_pro", 5) => Some("current_dist"),
+        ("field_description", 0) => Some("developer_data_index"),
+        ("field_description", 1) => Some("field_definition_number"),
+        ("field_description", 2) => Some("fit_base_type_id"),
+        ("field_description", 3) => Some("field_name"),
+        ("field_description", 4) => Some("array"),
+        ("field_description", 5) => Some("components"),
+        ("field_description", 6) => Some("scale"),
+        ("field_description", 7) => Some("offset"),
+        ("field_description", 8) => Some("units"),
+        ("field_description", 9) => Some("bits"),
+        ("field_description", 10) => Some("accumulate"),
+        ("field_description", 13) => Some("fit_base_unit_id"),
+        ("field_description", 14) => Some("native_mesg_num"),
+        ("field_description", 15) => Some("native_field_num"),
+        ("developer_data_id", 0) => Some("developer_id"),
+        ("developer_data_id", 1) => Some("application_id"),
+        ("developer_data_id", 2) => Some("manufacturer_id"),
+        ("developer_data_id", 3) => Some("developer_data_index"),
+        ("developer_data_id", 4) => Some("application_version"),
+        ("course", 4) => Some("sport"),
+        ("course", 5) => Some("name"),
+        ("course", 6) => Some("capabilities"),
+        ("course", 7) => Some("sub_sport"),
+        ("course_point", 254) => Some("message_index"),
+        ("course_point", 1) => Some("timestamp"),
+        ("course_point", 2) => Some("position_lat"),
+        ("course_point", 3) => Some("position_long"),
+        ("course_point", 4) => Some("distance"),
+        ("course_point", 5) => Some("type"),
+        ("course_point", 6) => Some("name"),
+        ("course_point", 8) => Some("favorite"),
+        ("segment_id", 0) => Some("name"),
+        ("segment_id", 1) => Some("uuid"),
+        ("segment_id", 2) => Some("sport"),
+        ("segment_id", 3) => Some("enabled"),
+        ("segment_id", 4) => Some("user_profile_primary_key"),
+        ("segment_id", 5) => Some("device_id"),
+        ("segment_id", 6) => Some("default_race_leader"),
+        ("segment_id", 7) => Some("delete_status"),
+        ("segment_id", 8) => Some("selection_type"),
+        ("segment_leaderboard_entry", 254) => Some("message_index"),
+        ("segment_leaderboard_entry", 0) => Some("name"),
+        ("segment_leaderboard_entry", 1) => Some("type"),
+        ("segment_leaderboard_entry", 2) => Some("group_primary_key"),
+        ("segment_leaderboard_entry", 3) => Some("activity_id"),
+        ("segment_leaderboard_entry", 4) => Some("segment_time"),
+        ("segment_leaderboard_entry", 5) => Some("activity_id_string"),
+        ("segment_point", 254) => Some("message_index"),
+        ("segment_point", 1) => Some("position_lat"),
+        ("segment_point", 2) => Some("position_long"),
+        ("segment_point", 3) => Some("distance"),
+        ("segment_point", 4) => Some("altitude"),
+        ("segment_point", 5) => Some("leader_time"),
+        ("segment_point", 6) => Some("enhanced_altitude"),
+        ("segment_lap", 254) => Some("message_index"),
+        ("segment_lap", 253) => Some("timestamp"),
+        ("segment_lap", 0) => Some("event"),
+        ("segment_lap", 1) => Some("event_type"),
+        ("segment_lap", 2) => Some("start_time"),
+        ("segment_lap", 3) => Some("start_position_lat"),
+        ("segment_lap", 4) => Some("start_position_long"),
+        ("segment_lap", 5) => Some("end_position_lat"),
+        ("segment_lap", 6) => Some("end_position_long"),
+        ("segment_lap", 7) => Some("total_elapsed_time"),
+        ("segment_lap", 8) => Some("total_timer_time"),
+        ("segment_lap", 9) => Some("total_distance"),
+        ("segment_lap", 10) => Some("total_cycles"),
+        ("segment_lap", 11) => Some("total_calories"),
+        ("segment_lap", 12) => Some("total_fat_calories"),
+        ("segment_lap", 13) => Some("avg_speed"),
+        ("segment_lap", 14) => Some("max_speed"),
+        ("segment_lap", 15) => Some("avg_heart_rate"),
+        ("segment_lap", 16) => Some("max_heart_rate"),
+        ("segment_lap", 17) => Some("avg_cadence"),
+        ("segment_lap", 18) => Some("max_cadence"),
+        ("segment_lap", 19) => Some("avg_power"),
+        ("segment_lap", 20) => Some("max_power"),
+        ("segment_lap", 21) => Some("total_ascent"),
+        ("segment_lap", 22) => Some("total_descent"),
+        ("segment_lap", 23) => Some("sport"),
+        ("segment_lap", 24) => Some("event_group"),
+        ("segment_lap", 25) => Some("nec_lat"),
+        ("segment_lap", 26) => Some("nec_long"),
+        ("segment_lap", 27) => Some("swc_lat"),
+        ("segment_lap", 28) => Some("swc_long"),
+        ("segment_lap", 29) => Some("name"),
+        ("segment_lap", 30) => Some("normalized_power"),
+        ("segment_lap", 31) => Some("left_right_balance"),
+        ("segment_lap", 32) => Some("sub_sport"),
+        ("segment_lap", 33) => Some("total_work"),
+        ("segment_lap", 34) => Some("avg_altitude"),
+        ("segment_lap", 35) => Some("max_altitude"),
+        ("segment_lap", 36) => Some("gps_accuracy"),
+        ("segment_lap", 37) => Some("avg_grade"),
+        ("segment_lap", 38) => Some("avg_pos_grade"),
+        ("segment_lap", 39) => Some("avg_neg_grade"),
+        ("segment_lap", 40) => Some("max_pos_grade"),
+        ("segment_lap", 41) => Some("max_neg_grade"),
+        ("segment_lap", 42) => Some("avg_temperature"),
+        ("segment_lap", 43) => Some("max_temperature"),
+        ("segment_lap", 44) => Some("total_moving_time"),
+        ("segment_lap", 45) => Some("avg_pos_vertical_speed"),
+        ("segment_lap", 46) => Some("avg_neg_vertical_speed"),
+        ("segment_lap", 47) => Some("max_pos_vertical_speed"),
+        ("segment_lap", 48) => Some("max_neg_vertical_speed"),
+        ("segment_lap", 49) => Some("time_in_hr_zone"),
+        ("segment_lap", 50) => Some("time_in_speed_zone"),
+        ("segment_lap", 51) => Some("time_in_cadence_zone"),
+        ("segment_lap", 52) => Some("time_in_power_zone"),
+        ("segment_lap", 53) => Some("repetition_num"),
+        ("segment_lap", 54) => Some("min_altitude"),
+        ("segment_lap", 55) => Some("min_heart_rate"),
+        ("segment_lap", 56) => Some("active_time"),
+        ("segment_lap", 57) => Some("wkt_step_index"),
+        ("segment_lap", 58) => Some("sport_event"),
+        ("segment_lap", 59) => Some("avg_left_torque_effectiveness"),
+        ("segment_lap", 60) => Some("avg_right_torque_effectiveness"),
+        ("segment_lap", 61) => Some("avg_left_pedal_smoothness"),
+        ("segment_lap", 62) => Some("avg_right_pedal_smoothness"),
+        ("segment_lap", 63) => Some("avg_combined_pedal_smoothness"),
+        ("segment_lap", 64) => Some("status"),
+        ("segment_lap", 65) => Some("uuid"),
+        ("segment_lap", 66) => Some("avg_fractional_cadence"),
+        ("segment_lap", 67) => Some("max_fractional_cadence"),
+        ("segment_lap", 68) => Some("total_fractional_cycles"),
+        ("segment_lap", 69) => Some("front_gear_shift_count"),
+        ("segment_lap", 70) => Some("rear_gear_shift_count"),
+        ("segment_lap", 71) => Some("time_standing"),
+        ("segment_lap", 72) => Some("stand_count"),
+        ("segment_lap", 73) => Some("avg_left_pco"),
+        ("segment_lap", 74) => Some("avg_right_pco"),
+        ("segment_lap", 75) => Some("avg_left_power_phase"),
+        ("segment_lap", 76) => Some("avg_left_power_phase_peak"),
+        ("segment_lap", 77) => Some("avg_right_power_phase"),
+        ("segment_lap", 78) => Some("avg_right_power_phase_peak"),
+        ("segment_lap", 79) => Some("avg_power_position"),
+        ("segment_lap", 80) => Some("max_power_position"),
+        ("segment_lap", 81) => Some("avg_cadence_position"),
+        ("segment_lap", 82) => Some("max_cadence_position"),
+        ("segment_lap", 83) => Some("manufacturer"),
+        ("segment_lap", 84) => Some("total_grit"),
+        ("segment_lap", 85) => Some("total_flow"),
+        ("segment_lap", 86) => Some("avg_grit"),
+        ("segment_lap", 87) => Some("avg_flow"),
+        ("segment_lap", 89) => Some("total_fractional_ascent"),
+        ("segment_lap", 90) => Some("total_fractional_descent"),
+        ("segment_lap", 91) => Some("enhanced_avg_altitude"),
+        ("segment_lap", 92) => Some("enhanced_max_altitude"),
+        ("segment_lap", 93) => Some("enhanced_min_altitude"),
+        ("segment_file", 254) => Some("message_index"),
+        ("segment_file", 1) => Some("file_uuid"),
+        ("segment_file", 3) => Some("enabled"),
+        ("segment_file", 4) => Some("user_profile_primary_key"),
+        ("segment_file", 7) => Some("leader_type"),
+        ("segment_file", 8) => Some("leader_group_primary_key"),
+        ("segment_file", 9) => Some("leader_activity_id"),
+        ("segment_file", 10) => Some("leader_activity_id_string"),
+        ("segment_file", 11) => Some("default_race_leader"),
+        ("workout", 254) => Some("message_index"),
+        ("workout", 4) => Some("sport"),
+        ("workout", 5) => Some("capabilities"),
+        ("workout", 6) => Some("num_valid_steps"),
+        ("workout", 8) => Some("wkt_name"),
+        ("workout", 11) => Some("sub_sport"),
+        ("workout", 14) => Some("pool_length"),
+        ("workout", 15) => Some("pool_length_unit"),
+        ("workout_session", 254) => Some("message_index"),
+        ("workout_session", 0) => Some("sport"),
+        ("workout_session", 1) => Some("sub_sport"),
+        ("workout_session", 2) => Some("num_valid_steps"),
+        ("workout_session", 3) => Some("first_step_index"),
+        ("workout_session", 4) => Some("pool_length"),
+        ("workout_session", 5) => Some("pool_length_unit"),
+        ("workout_step", 254) => Some("message_index"),
+        ("workout_step", 0) => Some("wkt_step_name"),
+        ("workout_step", 1) => Some("duration_type"),
+        ("workout_step", 2) => Some("duration_value"),
+        ("workout_step", 3) => Some("target_type"),
+        ("workout_step", 4) => Some("target_value"),
+        ("workout_step", 5) => Some("custom_target_value_low"),
+        ("workout_step", 6) => Some("custom_target_value_high"),
+        ("workout_step", 7) => Some("intensity"),
+        ("workout_step", 8) => Some("notes"),
+        ("workout_step", 9) => Some("equipment"),
+        ("workout_step", 10) => Some("exercise_category"),
+        ("workout_step", 11) => Some("exercise_name"),
+        ("workout_step", 12) => Some("exercise_weight"),
+        ("workout_step", 13) => Some("weight_display_unit"),
+        ("workout_step", 19) => Some("secondary_target_type"),
+        ("workout_step", 20) => Some("secondary_target_value"),
+        ("workout_step", 21) => Some("secondary_custom_target_value_low"),
+        ("workout_step", 22) => Some("secondary_custom_target_value_high"),
+        ("exercise_title", 254) => Some("message_index"),
+        ("exercise_title", 0) => Some("exercise_category"),
+        ("exercise_title", 1) => Some("exercise_name"),
+        ("exercise_title", 2) => Some("wkt_step_name"),
+        ("schedule", 0) => Some("manufacturer"),
+        ("schedule", 1) => Some("product"),
+        ("schedule", 2) => Some("serial_number"),
+        ("schedule", 3) => Some("time_created"),
+        ("schedule", 4) => Some("completed"),
+        ("schedule", 5) => Some("type"),
+        ("schedule", 6) => Some("scheduled_time"),
+        ("totals", 254) => Some("message_index"),
+        ("totals", 253) => Some("timestamp"),
+        ("totals", 0) => Some("timer_time"),
+        ("totals", 1) => Some("distance"),
+        ("totals", 2) => Some("calories"),
+        ("totals", 3) => Some("sport"),
+        ("totals", 4) => Some("elapsed_time"),
+        ("totals", 5) => Some("sessions"),
+        ("totals", 6) => Some("active_time"),
+        ("totals", 9) => Some("sport_index"),
+        ("weight_scale", 253) => Some("timestamp"),
+        ("weight_scale", 0) => Some("weight"),
+        ("weight_scale", 1) => Some("percent_fat"),
+        ("weight_scale", 2) => Some("percent_hydration"),
+        ("weight_scale", 3) => Some("visceral_fat_mass"),
+        ("weight_scale", 4) => Some("bone_mass"),
+        ("weight_scale", 5) => Some("muscle_mass"),
+        ("weight_scale", 7) => Some("basal_met"),
+        ("weight_scale", 8) => Some("physique_rating"),
+        ("weight_scale", 9) => Some("active_met"),
+        ("weight_scale", 10) => Some("metabolic_age"),
+        ("weight_scale", 11) => Some("visceral_fat_rating"),
+        ("weight_scale", 12) => Some("user_profile_index"),
+        ("weight_scale", 13) => Some("bmi"),
+        ("blood_pressure", 253) => Some("timestamp"),
+        ("blood_pressure", 0) => Some("systolic_pressure"),
+        ("blood_pressure", 1) => Some("diastolic_pressure"),
+        ("blood_pressure", 2) => Some("mean_arterial_pressure"),
+        ("blood_pressure", 3) => Some("map_3_sample_mean"),
+        ("blood_pressure", 4) => Some("map_morning_values"),
+        ("blood_pressure", 5) => Some("map_evening_values"),
+        ("blood_pressure", 6) => Some("heart_rate"),
+        ("blood_pressure", 7) => Some("heart_rate_type"),
+        ("blood_pressure", 8) => Some("status"),
+        ("blood_pressure", 9) => Some("user_profile_index"),
+        ("monitoring_info", 253) => Some("timestamp"),
+        ("monitoring_info", 0) => Some("local_timestamp"),
+        ("monitoring_info", 1) => Some("activity_type"),
+        ("monitoring_info", 3) => Some("cycles_to_distance"),
+        ("monitoring_info", 4) => Some("cycles_to_calories"),
+        ("monitoring_info", 5) => Some("resting_metabolic_rate"),
+        ("monitoring", 253) => Some("timestamp"),
+        ("monitoring", 0) => Some("device_index"),
+        ("monitoring", 1) => Some("calories"),
+        ("monitoring", 2) => Some("distance"),
+        ("monitoring", 3) => Some("cycles"),
+        ("monitoring", 4) => Some("active_time"),
+        ("monitoring", 5) => Some("activity_type"),
+        ("monitoring", 6) => Some("activity_subtype"),
+        ("monitoring", 7) => Some("activity_level"),
+        ("monitoring", 8) => Some("distance_16"),
+        ("monitoring", 9) => Some("cycles_16"),
+        ("monitoring", 10) => Some("active_time_16"),
+        ("monitoring", 11) => Some("local_timestamp"),
+        ("monitoring", 12) => Some("temperature"),
+        ("monitoring", 14) => Some("temperature_min"),
+        ("monitoring", 15) => Some("temperature_max"),
+        ("monitoring", 16) => Some("activity_time"),
+        ("monitoring", 19) => Some("active_calories"),
+        ("monitoring", 24) => Some("current_activity_type_intensity"),
+        ("monitoring", 25) => Some("timestamp_min_8"),
+        ("monitoring", 26) => Some("timestamp_16"),
+        ("monitoring", 27) => Some("heart_rate"),
+        ("monitoring", 28) => Some("intensity"),
+        ("monitoring", 29) => Some("duration_min"),
+        ("monitoring", 30) => Some("duration"),
+        ("monitoring", 31) => Some("ascent"),
+        ("monitoring", 32) => Some("descent"),
+        ("monitoring", 33) => Some("moderate_activity_minutes"),
+        ("monitoring", 34) => Some("vigorous_activity_minutes"),
+        ("monitoring_hr_data", 253) => Some("timestamp"),
+        ("monitoring_hr_data", 0) => Some("resting_heart_rate"),
+        ("monitoring_hr_data", 1) => Some("current_day_resting_heart_rate"),
+        ("spo2_data", 253) => Some("timestamp"),
+        ("spo2_data", 0) => Some("reading_spo2"),
+        ("spo2_data", 1) => Some("reading_confidence"),
+        ("spo2_data", 2) => Some("mode"),
+        ("hr", 253) => Some("timestamp"),
+        ("hr", 0) => Some("fractional_timestamp"),
+        ("hr", 1) => Some("time256"),
+        ("hr", 6) => Some("filtered_bpm"),
+        ("hr", 9) => Some("event_timestamp"),
+        ("hr", 10) => Some("event_timestamp_12"),
+        ("stress_level", 0) => Some("stress_level_value"),
+        ("stress_level", 1) => Some("stress_level_time"),
+        ("max_met_data", 0) => Some("update_time"),
+        ("max_met_data", 2) => Some("vo2_max"),
+        ("max_met_data", 5) => Some("sport"),
+        ("max_met_data", 6) => Some("sub_sport"),
+        ("max_met_data", 8) => Some("max_met_category"),
+        ("max_met_data", 9) => Some("calibrated_data"),
+        ("max_met_data", 12) => Some("hr_source"),
+        ("max_met_data", 13) => Some("speed_source"),
+        ("memo_glob", 250) => Some("part_index"),
+        ("memo_glob", 0) => Some("memo"),
+        ("memo_glob", 1) => Some("mesg_num"),
+        ("memo_glob", 2) => Some("parent_index"),
+        ("memo_glob", 3) => Some("field_num"),
+        ("memo_glob", 4) => Some("data"),
+        ("sleep_level", 253) => Some("timestamp"),
+        ("sleep_level", 0) => Some("sleep_level"),
+        ("ant_channel_id", 0) => Some("channel_number"),
+        ("ant_channel_id", 1) => Some("device_type"),
+        ("ant_channel_id", 2) => Some("device_number"),
+        ("ant_channel_id", 3) => Some("transmission_type"),
+        ("ant_channel_id", 4) => Some("device_index"),
+        ("ant_rx", 253) => Some("timestamp"),
+        ("ant_rx", 0) => Some("fractional_timestamp"),
+        ("ant_rx", 1) => Some("mesg_id"),
+        ("ant_rx", 2) => Some("mesg_data"),
+        ("ant_rx", 3) => Some("channel_number"),
+        ("ant_rx", 4) => Some("data"),
+        ("ant_tx", 253) => Some("timestamp"),
+        ("ant_tx", 0) => Some("fractional_timestamp"),
+        ("ant_tx", 1) => Some("mesg_id"),
+        ("ant_tx", 2) => Some("mesg_data"),
+        ("ant_tx", 3) => Some("channel_number"),
+        ("ant_tx", 4) => Some("data"),
+        ("exd_screen_configuration", 0) => Some("screen_index"),
+        ("exd_screen_configuration", 1) => Some("field_count"),
+        ("exd_screen_configuration", 2) => Some("layout"),
+        ("exd_screen_configuration", 3) => Some("screen_enabled"),
+        ("exd_data_field_configuration", 0) => Some("screen_index"),
+        ("exd_data_field_configuration", 1) => Some("concept_field"),
+        ("exd_data_field_configuration", 2) => Some("field_id"),
+        ("exd_data_field_configuration", 3) => Some("concept_count"),
+        ("exd_data_field_configuration", 4) => Some("display_type"),
+        ("exd_data_field_configuration", 5) => Some("title"),
+        ("exd_data_concept_configuration", 0) => Some("screen_index"),
+        ("exd_data_concept_configuration", 1) => Some("concept_field"),
+        ("exd_data_concept_configuration", 2) => Some("field_id"),
+        ("exd_data_concept_configuration", 3) => Some("concept_index"),
+        ("exd_data_concept_configuration", 4) => Some("data_page"),
+        ("exd_data_concept_configuration", 5) => Some("concept_key"),
+        ("exd_data_concept_configuration", 6) => Some("scaling"),
+        ("exd_data_concept_configuration", 8) => Some("data_units"),
+        ("exd_data_concept_configuration", 9) => Some("qualifier"),
+        ("exd_data_concept_configuration", 10) => Some("descriptor"),
+        ("exd_data_concept_configuration", 11) => Some("is_signed"),
+        ("dive_summary", 253) => Some("timestamp"),
+        ("dive_summary", 0) => Some("reference_mesg"),
+        ("dive_summary", 1) => Some("reference_index"),
+        ("dive_summary", 2) => Some("avg_depth"),
+        ("dive_summary", 3) => Some("max_depth"),
+        ("dive_summary", 4) => Some("surface_interval"),
+        ("dive_summary", 5) => Some("start_cns"),
+        ("dive_summary", 6) => Some("end_cns"),
+        ("dive_summary", 7) => Some("start_n2"),
+        ("dive_summary", 8) => Some("end_n2"),
+        ("dive_summary", 9) => Some("o2_toxicity"),
+        ("dive_summary", 10) => Some("dive_number"),
+        ("dive_summary", 11) => Some("bottom_time"),
+        ("dive_summary", 12) => Some("avg_pressure_sac"),
+        ("dive_summary", 13) => Some("avg_volume_sac"),
+        ("dive_summary", 14) => Some("avg_rmv"),
+        ("dive_summary", 15) => Some("descent_time"),
+        ("dive_summary", 16) => Some("ascent_time"),
+        ("dive_summary", 17) => Some("avg_ascent_rate"),
+        ("dive_summary", 22) => Some("avg_descent_rate"),
+        ("dive_summary", 23) => Some("max_ascent_rate"),
+        ("dive_summary", 24) => Some("max_descent_rate"),
+        ("dive_summary", 25) => Some("hang_time"),
+        ("hrv", 0) => Some("time"),
+        ("beat_intervals", 253) => Some("timestamp"),
+        ("beat_intervals", 0) => Some("timestamp_ms"),
+        ("beat_intervals", 1) => Some("time"),
+        ("hrv_status_summary", 253) => Some("timestamp"),
+        ("hrv_status_summary", 0) => Some("weekly_average"),
+        ("hrv_status_summary", 1) => Some("last_night_average"),
+        ("hrv_status_summary", 2) => Some("last_night_5_min_high"),
+        ("hrv_status_summary", 3) => Some("baseline_low_upper"),
+        ("hrv_status_summary", 4) => Some("baseline_balanced_lower"),
+        ("hrv_status_summary", 5) => Some("baseline_balanced_upper"),
+        ("hrv_status_summary", 6) => Some("status"),
+        ("hrv_value", 253) => Some("timestamp"),
+        ("hrv_value", 0) => Some("value"),
+        ("respiration_rate", 253) => Some("timestamp"),
+        ("respiration_rate", 0) => Some("respiration_rate"),
+        ("tank_update", 253) => Some("timestamp"),
+        ("tank_update", 0) => Some("sensor"),
+        ("tank_update", 1) => Some("pressure"),
+        ("tank_summary", 253) => Some("timestamp"),
+        ("tank_summary", 0) => Some("sensor"),
+        ("tank_summary", 1) => Some("start_pressure"),
+        ("tank_summary", 2) => Some("end_pressure"),
+        ("tank_summary", 3) => Some("volume_used"),
+        ("sleep_assessment", 0) => Some("combined_awake_score"),
+        ("sleep_assessment", 1) => Some("awake_time_score"),
+        ("sleep_assessment", 2) => Some("awakenings_count_score"),
+        ("sleep_assessment", 3) => Some("deep_sleep_score"),
+        ("sleep_assessment", 4) => Some("sleep_duration_score"),
+        ("sleep_assessment", 5) => Some("light_sleep_score"),
+        ("sleep_assessment", 6) => Some("overall_sleep_score"),
+        ("sleep_assessment", 7) => Some("sleep_quality_score"),
+        ("sleep_assessment", 8) => Some("sleep_recovery_score"),
+        ("sleep_assessment", 9) => Some("rem_sleep_score"),
+        ("sleep_assessment", 10) => Some("sleep_restlessness_score"),
+        ("sleep_assessment", 11) => Some("awakenings_count"),
+        ("sleep_assessment", 14) => Some("interruptions_score"),
+        ("sleep_assessment", 15) => Some("average_stress_during_sleep"),
+        _ => None,
+    }
+}
+/// Profile definition of a message field, looked up by field name
+pub fn field_def(message_type: &str, name: &str) -> Option<FieldDef> {
+    match (message_type, name) {
+        ("file_id", "type") => Some(FieldDef {
+            number: 0,
+            name: "type",
+            field_ty: "file",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_id", "manufacturer") => Some(FieldDef {
+            number: 1,
+            name: "manufacturer",
+            field_ty: "manufacturer",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_id", "product") => Some(FieldDef {
+            number: 2,
+            name: "product",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_id", "serial_number") => Some(FieldDef {
+            number: 3,
+            name: "serial_number",
+            field_ty: "uint32z",
+            base_type: fit::BaseType::UInt32z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_id", "time_created") => Some(FieldDef {
+            number: 4,
+            name: "time_created",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_id", "number") => Some(FieldDef {
+            number: 5,
+            name: "number",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_id", "product_name") => Some(FieldDef {
+            number: 8,
+            name: "product_name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_creator", "software_version") => Some(FieldDef {
+            number: 0,
+            name: "software_version",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_creator", "hardware_version") => Some(FieldDef {
+            number: 1,
+            name: "hardware_version",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("timestamp_correlation", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("timestamp_correlation", "fractional_timestamp") => Some(FieldDef {
+            number: 0,
+            name: "fractional_timestamp",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 32768.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("timestamp_correlation", "system_timestamp") => Some(FieldDef {
+            number: 1,
+            name: "system_timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("timestamp_correlation", "fractional_system_timestamp") => Some(FieldDef {
+            number: 2,
+            name: "fractional_system_timestamp",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 32768.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("timestamp_correlation", "local_timestamp") => Some(FieldDef {
+            number: 3,
+            name: "local_timestamp",
+            field_ty: "local_date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("timestamp_correlation", "timestamp_ms") => Some(FieldDef {
+            number: 4,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("timestamp_correlation", "system_timestamp_ms") => Some(FieldDef {
+            number: 5,
+            name: "system_timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("software", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("software", "version") => Some(FieldDef {
+            number: 3,
+            name: "version",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("software", "part_number") => Some(FieldDef {
+            number: 5,
+            name: "part_number",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("slave_device", "manufacturer") => Some(FieldDef {
+            number: 0,
+            name: "manufacturer",
+            field_ty: "manufacturer",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("slave_device", "product") => Some(FieldDef {
+            number: 1,
+            name: "product",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("capabilities", "languages") => Some(FieldDef {
+            number: 0,
+            name: "languages",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("capabilities", "sports") => Some(FieldDef {
+            number: 1,
+            name: "sports",
+            field_ty: "sport_bits_0",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("capabilities", "workouts_supported") => Some(FieldDef {
+            number: 21,
+            name: "workouts_supported",
+            field_ty: "workout_capabilities",
+            base_type: fit::BaseType::UInt32z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("capabilities", "connectivity_supported") => Some(FieldDef {
+            number: 23,
+            name: "connectivity_supported",
+            field_ty: "connectivity_capabilities",
+            base_type: fit::BaseType::UInt32z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_capabilities", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_capabilities", "type") => Some(FieldDef {
+            number: 0,
+            name: "type",
+            field_ty: "file",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_capabilities", "flags") => Some(FieldDef {
+            number: 1,
+            name: "flags",
+            field_ty: "file_flags",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_capabilities", "directory") => Some(FieldDef {
+            number: 2,
+            name: "directory",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_capabilities", "max_count") => Some(FieldDef {
+            number: 3,
+            name: "max_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("file_capabilities", "max_size") => Some(FieldDef {
+            number: 4,
+            name: "max_size",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bytes",
+        }),
+        ("mesg_capabilities", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("mesg_capabilities", "file") => Some(FieldDef {
+            number: 0,
+            name: "file",
+            field_ty: "file",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("mesg_capabilities", "mesg_num") => Some(FieldDef {
+            number: 1,
+            name: "mesg_num",
+            field_ty: "mesg_num",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("mesg_capabilities", "count_type") => Some(FieldDef {
+            number: 2,
+            name: "count_type",
+            field_ty: "mesg_count",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("mesg_capabilities", "count") => Some(FieldDef {
+            number: 3,
+            name: "count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_capabilities", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_capabilities", "file") => Some(FieldDef {
+            number: 0,
+            name: "file",
+            field_ty: "file",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_capabilities", "mesg_num") => Some(FieldDef {
+            number: 1,
+            name: "mesg_num",
+            field_ty: "mesg_num",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_capabilities", "field_num") => Some(FieldDef {
+            number: 2,
+            name: "field_num",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_capabilities", "count") => Some(FieldDef {
+            number: 3,
+            name: "count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "active_time_zone") => Some(FieldDef {
+            number: 0,
+            name: "active_time_zone",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "utc_offset") => Some(FieldDef {
+            number: 1,
+            name: "utc_offset",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "time_offset") => Some(FieldDef {
+            number: 2,
+            name: "time_offset",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("device_settings", "time_mode") => Some(FieldDef {
+            number: 4,
+            name: "time_mode",
+            field_ty: "time_mode",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "time_zone_offset") => Some(FieldDef {
+            number: 5,
+            name: "time_zone_offset",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 4.0,
+            offset: 0.0,
+            units: "hr",
+        }),
+        ("device_settings", "backlight_mode") => Some(FieldDef {
+            number: 12,
+            name: "backlight_mode",
+            field_ty: "backlight_mode",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "activity_tracker_enabled") => Some(FieldDef {
+            number: 36,
+            name: "activity_tracker_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "clock_time") => Some(FieldDef {
+            number: 39,
+            name: "clock_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "pages_enabled") => Some(FieldDef {
+            number: 40,
+            name: "pages_enabled",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "move_alert_enabled") => Some(FieldDef {
+            number: 46,
+            name: "move_alert_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "date_mode") => Some(FieldDef {
+            number: 47,
+            name: "date_mode",
+            field_ty: "date_mode",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "display_orientation") => Some(FieldDef {
+            number: 55,
+            name: "display_orientation",
+            field_ty: "display_orientation",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "mounting_side") => Some(FieldDef {
+            number: 56,
+            name: "mounting_side",
+            field_ty: "side",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "default_page") => Some(FieldDef {
+            number: 57,
+            name: "default_page",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "autosync_min_steps") => Some(FieldDef {
+            number: 58,
+            name: "autosync_min_steps",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "steps",
+        }),
+        ("device_settings", "autosync_min_time") => Some(FieldDef {
+            number: 59,
+            name: "autosync_min_time",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "minutes",
+        }),
+        ("device_settings", "lactate_threshold_autodetect_enabled") => Some(FieldDef {
+            number: 80,
+            name: "lactate_threshold_autodetect_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "ble_auto_upload_enabled") => Some(FieldDef {
+            number: 86,
+            name: "ble_auto_upload_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "auto_sync_frequency") => Some(FieldDef {
+            number: 89,
+            name: "auto_sync_frequency",
+            field_ty: "auto_sync_frequency",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "auto_activity_detect") => Some(FieldDef {
+            number: 90,
+            name: "auto_activity_detect",
+            field_ty: "auto_activity_detect",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "number_of_screens") => Some(FieldDef {
+            number: 94,
+            name: "number_of_screens",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "smart_notification_display_orientation") => Some(FieldDef {
+            number: 95,
+            name: "smart_notification_display_orientation",
+            field_ty: "display_orientation",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "tap_interface") => Some(FieldDef {
+            number: 134,
+            name: "tap_interface",
+            field_ty: "switch",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_settings", "tap_sensitivity") => Some(FieldDef {
+            number: 174,
+            name: "tap_sensitivity",
+            field_ty: "tap_sensitivity",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "friendly_name") => Some(FieldDef {
+            number: 0,
+            name: "friendly_name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "gender") => Some(FieldDef {
+            number: 1,
+            name: "gender",
+            field_ty: "gender",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "age") => Some(FieldDef {
+            number: 2,
+            name: "age",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "years",
+        }),
+        ("user_profile", "height") => Some(FieldDef {
+            number: 3,
+            name: "height",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("user_profile", "weight") => Some(FieldDef {
+            number: 4,
+            name: "weight",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "kg",
+        }),
+        ("user_profile", "language") => Some(FieldDef {
+            number: 5,
+            name: "language",
+            field_ty: "language",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "elev_setting") => Some(FieldDef {
+            number: 6,
+            name: "elev_setting",
+            field_ty: "display_measure",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "weight_setting") => Some(FieldDef {
+            number: 7,
+            name: "weight_setting",
+            field_ty: "display_measure",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "resting_heart_rate") => Some(FieldDef {
+            number: 8,
+            name: "resting_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("user_profile", "default_max_running_heart_rate") => Some(FieldDef {
+            number: 9,
+            name: "default_max_running_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("user_profile", "default_max_biking_heart_rate") => Some(FieldDef {
+            number: 10,
+            name: "default_max_biking_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("user_profile", "default_max_heart_rate") => Some(FieldDef {
+            number: 11,
+            name: "default_max_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("user_profile", "hr_setting") => Some(FieldDef {
+            number: 12,
+            name: "hr_setting",
+            field_ty: "display_heart",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "speed_setting") => Some(FieldDef {
+            number: 13,
+            name: "speed_setting",
+            field_ty: "display_measure",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "dist_setting") => Some(FieldDef {
+            number: 14,
+            name: "dist_setting",
+            field_ty: "display_measure",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "power_setting") => Some(FieldDef {
+            number: 16,
+            name: "power_setting",
+            field_ty: "display_power",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "activity_class") => Some(FieldDef {
+            number: 17,
+            name: "activity_class",
+            field_ty: "activity_class",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "position_setting") => Some(FieldDef {
+            number: 18,
+            name: "position_setting",
+            field_ty: "display_position",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "temperature_setting") => Some(FieldDef {
+            number: 21,
+            name: "temperature_setting",
+            field_ty: "display_measure",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "local_id") => Some(FieldDef {
+            number: 22,
+            name: "local_id",
+            field_ty: "user_local_id",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "global_id") => Some(FieldDef {
+            number: 23,
+            name: "global_id",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "wake_time") => Some(FieldDef {
+            number: 28,
+            name: "wake_time",
+            field_ty: "localtime_into_day",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "sleep_time") => Some(FieldDef {
+            number: 29,
+            name: "sleep_time",
+            field_ty: "localtime_into_day",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "height_setting") => Some(FieldDef {
+            number: 30,
+            name: "height_setting",
+            field_ty: "display_measure",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "user_running_step_length") => Some(FieldDef {
+            number: 31,
+            name: "user_running_step_length",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("user_profile", "user_walking_step_length") => Some(FieldDef {
+            number: 32,
+            name: "user_walking_step_length",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("user_profile", "depth_setting") => Some(FieldDef {
+            number: 47,
+            name: "depth_setting",
+            field_ty: "display_measure",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("user_profile", "dive_count") => Some(FieldDef {
+            number: 49,
+            name: "dive_count",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hrm_profile", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hrm_profile", "enabled") => Some(FieldDef {
+            number: 0,
+            name: "enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hrm_profile", "hrm_ant_id") => Some(FieldDef {
+            number: 1,
+            name: "hrm_ant_id",
+            field_ty: "uint16z",
+            base_type: fit::BaseType::UInt16z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hrm_profile", "log_hrv") => Some(FieldDef {
+            number: 2,
+            name: "log_hrv",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hrm_profile", "hrm_ant_id_trans_type") => Some(FieldDef {
+            number: 3,
+            name: "hrm_ant_id_trans_type",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sdm_profile", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sdm_profile", "enabled") => Some(FieldDef {
+            number: 0,
+            name: "enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sdm_profile", "sdm_ant_id") => Some(FieldDef {
+            number: 1,
+            name: "sdm_ant_id",
+            field_ty: "uint16z",
+            base_type: fit::BaseType::UInt16z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sdm_profile", "sdm_cal_factor") => Some(FieldDef {
+            number: 2,
+            name: "sdm_cal_factor",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("sdm_profile", "odometer") => Some(FieldDef {
+            number: 3,
+            name: "odometer",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("sdm_profile", "speed_source") => Some(FieldDef {
+            number: 4,
+            name: "speed_source",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sdm_profile", "sdm_ant_id_trans_type") => Some(FieldDef {
+            number: 5,
+            name: "sdm_ant_id_trans_type",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sdm_profile", "odometer_rollover") => Some(FieldDef {
+            number: 7,
+            name: "odometer_rollover",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "name") => Some(FieldDef {
+            number: 0,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "sport") => Some(FieldDef {
+            number: 1,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "sub_sport") => Some(FieldDef {
+            number: 2,
+            name: "sub_sport",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "odometer") => Some(FieldDef {
+            number: 3,
+            name: "odometer",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("bike_profile", "bike_spd_ant_id") => Some(FieldDef {
+            number: 4,
+            name: "bike_spd_ant_id",
+            field_ty: "uint16z",
+            base_type: fit::BaseType::UInt16z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "bike_cad_ant_id") => Some(FieldDef {
+            number: 5,
+            name: "bike_cad_ant_id",
+            field_ty: "uint16z",
+            base_type: fit::BaseType::UInt16z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "bike_spdcad_ant_id") => Some(FieldDef {
+            number: 6,
+            name: "bike_spdcad_ant_id",
+            field_ty: "uint16z",
+            base_type: fit::BaseType::UInt16z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "bike_power_ant_id") => Some(FieldDef {
+            number: 7,
+            name: "bike_power_ant_id",
+            field_ty: "uint16z",
+            base_type: fit::BaseType::UInt16z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "custom_wheelsize") => Some(FieldDef {
+            number: 8,
+            name: "custom_wheelsize",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("bike_profile", "auto_wheelsize") => Some(FieldDef {
+            number: 9,
+            name: "auto_wheelsize",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("bike_profile", "bike_weight") => Some(FieldDef {
+            number: 10,
+            name: "bike_weight",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "kg",
+        }),
+        ("bike_profile", "power_cal_factor") => Some(FieldDef {
+            number: 11,
+            name: "power_cal_factor",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("bike_profile", "auto_wheel_cal") => Some(FieldDef {
+            number: 12,
+            name: "auto_wheel_cal",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "auto_power_zero") => Some(FieldDef {
+            number: 13,
+            name: "auto_power_zero",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "id") => Some(FieldDef {
+            number: 14,
+            name: "id",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "spd_enabled") => Some(FieldDef {
+            number: 15,
+            name: "spd_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "cad_enabled") => Some(FieldDef {
+            number: 16,
+            name: "cad_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "spdcad_enabled") => Some(FieldDef {
+            number: 17,
+            name: "spdcad_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "power_enabled") => Some(FieldDef {
+            number: 18,
+            name: "power_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "crank_length") => Some(FieldDef {
+            number: 19,
+            name: "crank_length",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("bike_profile", "enabled") => Some(FieldDef {
+            number: 20,
+            name: "enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "bike_spd_ant_id_trans_type") => Some(FieldDef {
+            number: 21,
+            name: "bike_spd_ant_id_trans_type",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "bike_cad_ant_id_trans_type") => Some(FieldDef {
+            number: 22,
+            name: "bike_cad_ant_id_trans_type",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "bike_spdcad_ant_id_trans_type") => Some(FieldDef {
+            number: 23,
+            name: "bike_spdcad_ant_id_trans_type",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "bike_power_ant_id_trans_type") => Some(FieldDef {
+            number: 24,
+            name: "bike_power_ant_id_trans_type",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "odometer_rollover") => Some(FieldDef {
+            number: 37,
+            name: "odometer_rollover",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "front_gear_num") => Some(FieldDef {
+            number: 38,
+            name: "front_gear_num",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "front_gear") => Some(FieldDef {
+            number: 39,
+            name: "front_gear",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "rear_gear_num") => Some(FieldDef {
+            number: 40,
+            name: "rear_gear_num",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "rear_gear") => Some(FieldDef {
+            number: 41,
+            name: "rear_gear",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("bike_profile", "shimano_di2_enabled") => Some(FieldDef {
+            number: 44,
+            name: "shimano_di2_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "bluetooth_enabled") => Some(FieldDef {
+            number: 0,
+            name: "bluetooth_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "bluetooth_le_enabled") => Some(FieldDef {
+            number: 1,
+            name: "bluetooth_le_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "ant_enabled") => Some(FieldDef {
+            number: 2,
+            name: "ant_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "name") => Some(FieldDef {
+            number: 3,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "live_tracking_enabled") => Some(FieldDef {
+            number: 4,
+            name: "live_tracking_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "weather_conditions_enabled") => Some(FieldDef {
+            number: 5,
+            name: "weather_conditions_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "weather_alerts_enabled") => Some(FieldDef {
+            number: 6,
+            name: "weather_alerts_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "auto_activity_upload_enabled") => Some(FieldDef {
+            number: 7,
+            name: "auto_activity_upload_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "course_download_enabled") => Some(FieldDef {
+            number: 8,
+            name: "course_download_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "workout_download_enabled") => Some(FieldDef {
+            number: 9,
+            name: "workout_download_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "gps_ephemeris_download_enabled") => Some(FieldDef {
+            number: 10,
+            name: "gps_ephemeris_download_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "incident_detection_enabled") => Some(FieldDef {
+            number: 11,
+            name: "incident_detection_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("connectivity", "grouptrack_enabled") => Some(FieldDef {
+            number: 12,
+            name: "grouptrack_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("watchface_settings", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("watchface_settings", "mode") => Some(FieldDef {
+            number: 0,
+            name: "mode",
+            field_ty: "watchface_mode",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("watchface_settings", "layout") => Some(FieldDef {
+            number: 1,
+            name: "layout",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ohr_settings", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("ohr_settings", "enabled") => Some(FieldDef {
+            number: 0,
+            name: "enabled",
+            field_ty: "switch",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("time_in_zone", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("time_in_zone", "reference_mesg") => Some(FieldDef {
+            number: 0,
+            name: "reference_mesg",
+            field_ty: "mesg_num",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("time_in_zone", "reference_index") => Some(FieldDef {
+            number: 1,
+            name: "reference_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("time_in_zone", "time_in_hr_zone") => Some(FieldDef {
+            number: 2,
+            name: "time_in_hr_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("time_in_zone", "time_in_speed_zone") => Some(FieldDef {
+            number: 3,
+            name: "time_in_speed_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("time_in_zone", "time_in_cadence_zone") => Some(FieldDef {
+            number: 4,
+            name: "time_in_cadence_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("time_in_zone", "time_in_power_zone") => Some(FieldDef {
+            number: 5,
+            name: "time_in_power_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("time_in_zone", "hr_zone_high_boundary") => Some(FieldDef {
+            number: 6,
+            name: "hr_zone_high_boundary",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("time_in_zone", "speed_zone_high_boundary") => Some(FieldDef {
+            number: 7,
+            name: "speed_zone_high_boundary",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("time_in_zone", "cadence_zone_high_bondary") => Some(FieldDef {
+            number: 8,
+            name: "cadence_zone_high_bondary",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("time_in_zone", "power_zone_high_boundary") => Some(FieldDef {
+            number: 9,
+            name: "power_zone_high_boundary",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("time_in_zone", "hr_calc_type") => Some(FieldDef {
+            number: 10,
+            name: "hr_calc_type",
+            field_ty: "hr_zone_calc",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("time_in_zone", "max_heart_rate") => Some(FieldDef {
+            number: 11,
+            name: "max_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("time_in_zone", "resting_heart_rate") => Some(FieldDef {
+            number: 12,
+            name: "resting_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("time_in_zone", "threshold_heart_rate") => Some(FieldDef {
+            number: 13,
+            name: "threshold_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("time_in_zone", "pwr_calc_type") => Some(FieldDef {
+            number: 14,
+            name: "pwr_calc_type",
+            field_ty: "pwr_zone_calc",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("time_in_zone", "functional_threshold_power") => Some(FieldDef {
+            number: 15,
+            name: "functional_threshold_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("zones_target", "max_heart_rate") => Some(FieldDef {
+            number: 1,
+            name: "max_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("zones_target", "threshold_heart_rate") => Some(FieldDef {
+            number: 2,
+            name: "threshold_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("zones_target", "functional_threshold_power") => Some(FieldDef {
+            number: 3,
+            name: "functional_threshold_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("zones_target", "hr_calc_type") => Some(FieldDef {
+            number: 5,
+            name: "hr_calc_type",
+            field_ty: "hr_zone_calc",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("zones_target", "pwr_calc_type") => Some(FieldDef {
+            number: 7,
+            name: "pwr_calc_type",
+            field_ty: "pwr_zone_calc",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sport", "sport") => Some(FieldDef {
+            number: 0,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sport", "sub_sport") => Some(FieldDef {
+            number: 1,
+            name: "sub_sport",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sport", "name") => Some(FieldDef {
+            number: 3,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hr_zone", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hr_zone", "high_bpm") => Some(FieldDef {
+            number: 1,
+            name: "high_bpm",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("hr_zone", "name") => Some(FieldDef {
+            number: 2,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("speed_zone", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("speed_zone", "high_value") => Some(FieldDef {
+            number: 0,
+            name: "high_value",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("speed_zone", "name") => Some(FieldDef {
+            number: 1,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("cadence_zone", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("cadence_zone", "high_value") => Some(FieldDef {
+            number: 0,
+            name: "high_value",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("cadence_zone", "name") => Some(FieldDef {
+            number: 1,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("power_zone", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("power_zone", "high_value") => Some(FieldDef {
+            number: 1,
+            name: "high_value",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("power_zone", "name") => Some(FieldDef {
+            number: 2,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("met_zone", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("met_zone", "high_bpm") => Some(FieldDef {
+            number: 1,
+            name: "high_bpm",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("met_zone", "calories") => Some(FieldDef {
+            number: 2,
+            name: "calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "kcal / min",
+        }),
+        ("met_zone", "fat_calories") => Some(FieldDef {
+            number: 3,
+            name: "fat_calories",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 10.0,
+            offset: 0.0,
+            units: "kcal / min",
+        }),
+        ("dive_settings", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "name") => Some(FieldDef {
+            number: 0,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "model") => Some(FieldDef {
+            number: 1,
+            name: "model",
+            field_ty: "tissue_model_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "gf_low") => Some(FieldDef {
+            number: 2,
+            name: "gf_low",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_settings", "gf_high") => Some(FieldDef {
+            number: 3,
+            name: "gf_high",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_settings", "water_type") => Some(FieldDef {
+            number: 4,
+            name: "water_type",
+            field_ty: "water_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "water_density") => Some(FieldDef {
+            number: 5,
+            name: "water_density",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kg/m^3",
+        }),
+        ("dive_settings", "po2_warn") => Some(FieldDef {
+            number: 6,
+            name: "po2_warn",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_settings", "po2_critical") => Some(FieldDef {
+            number: 7,
+            name: "po2_critical",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_settings", "po2_deco") => Some(FieldDef {
+            number: 8,
+            name: "po2_deco",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_settings", "safety_stop_enabled") => Some(FieldDef {
+            number: 9,
+            name: "safety_stop_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "bottom_depth") => Some(FieldDef {
+            number: 10,
+            name: "bottom_depth",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "bottom_time") => Some(FieldDef {
+            number: 11,
+            name: "bottom_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "apnea_countdown_enabled") => Some(FieldDef {
+            number: 12,
+            name: "apnea_countdown_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "apnea_countdown_time") => Some(FieldDef {
+            number: 13,
+            name: "apnea_countdown_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "backlight_mode") => Some(FieldDef {
+            number: 14,
+            name: "backlight_mode",
+            field_ty: "dive_backlight_mode",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "backlight_brightness") => Some(FieldDef {
+            number: 15,
+            name: "backlight_brightness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "backlight_timeout") => Some(FieldDef {
+            number: 16,
+            name: "backlight_timeout",
+            field_ty: "backlight_timeout",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "repeat_dive_interval") => Some(FieldDef {
+            number: 17,
+            name: "repeat_dive_interval",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("dive_settings", "safety_stop_time") => Some(FieldDef {
+            number: 18,
+            name: "safety_stop_time",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("dive_settings", "heart_rate_source_type") => Some(FieldDef {
+            number: 19,
+            name: "heart_rate_source_type",
+            field_ty: "source_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "heart_rate_source") => Some(FieldDef {
+            number: 20,
+            name: "heart_rate_source",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "travel_gas") => Some(FieldDef {
+            number: 21,
+            name: "travel_gas",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "ccr_low_setpoint_switch_mode") => Some(FieldDef {
+            number: 22,
+            name: "ccr_low_setpoint_switch_mode",
+            field_ty: "ccr_setpoint_switch_mode",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "ccr_low_setpoint") => Some(FieldDef {
+            number: 23,
+            name: "ccr_low_setpoint",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_settings", "ccr_low_setpoint_depth") => Some(FieldDef {
+            number: 24,
+            name: "ccr_low_setpoint_depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("dive_settings", "ccr_high_setpoint_switch_mode") => Some(FieldDef {
+            number: 25,
+            name: "ccr_high_setpoint_switch_mode",
+            field_ty: "ccr_setpoint_switch_mode",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "ccr_high_setpoint") => Some(FieldDef {
+            number: 26,
+            name: "ccr_high_setpoint",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_settings", "ccr_high_setpoint_depth") => Some(FieldDef {
+            number: 27,
+            name: "ccr_high_setpoint_depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("dive_settings", "gas_consumption_display") => Some(FieldDef {
+            number: 29,
+            name: "gas_consumption_display",
+            field_ty: "gas_consumption_rate_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "up_key_enabled") => Some(FieldDef {
+            number: 30,
+            name: "up_key_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "dive_sounds") => Some(FieldDef {
+            number: 35,
+            name: "dive_sounds",
+            field_ty: "tone",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "last_stop_multiple") => Some(FieldDef {
+            number: 36,
+            name: "last_stop_multiple",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 10.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_settings", "no_fly_time_mode") => Some(FieldDef {
+            number: 37,
+            name: "no_fly_time_mode",
+            field_ty: "no_fly_time_mode",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "depth") => Some(FieldDef {
+            number: 0,
+            name: "depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("dive_alarm", "time") => Some(FieldDef {
+            number: 1,
+            name: "time",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("dive_alarm", "enabled") => Some(FieldDef {
+            number: 2,
+            name: "enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "alarm_type") => Some(FieldDef {
+            number: 3,
+            name: "alarm_type",
+            field_ty: "dive_alarm_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "sound") => Some(FieldDef {
+            number: 4,
+            name: "sound",
+            field_ty: "tone",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "dive_types") => Some(FieldDef {
+            number: 5,
+            name: "dive_types",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "id") => Some(FieldDef {
+            number: 6,
+            name: "id",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "popup_enabled") => Some(FieldDef {
+            number: 7,
+            name: "popup_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "trigger_on_descent") => Some(FieldDef {
+            number: 8,
+            name: "trigger_on_descent",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "trigger_on_ascent") => Some(FieldDef {
+            number: 9,
+            name: "trigger_on_ascent",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "repeating") => Some(FieldDef {
+            number: 10,
+            name: "repeating",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_alarm", "speed") => Some(FieldDef {
+            number: 11,
+            name: "speed",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "mps",
+        }),
+        ("dive_apnea_alarm", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_apnea_alarm", "depth") => Some(FieldDef {
+            number: 0,
+            name: "depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("dive_apnea_alarm", "time") => Some(FieldDef {
+            number: 1,
+            name: "time",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("dive_apnea_alarm", "enabled") => Some(FieldDef {
+            number: 2,
+            name: "enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_apnea_alarm", "alarm_type") => Some(FieldDef {
+            number: 3,
+            name: "alarm_type",
+            field_ty: "dive_alarm_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_apnea_alarm", "sound") => Some(FieldDef {
+            number: 4,
+            name: "sound",
+            field_ty: "tone",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_apnea_alarm", "dive_types") => Some(FieldDef {
+            number: 5,
+            name: "dive_types",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_apnea_alarm", "id") => Some(FieldDef {
+            number: 6,
+            name: "id",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_apnea_alarm", "popup_enabled") => Some(FieldDef {
+            number: 7,
+            name: "popup_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_apnea_alarm", "trigger_on_descent") => Some(FieldDef {
+            number: 8,
+            name: "trigger_on_descent",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_apnea_alarm", "trigger_on_ascent") => Some(FieldDef {
+            number: 9,
+            name: "trigger_on_ascent",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_apnea_alarm", "repeating") => Some(FieldDef {
+            number: 10,
+            name: "repeating",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_apnea_alarm", "speed") => Some(FieldDef {
+            number: 11,
+            name: "speed",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "mps",
+        }),
+        ("dive_gas", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_gas", "helium_content") => Some(FieldDef {
+            number: 0,
+            name: "helium_content",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_gas", "oxygen_content") => Some(FieldDef {
+            number: 1,
+            name: "oxygen_content",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_gas", "status") => Some(FieldDef {
+            number: 2,
+            name: "status",
+            field_ty: "dive_gas_status",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_gas", "mode") => Some(FieldDef {
+            number: 3,
+            name: "mode",
+            field_ty: "dive_gas_mode",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "sport") => Some(FieldDef {
+            number: 0,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "sub_sport") => Some(FieldDef {
+            number: 1,
+            name: "sub_sport",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "start_date") => Some(FieldDef {
+            number: 2,
+            name: "start_date",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "end_date") => Some(FieldDef {
+            number: 3,
+            name: "end_date",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "type") => Some(FieldDef {
+            number: 4,
+            name: "type",
+            field_ty: "goal",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "value") => Some(FieldDef {
+            number: 5,
+            name: "value",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "repeat") => Some(FieldDef {
+            number: 6,
+            name: "repeat",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "target_value") => Some(FieldDef {
+            number: 7,
+            name: "target_value",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "recurrence") => Some(FieldDef {
+            number: 8,
+            name: "recurrence",
+            field_ty: "goal_recurrence",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "recurrence_value") => Some(FieldDef {
+            number: 9,
+            name: "recurrence_value",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "enabled") => Some(FieldDef {
+            number: 10,
+            name: "enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("goal", "source") => Some(FieldDef {
+            number: 11,
+            name: "source",
+            field_ty: "goal_source",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("activity", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("activity", "total_timer_time") => Some(FieldDef {
+            number: 0,
+            name: "total_timer_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("activity", "num_sessions") => Some(FieldDef {
+            number: 1,
+            name: "num_sessions",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("activity", "type") => Some(FieldDef {
+            number: 2,
+            name: "type",
+            field_ty: "activity",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("activity", "event") => Some(FieldDef {
+            number: 3,
+            name: "event",
+            field_ty: "event",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("activity", "event_type") => Some(FieldDef {
+            number: 4,
+            name: "event_type",
+            field_ty: "event_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("activity", "local_timestamp") => Some(FieldDef {
+            number: 5,
+            name: "local_timestamp",
+            field_ty: "local_date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("activity", "event_group") => Some(FieldDef {
+            number: 6,
+            name: "event_group",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "event") => Some(FieldDef {
+            number: 0,
+            name: "event",
+            field_ty: "event",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "event_type") => Some(FieldDef {
+            number: 1,
+            name: "event_type",
+            field_ty: "event_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "start_time") => Some(FieldDef {
+            number: 2,
+            name: "start_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "start_position_lat") => Some(FieldDef {
+            number: 3,
+            name: "start_position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("session", "start_position_long") => Some(FieldDef {
+            number: 4,
+            name: "start_position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("session", "sport") => Some(FieldDef {
+            number: 5,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "sub_sport") => Some(FieldDef {
+            number: 6,
+            name: "sub_sport",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "total_elapsed_time") => Some(FieldDef {
+            number: 7,
+            name: "total_elapsed_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "total_timer_time") => Some(FieldDef {
+            number: 8,
+            name: "total_timer_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "total_distance") => Some(FieldDef {
+            number: 9,
+            name: "total_distance",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("session", "total_cycles") => Some(FieldDef {
+            number: 10,
+            name: "total_cycles",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "cycles",
+        }),
+        ("session", "total_calories") => Some(FieldDef {
+            number: 11,
+            name: "total_calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("session", "total_fat_calories") => Some(FieldDef {
+            number: 13,
+            name: "total_fat_calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("session", "avg_speed") => Some(FieldDef {
+            number: 14,
+            name: "avg_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "max_speed") => Some(FieldDef {
+            number: 15,
+            name: "max_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "avg_heart_rate") => Some(FieldDef {
+            number: 16,
+            name: "avg_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("session", "max_heart_rate") => Some(FieldDef {
+            number: 17,
+            name: "max_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("session", "avg_cadence") => Some(FieldDef {
+            number: 18,
+            name: "avg_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("session", "max_cadence") => Some(FieldDef {
+            number: 19,
+            name: "max_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("session", "avg_power") => Some(FieldDef {
+            number: 20,
+            name: "avg_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("session", "max_power") => Some(FieldDef {
+            number: 21,
+            name: "max_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("session", "total_ascent") => Some(FieldDef {
+            number: 22,
+            name: "total_ascent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("session", "total_descent") => Some(FieldDef {
+            number: 23,
+            name: "total_descent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("session", "total_training_effect") => Some(FieldDef {
+            number: 24,
+            name: "total_training_effect",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 10.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "first_lap_index") => Some(FieldDef {
+            number: 25,
+            name: "first_lap_index",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "num_laps") => Some(FieldDef {
+            number: 26,
+            name: "num_laps",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "event_group") => Some(FieldDef {
+            number: 27,
+            name: "event_group",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "trigger") => Some(FieldDef {
+            number: 28,
+            name: "trigger",
+            field_ty: "session_trigger",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "nec_lat") => Some(FieldDef {
+            number: 29,
+            name: "nec_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("session", "nec_long") => Some(FieldDef {
+            number: 30,
+            name: "nec_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("session", "swc_lat") => Some(FieldDef {
+            number: 31,
+            name: "swc_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("session", "swc_long") => Some(FieldDef {
+            number: 32,
+            name: "swc_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("session", "num_lengths") => Some(FieldDef {
+            number: 33,
+            name: "num_lengths",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "lengths",
+        }),
+        ("session", "normalized_power") => Some(FieldDef {
+            number: 34,
+            name: "normalized_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("session", "training_stress_score") => Some(FieldDef {
+            number: 35,
+            name: "training_stress_score",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "tss",
+        }),
+        ("session", "intensity_factor") => Some(FieldDef {
+            number: 36,
+            name: "intensity_factor",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "if",
+        }),
+        ("session", "left_right_balance") => Some(FieldDef {
+            number: 37,
+            name: "left_right_balance",
+            field_ty: "left_right_balance_100",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "end_position_lat") => Some(FieldDef {
+            number: 38,
+            name: "end_position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("session", "end_position_long") => Some(FieldDef {
+            number: 39,
+            name: "end_position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("session", "avg_stroke_count") => Some(FieldDef {
+            number: 41,
+            name: "avg_stroke_count",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 10.0,
+            offset: 0.0,
+            units: "strokes/lap",
+        }),
+        ("session", "avg_stroke_distance") => Some(FieldDef {
+            number: 42,
+            name: "avg_stroke_distance",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("session", "swim_stroke") => Some(FieldDef {
+            number: 43,
+            name: "swim_stroke",
+            field_ty: "swim_stroke",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "swim_stroke",
+        }),
+        ("session", "pool_length") => Some(FieldDef {
+            number: 44,
+            name: "pool_length",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("session", "threshold_power") => Some(FieldDef {
+            number: 45,
+            name: "threshold_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("session", "pool_length_unit") => Some(FieldDef {
+            number: 46,
+            name: "pool_length_unit",
+            field_ty: "display_measure",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "num_active_lengths") => Some(FieldDef {
+            number: 47,
+            name: "num_active_lengths",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "lengths",
+        }),
+        ("session", "total_work") => Some(FieldDef {
+            number: 48,
+            name: "total_work",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "J",
+        }),
+        ("session", "avg_altitude") => Some(FieldDef {
+            number: 49,
+            name: "avg_altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("session", "max_altitude") => Some(FieldDef {
+            number: 50,
+            name: "max_altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("session", "gps_accuracy") => Some(FieldDef {
+            number: 51,
+            name: "gps_accuracy",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("session", "avg_grade") => Some(FieldDef {
+            number: 52,
+            name: "avg_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("session", "avg_pos_grade") => Some(FieldDef {
+            number: 53,
+            name: "avg_pos_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("session", "avg_neg_grade") => Some(FieldDef {
+            number: 54,
+            name: "avg_neg_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("session", "max_pos_grade") => Some(FieldDef {
+            number: 55,
+            name: "max_pos_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("session", "max_neg_grade") => Some(FieldDef {
+            number: 56,
+            name: "max_neg_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("session", "avg_temperature") => Some(FieldDef {
+            number: 57,
+            name: "avg_temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("session", "max_temperature") => Some(FieldDef {
+            number: 58,
+            name: "max_temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("session", "total_moving_time") => Some(FieldDef {
+            number: 59,
+            name: "total_moving_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "avg_pos_vertical_speed") => Some(FieldDef {
+            number: 60,
+            name: "avg_pos_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "avg_neg_vertical_speed") => Some(FieldDef {
+            number: 61,
+            name: "avg_neg_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "max_pos_vertical_speed") => Some(FieldDef {
+            number: 62,
+            name: "max_pos_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "max_neg_vertical_speed") => Some(FieldDef {
+            number: 63,
+            name: "max_neg_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "min_heart_rate") => Some(FieldDef {
+            number: 64,
+            name: "min_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("session", "time_in_hr_zone") => Some(FieldDef {
+            number: 65,
+            name: "time_in_hr_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "time_in_speed_zone") => Some(FieldDef {
+            number: 66,
+            name: "time_in_speed_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "time_in_cadence_zone") => Some(FieldDef {
+            number: 67,
+            name: "time_in_cadence_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "time_in_power_zone") => Some(FieldDef {
+            number: 68,
+            name: "time_in_power_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "avg_lap_time") => Some(FieldDef {
+            number: 69,
+            name: "avg_lap_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "best_lap_index") => Some(FieldDef {
+            number: 70,
+            name: "best_lap_index",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "min_altitude") => Some(FieldDef {
+            number: 71,
+            name: "min_altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("session", "player_score") => Some(FieldDef {
+            number: 82,
+            name: "player_score",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "opponent_score") => Some(FieldDef {
+            number: 83,
+            name: "opponent_score",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "opponent_name") => Some(FieldDef {
+            number: 84,
+            name: "opponent_name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "stroke_count") => Some(FieldDef {
+            number: 85,
+            name: "stroke_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("session", "zone_count") => Some(FieldDef {
+            number: 86,
+            name: "zone_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("session", "max_ball_speed") => Some(FieldDef {
+            number: 87,
+            name: "max_ball_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "avg_ball_speed") => Some(FieldDef {
+            number: 88,
+            name: "avg_ball_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "avg_vertical_oscillation") => Some(FieldDef {
+            number: 89,
+            name: "avg_vertical_oscillation",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("session", "avg_stance_time_percent") => Some(FieldDef {
+            number: 90,
+            name: "avg_stance_time_percent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "avg_stance_time") => Some(FieldDef {
+            number: 91,
+            name: "avg_stance_time",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("session", "avg_fractional_cadence") => Some(FieldDef {
+            number: 92,
+            name: "avg_fractional_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("session", "max_fractional_cadence") => Some(FieldDef {
+            number: 93,
+            name: "max_fractional_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("session", "total_fractional_cycles") => Some(FieldDef {
+            number: 94,
+            name: "total_fractional_cycles",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "cycles",
+        }),
+        ("session", "avg_total_hemoglobin_conc") => Some(FieldDef {
+            number: 95,
+            name: "avg_total_hemoglobin_conc",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "g/dL",
+        }),
+        ("session", "min_total_hemoglobin_conc") => Some(FieldDef {
+            number: 96,
+            name: "min_total_hemoglobin_conc",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "g/dL",
+        }),
+        ("session", "max_total_hemoglobin_conc") => Some(FieldDef {
+            number: 97,
+            name: "max_total_hemoglobin_conc",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "g/dL",
+        }),
+        ("session", "avg_saturated_hemoglobin_percent") => Some(FieldDef {
+            number: 98,
+            name: "avg_saturated_hemoglobin_percent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("session", "min_saturated_hemoglobin_percent") => Some(FieldDef {
+            number: 99,
+            name: "min_saturated_hemoglobin_percent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("session", "max_saturated_hemoglobin_percent") => Some(FieldDef {
+            number: 100,
+            name: "max_saturated_hemoglobin_percent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("session", "avg_left_torque_effectiveness") => Some(FieldDef {
+            number: 101,
+            name: "avg_left_torque_effectiveness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "avg_right_torque_effectiveness") => Some(FieldDef {
+            number: 102,
+            name: "avg_right_torque_effectiveness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "avg_left_pedal_smoothness") => Some(FieldDef {
+            number: 103,
+            name: "avg_left_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "avg_right_pedal_smoothness") => Some(FieldDef {
+            number: 104,
+            name: "avg_right_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "avg_combined_pedal_smoothness") => Some(FieldDef {
+            number: 105,
+            name: "avg_combined_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "sport_profile_name") => Some(FieldDef {
+            number: 110,
+            name: "sport_profile_name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "sport_index") => Some(FieldDef {
+            number: 111,
+            name: "sport_index",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "time_standing") => Some(FieldDef {
+            number: 112,
+            name: "time_standing",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "stand_count") => Some(FieldDef {
+            number: 113,
+            name: "stand_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "avg_left_pco") => Some(FieldDef {
+            number: 114,
+            name: "avg_left_pco",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("session", "avg_right_pco") => Some(FieldDef {
+            number: 115,
+            name: "avg_right_pco",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("session", "avg_left_power_phase") => Some(FieldDef {
+            number: 116,
+            name: "avg_left_power_phase",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("session", "avg_left_power_phase_peak") => Some(FieldDef {
+            number: 117,
+            name: "avg_left_power_phase_peak",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("session", "avg_right_power_phase") => Some(FieldDef {
+            number: 118,
+            name: "avg_right_power_phase",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("session", "avg_right_power_phase_peak") => Some(FieldDef {
+            number: 119,
+            name: "avg_right_power_phase_peak",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("session", "avg_power_position") => Some(FieldDef {
+            number: 120,
+            name: "avg_power_position",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("session", "max_power_position") => Some(FieldDef {
+            number: 121,
+            name: "max_power_position",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("session", "avg_cadence_position") => Some(FieldDef {
+            number: 122,
+            name: "avg_cadence_position",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("session", "max_cadence_position") => Some(FieldDef {
+            number: 123,
+            name: "max_cadence_position",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("session", "enhanced_avg_speed") => Some(FieldDef {
+            number: 124,
+            name: "enhanced_avg_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "enhanced_max_speed") => Some(FieldDef {
+            number: 125,
+            name: "enhanced_max_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "enhanced_avg_altitude") => Some(FieldDef {
+            number: 126,
+            name: "enhanced_avg_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("session", "enhanced_min_altitude") => Some(FieldDef {
+            number: 127,
+            name: "enhanced_min_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("session", "enhanced_max_altitude") => Some(FieldDef {
+            number: 128,
+            name: "enhanced_max_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("session", "avg_lev_motor_power") => Some(FieldDef {
+            number: 129,
+            name: "avg_lev_motor_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("session", "max_lev_motor_power") => Some(FieldDef {
+            number: 130,
+            name: "max_lev_motor_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("session", "lev_battery_consumption") => Some(FieldDef {
+            number: 131,
+            name: "lev_battery_consumption",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "avg_vertical_ratio") => Some(FieldDef {
+            number: 132,
+            name: "avg_vertical_ratio",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "avg_stance_time_balance") => Some(FieldDef {
+            number: 133,
+            name: "avg_stance_time_balance",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "avg_step_length") => Some(FieldDef {
+            number: 134,
+            name: "avg_step_length",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("session", "total_anaerobic_training_effect") => Some(FieldDef {
+            number: 137,
+            name: "total_anaerobic_training_effect",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 10.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "avg_vam") => Some(FieldDef {
+            number: 139,
+            name: "avg_vam",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("session", "avg_depth") => Some(FieldDef {
+            number: 140,
+            name: "avg_depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("session", "max_depth") => Some(FieldDef {
+            number: 141,
+            name: "max_depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("session", "surface_interval") => Some(FieldDef {
+            number: 142,
+            name: "surface_interval",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("session", "start_cns") => Some(FieldDef {
+            number: 143,
+            name: "start_cns",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "end_cns") => Some(FieldDef {
+            number: 144,
+            name: "end_cns",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "start_n2") => Some(FieldDef {
+            number: 145,
+            name: "start_n2",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "end_n2") => Some(FieldDef {
+            number: 146,
+            name: "end_n2",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "avg_respiration_rate") => Some(FieldDef {
+            number: 147,
+            name: "avg_respiration_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "max_respiration_rate") => Some(FieldDef {
+            number: 148,
+            name: "max_respiration_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "min_respiration_rate") => Some(FieldDef {
+            number: 149,
+            name: "min_respiration_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "min_temperature") => Some(FieldDef {
+            number: 150,
+            name: "min_temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("session", "o2_toxicity") => Some(FieldDef {
+            number: 155,
+            name: "o2_toxicity",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "OTUs",
+        }),
+        ("session", "dive_number") => Some(FieldDef {
+            number: 156,
+            name: "dive_number",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "training_load_peak") => Some(FieldDef {
+            number: 168,
+            name: "training_load_peak",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 65536.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "enhanced_avg_respiration_rate") => Some(FieldDef {
+            number: 169,
+            name: "enhanced_avg_respiration_rate",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "Breaths/min",
+        }),
+        ("session", "enhanced_max_respiration_rate") => Some(FieldDef {
+            number: 170,
+            name: "enhanced_max_respiration_rate",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "Breaths/min",
+        }),
+        ("session", "enhanced_min_respiration_rate") => Some(FieldDef {
+            number: 180,
+            name: "enhanced_min_respiration_rate",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "total_grit") => Some(FieldDef {
+            number: 181,
+            name: "total_grit",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kGrit",
+        }),
+        ("session", "total_flow") => Some(FieldDef {
+            number: 182,
+            name: "total_flow",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "Flow",
+        }),
+        ("session", "jump_count") => Some(FieldDef {
+            number: 183,
+            name: "jump_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("session", "avg_grit") => Some(FieldDef {
+            number: 186,
+            name: "avg_grit",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kGrit",
+        }),
+        ("session", "avg_flow") => Some(FieldDef {
+            number: 187,
+            name: "avg_flow",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "Flow",
+        }),
+        ("session", "avg_spo2") => Some(FieldDef {
+            number: 194,
+            name: "avg_spo2",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "avg_stress") => Some(FieldDef {
+            number: 195,
+            name: "avg_stress",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("session", "sdrr_hrv") => Some(FieldDef {
+            number: 197,
+            name: "sdrr_hrv",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mS",
+        }),
+        ("session", "rmssd_hrv") => Some(FieldDef {
+            number: 198,
+            name: "rmssd_hrv",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mS",
+        }),
+        ("session", "total_fractional_ascent") => Some(FieldDef {
+            number: 199,
+            name: "total_fractional_ascent",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("session", "total_fractional_descent") => Some(FieldDef {
+            number: 200,
+            name: "total_fractional_descent",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("session", "avg_core_temperature") => Some(FieldDef {
+            number: 208,
+            name: "avg_core_temperature",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("session", "min_core_temperature") => Some(FieldDef {
+            number: 209,
+            name: "min_core_temperature",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("session", "max_core_temperature") => Some(FieldDef {
+            number: 210,
+            name: "max_core_temperature",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("lap", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("lap", "event") => Some(FieldDef {
+            number: 0,
+            name: "event",
+            field_ty: "event",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "event_type") => Some(FieldDef {
+            number: 1,
+            name: "event_type",
+            field_ty: "event_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "start_time") => Some(FieldDef {
+            number: 2,
+            name: "start_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "start_position_lat") => Some(FieldDef {
+            number: 3,
+            name: "start_position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("lap", "start_position_long") => Some(FieldDef {
+            number: 4,
+            name: "start_position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("lap", "end_position_lat") => Some(FieldDef {
+            number: 5,
+            name: "end_position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("lap", "end_position_long") => Some(FieldDef {
+            number: 6,
+            name: "end_position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("lap", "total_elapsed_time") => Some(FieldDef {
+            number: 7,
+            name: "total_elapsed_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("lap", "total_timer_time") => Some(FieldDef {
+            number: 8,
+            name: "total_timer_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("lap", "total_distance") => Some(FieldDef {
+            number: 9,
+            name: "total_distance",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("lap", "total_cycles") => Some(FieldDef {
+            number: 10,
+            name: "total_cycles",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "cycles",
+        }),
+        ("lap", "total_calories") => Some(FieldDef {
+            number: 11,
+            name: "total_calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("lap", "total_fat_calories") => Some(FieldDef {
+            number: 12,
+            name: "total_fat_calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("lap", "avg_speed") => Some(FieldDef {
+            number: 13,
+            name: "avg_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("lap", "max_speed") => Some(FieldDef {
+            number: 14,
+            name: "max_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("lap", "avg_heart_rate") => Some(FieldDef {
+            number: 15,
+            name: "avg_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("lap", "max_heart_rate") => Some(FieldDef {
+            number: 16,
+            name: "max_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("lap", "avg_cadence") => Some(FieldDef {
+            number: 17,
+            name: "avg_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("lap", "max_cadence") => Some(FieldDef {
+            number: 18,
+            name: "max_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("lap", "avg_power") => Some(FieldDef {
+            number: 19,
+            name: "avg_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("lap", "max_power") => Some(FieldDef {
+            number: 20,
+            name: "max_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("lap", "total_ascent") => Some(FieldDef {
+            number: 21,
+            name: "total_ascent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("lap", "total_descent") => Some(FieldDef {
+            number: 22,
+            name: "total_descent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("lap", "intensity") => Some(FieldDef {
+            number: 23,
+            name: "intensity",
+            field_ty: "intensity",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "lap_trigger") => Some(FieldDef {
+            number: 24,
+            name: "lap_trigger",
+            field_ty: "lap_trigger",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "sport") => Some(FieldDef {
+            number: 25,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "event_group") => Some(FieldDef {
+            number: 26,
+            name: "event_group",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "num_lengths") => Some(FieldDef {
+            number: 32,
+            name: "num_lengths",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "lengths",
+        }),
+        ("lap", "normalized_power") => Some(FieldDef {
+            number: 33,
+            name: "normalized_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("lap", "left_right_balance") => Some(FieldDef {
+            number: 34,
+            name: "left_right_balance",
+            field_ty: "left_right_balance_100",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "first_length_index") => Some(FieldDef {
+            number: 35,
+            name: "first_length_index",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "avg_stroke_distance") => Some(FieldDef {
+            number: 37,
+            name: "avg_stroke_distance",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("lap", "swim_stroke") => Some(FieldDef {
+            number: 38,
+            name: "swim_stroke",
+            field_ty: "swim_stroke",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "sub_sport") => Some(FieldDef {
+            number: 39,
+            name: "sub_sport",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "num_active_lengths") => Some(FieldDef {
+            number: 40,
+            name: "num_active_lengths",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "lengths",
+        }),
+        ("lap", "total_work") => Some(FieldDef {
+            number: 41,
+            name: "total_work",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "J",
+        }),
+        ("lap", "avg_altitude") => Some(FieldDef {
+            number: 42,
+            name: "avg_altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("lap", "max_altitude") => Some(FieldDef {
+            number: 43,
+            name: "max_altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("lap", "gps_accuracy") => Some(FieldDef {
+            number: 44,
+            name: "gps_accuracy",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("lap", "avg_grade") => Some(FieldDef {
+            number: 45,
+            name: "avg_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("lap", "avg_pos_grade") => Some(FieldDef {
+            number: 46,
+            name: "avg_pos_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("lap", "avg_neg_grade") => Some(FieldDef {
+            number: 47,
+            name: "avg_neg_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("lap", "max_pos_grade") => Some(FieldDef {
+            number: 48,
+            name: "max_pos_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("lap", "max_neg_grade") => Some(FieldDef {
+            number: 49,
+            name: "max_neg_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("lap", "avg_temperature") => Some(FieldDef {
+            number: 50,
+            name: "avg_temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("lap", "max_temperature") => Some(FieldDef {
+            number: 51,
+            name: "max_temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("lap", "total_moving_time") => Some(FieldDef {
+            number: 52,
+            name: "total_moving_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("lap", "avg_pos_vertical_speed") => Some(FieldDef {
+            number: 53,
+            name: "avg_pos_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("lap", "avg_neg_vertical_speed") => Some(FieldDef {
+            number: 54,
+            name: "avg_neg_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("lap", "max_pos_vertical_speed") => Some(FieldDef {
+            number: 55,
+            name: "max_pos_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("lap", "max_neg_vertical_speed") => Some(FieldDef {
+            number: 56,
+            name: "max_neg_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("lap", "time_in_hr_zone") => Some(FieldDef {
+            number: 57,
+            name: "time_in_hr_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("lap", "time_in_speed_zone") => Some(FieldDef {
+            number: 58,
+            name: "time_in_speed_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("lap", "time_in_cadence_zone") => Some(FieldDef {
+            number: 59,
+            name: "time_in_cadence_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("lap", "time_in_power_zone") => Some(FieldDef {
+            number: 60,
+            name: "time_in_power_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("lap", "repetition_num") => Some(FieldDef {
+            number: 61,
+            name: "repetition_num",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "min_altitude") => Some(FieldDef {
+            number: 62,
+            name: "min_altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("lap", "min_heart_rate") => Some(FieldDef {
+            number: 63,
+            name: "min_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("lap", "wkt_step_index") => Some(FieldDef {
+            number: 71,
+            name: "wkt_step_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "opponent_score") => Some(FieldDef {
+            number: 74,
+            name: "opponent_score",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "stroke_count") => Some(FieldDef {
+            number: 75,
+            name: "stroke_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("lap", "zone_count") => Some(FieldDef {
+            number: 76,
+            name: "zone_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("lap", "avg_vertical_oscillation") => Some(FieldDef {
+            number: 77,
+            name: "avg_vertical_oscillation",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("lap", "avg_stance_time_percent") => Some(FieldDef {
+            number: 78,
+            name: "avg_stance_time_percent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("lap", "avg_stance_time") => Some(FieldDef {
+            number: 79,
+            name: "avg_stance_time",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("lap", "avg_fractional_cadence") => Some(FieldDef {
+            number: 80,
+            name: "avg_fractional_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("lap", "max_fractional_cadence") => Some(FieldDef {
+            number: 81,
+            name: "max_fractional_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("lap", "total_fractional_cycles") => Some(FieldDef {
+            number: 82,
+            name: "total_fractional_cycles",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "cycles",
+        }),
+        ("lap", "player_score") => Some(FieldDef {
+            number: 83,
+            name: "player_score",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "avg_total_hemoglobin_conc") => Some(FieldDef {
+            number: 84,
+            name: "avg_total_hemoglobin_conc",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "g/dL",
+        }),
+        ("lap", "min_total_hemoglobin_conc") => Some(FieldDef {
+            number: 85,
+            name: "min_total_hemoglobin_conc",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "g/dL",
+        }),
+        ("lap", "max_total_hemoglobin_conc") => Some(FieldDef {
+            number: 86,
+            name: "max_total_hemoglobin_conc",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "g/dL",
+        }),
+        ("lap", "avg_saturated_hemoglobin_percent") => Some(FieldDef {
+            number: 87,
+            name: "avg_saturated_hemoglobin_percent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("lap", "min_saturated_hemoglobin_percent") => Some(FieldDef {
+            number: 88,
+            name: "min_saturated_hemoglobin_percent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("lap", "max_saturated_hemoglobin_percent") => Some(FieldDef {
+            number: 89,
+            name: "max_saturated_hemoglobin_percent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("lap", "avg_left_torque_effectiveness") => Some(FieldDef {
+            number: 91,
+            name: "avg_left_torque_effectiveness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("lap", "avg_right_torque_effectiveness") => Some(FieldDef {
+            number: 92,
+            name: "avg_right_torque_effectiveness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("lap", "avg_left_pedal_smoothness") => Some(FieldDef {
+            number: 93,
+            name: "avg_left_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("lap", "avg_right_pedal_smoothness") => Some(FieldDef {
+            number: 94,
+            name: "avg_right_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("lap", "avg_combined_pedal_smoothness") => Some(FieldDef {
+            number: 95,
+            name: "avg_combined_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("lap", "time_standing") => Some(FieldDef {
+            number: 98,
+            name: "time_standing",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("lap", "stand_count") => Some(FieldDef {
+            number: 99,
+            name: "stand_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "avg_left_pco") => Some(FieldDef {
+            number: 100,
+            name: "avg_left_pco",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("lap", "avg_right_pco") => Some(FieldDef {
+            number: 101,
+            name: "avg_right_pco",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("lap", "avg_left_power_phase") => Some(FieldDef {
+            number: 102,
+            name: "avg_left_power_phase",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("lap", "avg_left_power_phase_peak") => Some(FieldDef {
+            number: 103,
+            name: "avg_left_power_phase_peak",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("lap", "avg_right_power_phase") => Some(FieldDef {
+            number: 104,
+            name: "avg_right_power_phase",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("lap", "avg_right_power_phase_peak") => Some(FieldDef {
+            number: 105,
+            name: "avg_right_power_phase_peak",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("lap", "avg_power_position") => Some(FieldDef {
+            number: 106,
+            name: "avg_power_position",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("lap", "max_power_position") => Some(FieldDef {
+            number: 107,
+            name: "max_power_position",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("lap", "avg_cadence_position") => Some(FieldDef {
+            number: 108,
+            name: "avg_cadence_position",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("lap", "max_cadence_position") => Some(FieldDef {
+            number: 109,
+            name: "max_cadence_position",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("lap", "enhanced_avg_speed") => Some(FieldDef {
+            number: 110,
+            name: "enhanced_avg_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("lap", "enhanced_max_speed") => Some(FieldDef {
+            number: 111,
+            name: "enhanced_max_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("lap", "enhanced_avg_altitude") => Some(FieldDef {
+            number: 112,
+            name: "enhanced_avg_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("lap", "enhanced_min_altitude") => Some(FieldDef {
+            number: 113,
+            name: "enhanced_min_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("lap", "enhanced_max_altitude") => Some(FieldDef {
+            number: 114,
+            name: "enhanced_max_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("lap", "avg_lev_motor_power") => Some(FieldDef {
+            number: 115,
+            name: "avg_lev_motor_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("lap", "max_lev_motor_power") => Some(FieldDef {
+            number: 116,
+            name: "max_lev_motor_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("lap", "lev_battery_consumption") => Some(FieldDef {
+            number: 117,
+            name: "lev_battery_consumption",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("lap", "avg_vertical_ratio") => Some(FieldDef {
+            number: 118,
+            name: "avg_vertical_ratio",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("lap", "avg_stance_time_balance") => Some(FieldDef {
+            number: 119,
+            name: "avg_stance_time_balance",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("lap", "avg_step_length") => Some(FieldDef {
+            number: 120,
+            name: "avg_step_length",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("lap", "avg_vam") => Some(FieldDef {
+            number: 121,
+            name: "avg_vam",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("lap", "avg_depth") => Some(FieldDef {
+            number: 122,
+            name: "avg_depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("lap", "max_depth") => Some(FieldDef {
+            number: 123,
+            name: "max_depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("lap", "min_temperature") => Some(FieldDef {
+            number: 124,
+            name: "min_temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("lap", "enhanced_avg_respiration_rate") => Some(FieldDef {
+            number: 136,
+            name: "enhanced_avg_respiration_rate",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "Breaths/min",
+        }),
+        ("lap", "enhanced_max_respiration_rate") => Some(FieldDef {
+            number: 137,
+            name: "enhanced_max_respiration_rate",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "Breaths/min",
+        }),
+        ("lap", "avg_respiration_rate") => Some(FieldDef {
+            number: 147,
+            name: "avg_respiration_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "max_respiration_rate") => Some(FieldDef {
+            number: 148,
+            name: "max_respiration_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "total_grit") => Some(FieldDef {
+            number: 149,
+            name: "total_grit",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kGrit",
+        }),
+        ("lap", "total_flow") => Some(FieldDef {
+            number: 150,
+            name: "total_flow",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "Flow",
+        }),
+        ("lap", "jump_count") => Some(FieldDef {
+            number: 151,
+            name: "jump_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("lap", "avg_grit") => Some(FieldDef {
+            number: 153,
+            name: "avg_grit",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kGrit",
+        }),
+        ("lap", "avg_flow") => Some(FieldDef {
+            number: 154,
+            name: "avg_flow",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "Flow",
+        }),
+        ("lap", "total_fractional_ascent") => Some(FieldDef {
+            number: 156,
+            name: "total_fractional_ascent",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("lap", "total_fractional_descent") => Some(FieldDef {
+            number: 157,
+            name: "total_fractional_descent",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("lap", "avg_core_temperature") => Some(FieldDef {
+            number: 158,
+            name: "avg_core_temperature",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("lap", "min_core_temperature") => Some(FieldDef {
+            number: 159,
+            name: "min_core_temperature",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("lap", "max_core_temperature") => Some(FieldDef {
+            number: 160,
+            name: "max_core_temperature",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("length", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("length", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("length", "event") => Some(FieldDef {
+            number: 0,
+            name: "event",
+            field_ty: "event",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("length", "event_type") => Some(FieldDef {
+            number: 1,
+            name: "event_type",
+            field_ty: "event_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("length", "start_time") => Some(FieldDef {
+            number: 2,
+            name: "start_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("length", "total_elapsed_time") => Some(FieldDef {
+            number: 3,
+            name: "total_elapsed_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("length", "total_timer_time") => Some(FieldDef {
+            number: 4,
+            name: "total_timer_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("length", "total_strokes") => Some(FieldDef {
+            number: 5,
+            name: "total_strokes",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "strokes",
+        }),
+        ("length", "avg_speed") => Some(FieldDef {
+            number: 6,
+            name: "avg_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("length", "swim_stroke") => Some(FieldDef {
+            number: 7,
+            name: "swim_stroke",
+            field_ty: "swim_stroke",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "swim_stroke",
+        }),
+        ("length", "avg_swimming_cadence") => Some(FieldDef {
+            number: 9,
+            name: "avg_swimming_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "strokes/min",
+        }),
+        ("length", "event_group") => Some(FieldDef {
+            number: 10,
+            name: "event_group",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("length", "total_calories") => Some(FieldDef {
+            number: 11,
+            name: "total_calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("length", "length_type") => Some(FieldDef {
+            number: 12,
+            name: "length_type",
+            field_ty: "length_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("length", "player_score") => Some(FieldDef {
+            number: 18,
+            name: "player_score",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("length", "opponent_score") => Some(FieldDef {
+            number: 19,
+            name: "opponent_score",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("length", "stroke_count") => Some(FieldDef {
+            number: 20,
+            name: "stroke_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("length", "zone_count") => Some(FieldDef {
+            number: 21,
+            name: "zone_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("length", "enhanced_avg_respiration_rate") => Some(FieldDef {
+            number: 22,
+            name: "enhanced_avg_respiration_rate",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "Breaths/min",
+        }),
+        ("length", "enhanced_max_respiration_rate") => Some(FieldDef {
+            number: 23,
+            name: "enhanced_max_respiration_rate",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "Breaths/min",
+        }),
+        ("length", "avg_respiration_rate") => Some(FieldDef {
+            number: 24,
+            name: "avg_respiration_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("length", "max_respiration_rate") => Some(FieldDef {
+            number: 25,
+            name: "max_respiration_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("record", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("record", "position_lat") => Some(FieldDef {
+            number: 0,
+            name: "position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("record", "position_long") => Some(FieldDef {
+            number: 1,
+            name: "position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("record", "altitude") => Some(FieldDef {
+            number: 2,
+            name: "altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("record", "heart_rate") => Some(FieldDef {
+            number: 3,
+            name: "heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("record", "cadence") => Some(FieldDef {
+            number: 4,
+            name: "cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("record", "distance") => Some(FieldDef {
+            number: 5,
+            name: "distance",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("record", "speed") => Some(FieldDef {
+            number: 6,
+            name: "speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("record", "power") => Some(FieldDef {
+            number: 7,
+            name: "power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("record", "compressed_speed_distance") => Some(FieldDef {
+            number: 8,
+            name: "compressed_speed_distance",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m/s,m",
+        }),
+        ("record", "grade") => Some(FieldDef {
+            number: 9,
+            name: "grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("record", "resistance") => Some(FieldDef {
+            number: 10,
+            name: "resistance",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("record", "time_from_course") => Some(FieldDef {
+            number: 11,
+            name: "time_from_course",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("record", "cycle_length") => Some(FieldDef {
+            number: 12,
+            name: "cycle_length",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("record", "temperature") => Some(FieldDef {
+            number: 13,
+            name: "temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("record", "speed_1s") => Some(FieldDef {
+            number: 17,
+            name: "speed_1s",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 16.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("record", "cycles") => Some(FieldDef {
+            number: 18,
+            name: "cycles",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "cycles",
+        }),
+        ("record", "total_cycles") => Some(FieldDef {
+            number: 19,
+            name: "total_cycles",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "cycles",
+        }),
+        ("record", "compressed_accumulated_power") => Some(FieldDef {
+            number: 28,
+            name: "compressed_accumulated_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("record", "accumulated_power") => Some(FieldDef {
+            number: 29,
+            name: "accumulated_power",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("record", "left_right_balance") => Some(FieldDef {
+            number: 30,
+            name: "left_right_balance",
+            field_ty: "left_right_balance",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("record", "gps_accuracy") => Some(FieldDef {
+            number: 31,
+            name: "gps_accuracy",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("record", "vertical_speed") => Some(FieldDef {
+            number: 32,
+            name: "vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("record", "calories") => Some(FieldDef {
+            number: 33,
+            name: "calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("record", "vertical_oscillation") => Some(FieldDef {
+            number: 39,
+            name: "vertical_oscillation",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("record", "stance_time_percent") => Some(FieldDef {
+            number: 40,
+            name: "stance_time_percent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "stance_time") => Some(FieldDef {
+            number: 41,
+            name: "stance_time",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("record", "activity_type") => Some(FieldDef {
+            number: 42,
+            name: "activity_type",
+            field_ty: "activity_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("record", "left_torque_effectiveness") => Some(FieldDef {
+            number: 43,
+            name: "left_torque_effectiveness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "right_torque_effectiveness") => Some(FieldDef {
+            number: 44,
+            name: "right_torque_effectiveness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "left_pedal_smoothness") => Some(FieldDef {
+            number: 45,
+            name: "left_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "right_pedal_smoothness") => Some(FieldDef {
+            number: 46,
+            name: "right_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "combined_pedal_smoothness") => Some(FieldDef {
+            number: 47,
+            name: "combined_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "time128") => Some(FieldDef {
+            number: 48,
+            name: "time128",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("record", "stroke_type") => Some(FieldDef {
+            number: 49,
+            name: "stroke_type",
+            field_ty: "stroke_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("record", "zone") => Some(FieldDef {
+            number: 50,
+            name: "zone",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("record", "ball_speed") => Some(FieldDef {
+            number: 51,
+            name: "ball_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("record", "cadence256") => Some(FieldDef {
+            number: 52,
+            name: "cadence256",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 256.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("record", "fractional_cadence") => Some(FieldDef {
+            number: 53,
+            name: "fractional_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("record", "total_hemoglobin_conc") => Some(FieldDef {
+            number: 54,
+            name: "total_hemoglobin_conc",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "g/dL",
+        }),
+        ("record", "total_hemoglobin_conc_min") => Some(FieldDef {
+            number: 55,
+            name: "total_hemoglobin_conc_min",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "g/dL",
+        }),
+        ("record", "total_hemoglobin_conc_max") => Some(FieldDef {
+            number: 56,
+            name: "total_hemoglobin_conc_max",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "g/dL",
+        }),
+        ("record", "saturated_hemoglobin_percent") => Some(FieldDef {
+            number: 57,
+            name: "saturated_hemoglobin_percent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("record", "saturated_hemoglobin_percent_min") => Some(FieldDef {
+            number: 58,
+            name: "saturated_hemoglobin_percent_min",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("record", "saturated_hemoglobin_percent_max") => Some(FieldDef {
+            number: 59,
+            name: "saturated_hemoglobin_percent_max",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("record", "device_index") => Some(FieldDef {
+            number: 62,
+            name: "device_index",
+            field_ty: "device_index",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("record", "left_pco") => Some(FieldDef {
+            number: 67,
+            name: "left_pco",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("record", "right_pco") => Some(FieldDef {
+            number: 68,
+            name: "right_pco",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("record", "left_power_phase") => Some(FieldDef {
+            number: 69,
+            name: "left_power_phase",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("record", "left_power_phase_peak") => Some(FieldDef {
+            number: 70,
+            name: "left_power_phase_peak",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("record", "right_power_phase") => Some(FieldDef {
+            number: 71,
+            name: "right_power_phase",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("record", "right_power_phase_peak") => Some(FieldDef {
+            number: 72,
+            name: "right_power_phase_peak",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("record", "enhanced_speed") => Some(FieldDef {
+            number: 73,
+            name: "enhanced_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("record", "enhanced_altitude") => Some(FieldDef {
+            number: 78,
+            name: "enhanced_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("record", "battery_soc") => Some(FieldDef {
+            number: 81,
+            name: "battery_soc",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "motor_power") => Some(FieldDef {
+            number: 82,
+            name: "motor_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("record", "vertical_ratio") => Some(FieldDef {
+            number: 83,
+            name: "vertical_ratio",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "stance_time_balance") => Some(FieldDef {
+            number: 84,
+            name: "stance_time_balance",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "step_length") => Some(FieldDef {
+            number: 85,
+            name: "step_length",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("record", "cycle_length16") => Some(FieldDef {
+            number: 87,
+            name: "cycle_length16",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("record", "absolute_pressure") => Some(FieldDef {
+            number: 91,
+            name: "absolute_pressure",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "Pa",
+        }),
+        ("record", "depth") => Some(FieldDef {
+            number: 92,
+            name: "depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("record", "next_stop_depth") => Some(FieldDef {
+            number: 93,
+            name: "next_stop_depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("record", "next_stop_time") => Some(FieldDef {
+            number: 94,
+            name: "next_stop_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("record", "time_to_surface") => Some(FieldDef {
+            number: 95,
+            name: "time_to_surface",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("record", "ndl_time") => Some(FieldDef {
+            number: 96,
+            name: "ndl_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("record", "cns_load") => Some(FieldDef {
+            number: 97,
+            name: "cns_load",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "n2_load") => Some(FieldDef {
+            number: 98,
+            name: "n2_load",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "respiration_rate") => Some(FieldDef {
+            number: 99,
+            name: "respiration_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("record", "enhanced_respiration_rate") => Some(FieldDef {
+            number: 108,
+            name: "enhanced_respiration_rate",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "Breaths/min",
+        }),
+        ("record", "grit") => Some(FieldDef {
+            number: 114,
+            name: "grit",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("record", "flow") => Some(FieldDef {
+            number: 115,
+            name: "flow",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("record", "current_stress") => Some(FieldDef {
+            number: 116,
+            name: "current_stress",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("record", "ebike_travel_range") => Some(FieldDef {
+            number: 117,
+            name: "ebike_travel_range",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "km",
+        }),
+        ("record", "ebike_battery_level") => Some(FieldDef {
+            number: 118,
+            name: "ebike_battery_level",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "ebike_assist_mode") => Some(FieldDef {
+            number: 119,
+            name: "ebike_assist_mode",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "depends on sensor",
+        }),
+        ("record", "ebike_assist_level_percent") => Some(FieldDef {
+            number: 120,
+            name: "ebike_assist_level_percent",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "air_time_remaining") => Some(FieldDef {
+            number: 123,
+            name: "air_time_remaining",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("record", "pressure_sac") => Some(FieldDef {
+            number: 124,
+            name: "pressure_sac",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "bar/min",
+        }),
+        ("record", "volume_sac") => Some(FieldDef {
+            number: 125,
+            name: "volume_sac",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "L/min",
+        }),
+        ("record", "rmv") => Some(FieldDef {
+            number: 126,
+            name: "rmv",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "L/min",
+        }),
+        ("record", "ascent_rate") => Some(FieldDef {
+            number: 127,
+            name: "ascent_rate",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("record", "po2") => Some(FieldDef {
+            number: 129,
+            name: "po2",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("record", "core_temperature") => Some(FieldDef {
+            number: 139,
+            name: "core_temperature",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("event", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("event", "event") => Some(FieldDef {
+            number: 0,
+            name: "event",
+            field_ty: "event",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "event_type") => Some(FieldDef {
+            number: 1,
+            name: "event_type",
+            field_ty: "event_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "data16") => Some(FieldDef {
+            number: 2,
+            name: "data16",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "data") => Some(FieldDef {
+            number: 3,
+            name: "data",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "event_group") => Some(FieldDef {
+            number: 4,
+            name: "event_group",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "score") => Some(FieldDef {
+            number: 7,
+            name: "score",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "opponent_score") => Some(FieldDef {
+            number: 8,
+            name: "opponent_score",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "front_gear_num") => Some(FieldDef {
+            number: 9,
+            name: "front_gear_num",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "front_gear") => Some(FieldDef {
+            number: 10,
+            name: "front_gear",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "rear_gear_num") => Some(FieldDef {
+            number: 11,
+            name: "rear_gear_num",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "rear_gear") => Some(FieldDef {
+            number: 12,
+            name: "rear_gear",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "device_index") => Some(FieldDef {
+            number: 13,
+            name: "device_index",
+            field_ty: "device_index",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "activity_type") => Some(FieldDef {
+            number: 14,
+            name: "activity_type",
+            field_ty: "activity_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "start_timestamp") => Some(FieldDef {
+            number: 15,
+            name: "start_timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("event", "radar_threat_level_max") => Some(FieldDef {
+            number: 21,
+            name: "radar_threat_level_max",
+            field_ty: "radar_threat_level_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "radar_threat_count") => Some(FieldDef {
+            number: 22,
+            name: "radar_threat_count",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("event", "radar_threat_avg_approach_speed") => Some(FieldDef {
+            number: 23,
+            name: "radar_threat_avg_approach_speed",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 10.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("event", "radar_threat_max_approach_speed") => Some(FieldDef {
+            number: 24,
+            name: "radar_threat_max_approach_speed",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 10.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("device_info", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("device_info", "device_index") => Some(FieldDef {
+            number: 0,
+            name: "device_index",
+            field_ty: "device_index",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "device_type") => Some(FieldDef {
+            number: 1,
+            name: "device_type",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "manufacturer") => Some(FieldDef {
+            number: 2,
+            name: "manufacturer",
+            field_ty: "manufacturer",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "serial_number") => Some(FieldDef {
+            number: 3,
+            name: "serial_number",
+            field_ty: "uint32z",
+            base_type: fit::BaseType::UInt32z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "product") => Some(FieldDef {
+            number: 4,
+            name: "product",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "software_version") => Some(FieldDef {
+            number: 5,
+            name: "software_version",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "hardware_version") => Some(FieldDef {
+            number: 6,
+            name: "hardware_version",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "cum_operating_time") => Some(FieldDef {
+            number: 7,
+            name: "cum_operating_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("device_info", "battery_voltage") => Some(FieldDef {
+            number: 10,
+            name: "battery_voltage",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 256.0,
+            offset: 0.0,
+            units: "V",
+        }),
+        ("device_info", "battery_status") => Some(FieldDef {
+            number: 11,
+            name: "battery_status",
+            field_ty: "battery_status",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "sensor_position") => Some(FieldDef {
+            number: 18,
+            name: "sensor_position",
+            field_ty: "body_location",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "descriptor") => Some(FieldDef {
+            number: 19,
+            name: "descriptor",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "ant_transmission_type") => Some(FieldDef {
+            number: 20,
+            name: "ant_transmission_type",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "ant_device_number") => Some(FieldDef {
+            number: 21,
+            name: "ant_device_number",
+            field_ty: "uint16z",
+            base_type: fit::BaseType::UInt16z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "ant_network") => Some(FieldDef {
+            number: 22,
+            name: "ant_network",
+            field_ty: "ant_network",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "source_type") => Some(FieldDef {
+            number: 25,
+            name: "source_type",
+            field_ty: "source_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "product_name") => Some(FieldDef {
+            number: 27,
+            name: "product_name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_info", "battery_level") => Some(FieldDef {
+            number: 32,
+            name: "battery_level",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("device_aux_battery_info", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_aux_battery_info", "device_index") => Some(FieldDef {
+            number: 0,
+            name: "device_index",
+            field_ty: "device_index",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_aux_battery_info", "battery_voltage") => Some(FieldDef {
+            number: 1,
+            name: "battery_voltage",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 256.0,
+            offset: 0.0,
+            units: "V",
+        }),
+        ("device_aux_battery_info", "battery_status") => Some(FieldDef {
+            number: 2,
+            name: "battery_status",
+            field_ty: "battery_status",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("device_aux_battery_info", "battery_identifier") => Some(FieldDef {
+            number: 3,
+            name: "battery_identifier",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("training_file", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("training_file", "type") => Some(FieldDef {
+            number: 0,
+            name: "type",
+            field_ty: "file",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("training_file", "manufacturer") => Some(FieldDef {
+            number: 1,
+            name: "manufacturer",
+            field_ty: "manufacturer",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("training_file", "product") => Some(FieldDef {
+            number: 2,
+            name: "product",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("training_file", "serial_number") => Some(FieldDef {
+            number: 3,
+            name: "serial_number",
+            field_ty: "uint32z",
+            base_type: fit::BaseType::UInt32z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("training_file", "time_created") => Some(FieldDef {
+            number: 4,
+            name: "time_created",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_conditions", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_conditions", "weather_report") => Some(FieldDef {
+            number: 0,
+            name: "weather_report",
+            field_ty: "weather_report",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_conditions", "temperature") => Some(FieldDef {
+            number: 1,
+            name: "temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("weather_conditions", "condition") => Some(FieldDef {
+            number: 2,
+            name: "condition",
+            field_ty: "weather_status",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_conditions", "wind_direction") => Some(FieldDef {
+            number: 3,
+            name: "wind_direction",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("weather_conditions", "wind_speed") => Some(FieldDef {
+            number: 4,
+            name: "wind_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("weather_conditions", "precipitation_probability") => Some(FieldDef {
+            number: 5,
+            name: "precipitation_probability",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_conditions", "temperature_feels_like") => Some(FieldDef {
+            number: 6,
+            name: "temperature_feels_like",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("weather_conditions", "relative_humidity") => Some(FieldDef {
+            number: 7,
+            name: "relative_humidity",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_conditions", "location") => Some(FieldDef {
+            number: 8,
+            name: "location",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_conditions", "observed_at_time") => Some(FieldDef {
+            number: 9,
+            name: "observed_at_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_conditions", "observed_location_lat") => Some(FieldDef {
+            number: 10,
+            name: "observed_location_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("weather_conditions", "observed_location_long") => Some(FieldDef {
+            number: 11,
+            name: "observed_location_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("weather_conditions", "day_of_week") => Some(FieldDef {
+            number: 12,
+            name: "day_of_week",
+            field_ty: "day_of_week",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_conditions", "high_temperature") => Some(FieldDef {
+            number: 13,
+            name: "high_temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("weather_conditions", "low_temperature") => Some(FieldDef {
+            number: 14,
+            name: "low_temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("weather_alert", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_alert", "report_id") => Some(FieldDef {
+            number: 0,
+            name: "report_id",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_alert", "issue_time") => Some(FieldDef {
+            number: 1,
+            name: "issue_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_alert", "expire_time") => Some(FieldDef {
+            number: 2,
+            name: "expire_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_alert", "severity") => Some(FieldDef {
+            number: 3,
+            name: "severity",
+            field_ty: "weather_severity",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weather_alert", "type") => Some(FieldDef {
+            number: 4,
+            name: "type",
+            field_ty: "weather_severe_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("gps_metadata", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("gps_metadata", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("gps_metadata", "position_lat") => Some(FieldDef {
+            number: 1,
+            name: "position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("gps_metadata", "position_long") => Some(FieldDef {
+            number: 2,
+            name: "position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("gps_metadata", "enhanced_altitude") => Some(FieldDef {
+            number: 3,
+            name: "enhanced_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("gps_metadata", "enhanced_speed") => Some(FieldDef {
+            number: 4,
+            name: "enhanced_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("gps_metadata", "heading") => Some(FieldDef {
+            number: 5,
+            name: "heading",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("gps_metadata", "utc_timestamp") => Some(FieldDef {
+            number: 6,
+            name: "utc_timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("gps_metadata", "velocity") => Some(FieldDef {
+            number: 7,
+            name: "velocity",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("camera_event", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("camera_event", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("camera_event", "camera_event_type") => Some(FieldDef {
+            number: 1,
+            name: "camera_event_type",
+            field_ty: "camera_event_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("camera_event", "camera_file_uuid") => Some(FieldDef {
+            number: 2,
+            name: "camera_file_uuid",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("camera_event", "camera_orientation") => Some(FieldDef {
+            number: 3,
+            name: "camera_orientation",
+            field_ty: "camera_orientation_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("gyroscope_data", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("gyroscope_data", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("gyroscope_data", "sample_time_offset") => Some(FieldDef {
+            number: 1,
+            name: "sample_time_offset",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("gyroscope_data", "gyro_x") => Some(FieldDef {
+            number: 2,
+            name: "gyro_x",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("gyroscope_data", "gyro_y") => Some(FieldDef {
+            number: 3,
+            name: "gyro_y",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("gyroscope_data", "gyro_z") => Some(FieldDef {
+            number: 4,
+            name: "gyro_z",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("gyroscope_data", "calibrated_gyro_x") => Some(FieldDef {
+            number: 5,
+            name: "calibrated_gyro_x",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "deg/s",
+        }),
+        ("gyroscope_data", "calibrated_gyro_y") => Some(FieldDef {
+            number: 6,
+            name: "calibrated_gyro_y",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "deg/s",
+        }),
+        ("gyroscope_data", "calibrated_gyro_z") => Some(FieldDef {
+            number: 7,
+            name: "calibrated_gyro_z",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "deg/s",
+        }),
+        ("accelerometer_data", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("accelerometer_data", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("accelerometer_data", "sample_time_offset") => Some(FieldDef {
+            number: 1,
+            name: "sample_time_offset",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("accelerometer_data", "accel_x") => Some(FieldDef {
+            number: 2,
+            name: "accel_x",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("accelerometer_data", "accel_y") => Some(FieldDef {
+            number: 3,
+            name: "accel_y",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("accelerometer_data", "accel_z") => Some(FieldDef {
+            number: 4,
+            name: "accel_z",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("accelerometer_data", "calibrated_accel_x") => Some(FieldDef {
+            number: 5,
+            name: "calibrated_accel_x",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "g",
+        }),
+        ("accelerometer_data", "calibrated_accel_y") => Some(FieldDef {
+            number: 6,
+            name: "calibrated_accel_y",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "g",
+        }),
+        ("accelerometer_data", "calibrated_accel_z") => Some(FieldDef {
+            number: 7,
+            name: "calibrated_accel_z",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "g",
+        }),
+        ("accelerometer_data", "compressed_calibrated_accel_x") => Some(FieldDef {
+            number: 8,
+            name: "compressed_calibrated_accel_x",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mG",
+        }),
+        ("accelerometer_data", "compressed_calibrated_accel_y") => Some(FieldDef {
+            number: 9,
+            name: "compressed_calibrated_accel_y",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mG",
+        }),
+        ("accelerometer_data", "compressed_calibrated_accel_z") => Some(FieldDef {
+            number: 10,
+            name: "compressed_calibrated_accel_z",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mG",
+        }),
+        ("magnetometer_data", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("magnetometer_data", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("magnetometer_data", "sample_time_offset") => Some(FieldDef {
+            number: 1,
+            name: "sample_time_offset",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("magnetometer_data", "mag_x") => Some(FieldDef {
+            number: 2,
+            name: "mag_x",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("magnetometer_data", "mag_y") => Some(FieldDef {
+            number: 3,
+            name: "mag_y",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("magnetometer_data", "mag_z") => Some(FieldDef {
+            number: 4,
+            name: "mag_z",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("magnetometer_data", "calibrated_mag_x") => Some(FieldDef {
+            number: 5,
+            name: "calibrated_mag_x",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "G",
+        }),
+        ("magnetometer_data", "calibrated_mag_y") => Some(FieldDef {
+            number: 6,
+            name: "calibrated_mag_y",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "G",
+        }),
+        ("magnetometer_data", "calibrated_mag_z") => Some(FieldDef {
+            number: 7,
+            name: "calibrated_mag_z",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "G",
+        }),
+        ("barometer_data", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("barometer_data", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("barometer_data", "sample_time_offset") => Some(FieldDef {
+            number: 1,
+            name: "sample_time_offset",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("barometer_data", "baro_pres") => Some(FieldDef {
+            number: 2,
+            name: "baro_pres",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "Pa",
+        }),
+        ("three_d_sensor_calibration", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("three_d_sensor_calibration", "sensor_type") => Some(FieldDef {
+            number: 0,
+            name: "sensor_type",
+            field_ty: "sensor_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("three_d_sensor_calibration", "calibration_factor") => Some(FieldDef {
+            number: 1,
+            name: "calibration_factor",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("three_d_sensor_calibration", "calibration_divisor") => Some(FieldDef {
+            number: 2,
+            name: "calibration_divisor",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("three_d_sensor_calibration", "level_shift") => Some(FieldDef {
+            number: 3,
+            name: "level_shift",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("three_d_sensor_calibration", "offset_cal") => Some(FieldDef {
+            number: 4,
+            name: "offset_cal",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("three_d_sensor_calibration", "orientation_matrix") => Some(FieldDef {
+            number: 5,
+            name: "orientation_matrix",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 65535.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("one_d_sensor_calibration", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("one_d_sensor_calibration", "sensor_type") => Some(FieldDef {
+            number: 0,
+            name: "sensor_type",
+            field_ty: "sensor_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("one_d_sensor_calibration", "calibration_factor") => Some(FieldDef {
+            number: 1,
+            name: "calibration_factor",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("one_d_sensor_calibration", "calibration_divisor") => Some(FieldDef {
+            number: 2,
+            name: "calibration_divisor",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "counts",
+        }),
+        ("one_d_sensor_calibration", "level_shift") => Some(FieldDef {
+            number: 3,
+            name: "level_shift",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("one_d_sensor_calibration", "offset_cal") => Some(FieldDef {
+            number: 4,
+            name: "offset_cal",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_frame", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("video_frame", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("video_frame", "frame_number") => Some(FieldDef {
+            number: 1,
+            name: "frame_number",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("obdii_data", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("obdii_data", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("obdii_data", "time_offset") => Some(FieldDef {
+            number: 1,
+            name: "time_offset",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("obdii_data", "pid") => Some(FieldDef {
+            number: 2,
+            name: "pid",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("obdii_data", "raw_data") => Some(FieldDef {
+            number: 3,
+            name: "raw_data",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("obdii_data", "pid_data_size") => Some(FieldDef {
+            number: 4,
+            name: "pid_data_size",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("obdii_data", "system_time") => Some(FieldDef {
+            number: 5,
+            name: "system_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("obdii_data", "start_timestamp") => Some(FieldDef {
+            number: 6,
+            name: "start_timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("obdii_data", "start_timestamp_ms") => Some(FieldDef {
+            number: 7,
+            name: "start_timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("nmea_sentence", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("nmea_sentence", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("nmea_sentence", "sentence") => Some(FieldDef {
+            number: 1,
+            name: "sentence",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("aviation_attitude", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("aviation_attitude", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("aviation_attitude", "system_time") => Some(FieldDef {
+            number: 1,
+            name: "system_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("aviation_attitude", "pitch") => Some(FieldDef {
+            number: 2,
+            name: "pitch",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 10430.38,
+            offset: 0.0,
+            units: "radians",
+        }),
+        ("aviation_attitude", "roll") => Some(FieldDef {
+            number: 3,
+            name: "roll",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 10430.38,
+            offset: 0.0,
+            units: "radians",
+        }),
+        ("aviation_attitude", "accel_lateral") => Some(FieldDef {
+            number: 4,
+            name: "accel_lateral",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m/s^2",
+        }),
+        ("aviation_attitude", "accel_normal") => Some(FieldDef {
+            number: 5,
+            name: "accel_normal",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m/s^2",
+        }),
+        ("aviation_attitude", "turn_rate") => Some(FieldDef {
+            number: 6,
+            name: "turn_rate",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1024.0,
+            offset: 0.0,
+            units: "radians/second",
+        }),
+        ("aviation_attitude", "stage") => Some(FieldDef {
+            number: 7,
+            name: "stage",
+            field_ty: "attitude_stage",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("aviation_attitude", "attitude_stage_complete") => Some(FieldDef {
+            number: 8,
+            name: "attitude_stage_complete",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("aviation_attitude", "track") => Some(FieldDef {
+            number: 9,
+            name: "track",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10430.38,
+            offset: 0.0,
+            units: "radians",
+        }),
+        ("aviation_attitude", "validity") => Some(FieldDef {
+            number: 10,
+            name: "validity",
+            field_ty: "attitude_validity",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video", "url") => Some(FieldDef {
+            number: 0,
+            name: "url",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video", "hosting_provider") => Some(FieldDef {
+            number: 1,
+            name: "hosting_provider",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video", "duration") => Some(FieldDef {
+            number: 2,
+            name: "duration",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("video_title", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_title", "message_count") => Some(FieldDef {
+            number: 0,
+            name: "message_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_title", "text") => Some(FieldDef {
+            number: 1,
+            name: "text",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_description", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_description", "message_count") => Some(FieldDef {
+            number: 0,
+            name: "message_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_description", "text") => Some(FieldDef {
+            number: 1,
+            name: "text",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_clip", "clip_number") => Some(FieldDef {
+            number: 0,
+            name: "clip_number",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_clip", "start_timestamp") => Some(FieldDef {
+            number: 1,
+            name: "start_timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_clip", "start_timestamp_ms") => Some(FieldDef {
+            number: 2,
+            name: "start_timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_clip", "end_timestamp") => Some(FieldDef {
+            number: 3,
+            name: "end_timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_clip", "end_timestamp_ms") => Some(FieldDef {
+            number: 4,
+            name: "end_timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("video_clip", "clip_start") => Some(FieldDef {
+            number: 6,
+            name: "clip_start",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("video_clip", "clip_end") => Some(FieldDef {
+            number: 7,
+            name: "clip_end",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("set", "timestamp") => Some(FieldDef {
+            number: 254,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("set", "duration") => Some(FieldDef {
+            number: 0,
+            name: "duration",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("set", "repetitions") => Some(FieldDef {
+            number: 3,
+            name: "repetitions",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("set", "weight") => Some(FieldDef {
+            number: 4,
+            name: "weight",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 16.0,
+            offset: 0.0,
+            units: "kg",
+        }),
+        ("set", "set_type") => Some(FieldDef {
+            number: 5,
+            name: "set_type",
+            field_ty: "set_type",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("set", "start_time") => Some(FieldDef {
+            number: 6,
+            name: "start_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("set", "category") => Some(FieldDef {
+            number: 7,
+            name: "category",
+            field_ty: "exercise_category",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("set", "category_subtype") => Some(FieldDef {
+            number: 8,
+            name: "category_subtype",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("set", "weight_display_unit") => Some(FieldDef {
+            number: 9,
+            name: "weight_display_unit",
+            field_ty: "fit_base_unit",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("set", "message_index") => Some(FieldDef {
+            number: 10,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("set", "wkt_step_index") => Some(FieldDef {
+            number: 11,
+            name: "wkt_step_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("jump", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("jump", "distance") => Some(FieldDef {
+            number: 0,
+            name: "distance",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("jump", "height") => Some(FieldDef {
+            number: 1,
+            name: "height",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("jump", "rotations") => Some(FieldDef {
+            number: 2,
+            name: "rotations",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("jump", "hang_time") => Some(FieldDef {
+            number: 3,
+            name: "hang_time",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("jump", "score") => Some(FieldDef {
+            number: 4,
+            name: "score",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("jump", "position_lat") => Some(FieldDef {
+            number: 5,
+            name: "position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("jump", "position_long") => Some(FieldDef {
+            number: 6,
+            name: "position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("jump", "speed") => Some(FieldDef {
+            number: 7,
+            name: "speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("jump", "enhanced_speed") => Some(FieldDef {
+            number: 8,
+            name: "enhanced_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("split", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("split", "split_type") => Some(FieldDef {
+            number: 0,
+            name: "split_type",
+            field_ty: "split_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("split", "total_elapsed_time") => Some(FieldDef {
+            number: 1,
+            name: "total_elapsed_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("split", "total_timer_time") => Some(FieldDef {
+            number: 2,
+            name: "total_timer_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("split", "total_distance") => Some(FieldDef {
+            number: 3,
+            name: "total_distance",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("split", "avg_speed") => Some(FieldDef {
+            number: 4,
+            name: "avg_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("split", "start_time") => Some(FieldDef {
+            number: 9,
+            name: "start_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("split", "total_ascent") => Some(FieldDef {
+            number: 13,
+            name: "total_ascent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("split", "total_descent") => Some(FieldDef {
+            number: 14,
+            name: "total_descent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("split", "start_position_lat") => Some(FieldDef {
+            number: 21,
+            name: "start_position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("split", "start_position_long") => Some(FieldDef {
+            number: 22,
+            name: "start_position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("split", "end_position_lat") => Some(FieldDef {
+            number: 23,
+            name: "end_position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("split", "end_position_long") => Some(FieldDef {
+            number: 24,
+            name: "end_position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("split", "max_speed") => Some(FieldDef {
+            number: 25,
+            name: "max_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("split", "avg_vert_speed") => Some(FieldDef {
+            number: 26,
+            name: "avg_vert_speed",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("split", "end_time") => Some(FieldDef {
+            number: 27,
+            name: "end_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("split", "total_calories") => Some(FieldDef {
+            number: 28,
+            name: "total_calories",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("split", "start_elevation") => Some(FieldDef {
+            number: 74,
+            name: "start_elevation",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("split", "total_moving_time") => Some(FieldDef {
+            number: 110,
+            name: "total_moving_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("split_summary", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("split_summary", "split_type") => Some(FieldDef {
+            number: 0,
+            name: "split_type",
+            field_ty: "split_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("split_summary", "num_splits") => Some(FieldDef {
+            number: 3,
+            name: "num_splits",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("split_summary", "total_timer_time") => Some(FieldDef {
+            number: 4,
+            name: "total_timer_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("split_summary", "total_distance") => Some(FieldDef {
+            number: 5,
+            name: "total_distance",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("split_summary", "avg_speed") => Some(FieldDef {
+            number: 6,
+            name: "avg_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("split_summary", "max_speed") => Some(FieldDef {
+            number: 7,
+            name: "max_speed",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("split_summary", "total_ascent") => Some(FieldDef {
+            number: 8,
+            name: "total_ascent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("split_summary", "total_descent") => Some(FieldDef {
+            number: 9,
+            name: "total_descent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("split_summary", "avg_heart_rate") => Some(FieldDef {
+            number: 10,
+            name: "avg_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("split_summary", "max_heart_rate") => Some(FieldDef {
+            number: 11,
+            name: "max_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("split_summary", "avg_vert_speed") => Some(FieldDef {
+            number: 12,
+            name: "avg_vert_speed",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("split_summary", "total_calories") => Some(FieldDef {
+            number: 13,
+            name: "total_calories",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("split_summary", "total_moving_time") => Some(FieldDef {
+            number: 77,
+            name: "total_moving_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("climb_pro", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("climb_pro", "position_lat") => Some(FieldDef {
+            number: 0,
+            name: "position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("climb_pro", "position_long") => Some(FieldDef {
+            number: 1,
+            name: "position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("climb_pro", "climb_pro_event") => Some(FieldDef {
+            number: 2,
+            name: "climb_pro_event",
+            field_ty: "climb_pro_event",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("climb_pro", "climb_number") => Some(FieldDef {
+            number: 3,
+            name: "climb_number",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("climb_pro", "climb_category") => Some(FieldDef {
+            number: 4,
+            name: "climb_category",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("climb_pro", "current_dist") => Some(FieldDef {
+            number: 5,
+            name: "current_dist",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("field_description", "developer_data_index") => Some(FieldDef {
+            number: 0,
+            name: "developer_data_index",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "field_definition_number") => Some(FieldDef {
+            number: 1,
+            name: "field_definition_number",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "fit_base_type_id") => Some(FieldDef {
+            number: 2,
+            name: "fit_base_type_id",
+            field_ty: "fit_base_type",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "field_name") => Some(FieldDef {
+            number: 3,
+            name: "field_name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "array") => Some(FieldDef {
+            number: 4,
+            name: "array",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "components") => Some(FieldDef {
+            number: 5,
+            name: "components",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "scale") => Some(FieldDef {
+            number: 6,
+            name: "scale",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "offset") => Some(FieldDef {
+            number: 7,
+            name: "offset",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "units") => Some(FieldDef {
+            number: 8,
+            name: "units",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "bits") => Some(FieldDef {
+            number: 9,
+            name: "bits",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "accumulate") => Some(FieldDef {
+            number: 10,
+            name: "accumulate",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "fit_base_unit_id") => Some(FieldDef {
+            number: 13,
+            name: "fit_base_unit_id",
+            field_ty: "fit_base_unit",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "native_mesg_num") => Some(FieldDef {
+            number: 14,
+            name: "native_mesg_num",
+            field_ty: "mesg_num",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("field_description", "native_field_num") => Some(FieldDef {
+            number: 15,
+            name: "native_field_num",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("developer_data_id", "developer_id") => Some(FieldDef {
+            number: 0,
+            name: "developer_id",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("developer_data_id", "application_id") => Some(FieldDef {
+            number: 1,
+            name: "application_id",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("developer_data_id", "manufacturer_id") => Some(FieldDef {
+            number: 2,
+            name: "manufacturer_id",
+            field_ty: "manufacturer",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("developer_data_id", "developer_data_index") => Some(FieldDef {
+            number: 3,
+            name: "developer_data_index",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("developer_data_id", "application_version") => Some(FieldDef {
+            number: 4,
+            name: "application_version",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("course", "sport") => Some(FieldDef {
+            number: 4,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("course", "name") => Some(FieldDef {
+            number: 5,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("course", "capabilities") => Some(FieldDef {
+            number: 6,
+            name: "capabilities",
+            field_ty: "course_capabilities",
+            base_type: fit::BaseType::UInt32z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("course", "sub_sport") => Some(FieldDef {
+            number: 7,
+            name: "sub_sport",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("course_point", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("course_point", "timestamp") => Some(FieldDef {
+            number: 1,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("course_point", "position_lat") => Some(FieldDef {
+            number: 2,
+            name: "position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("course_point", "position_long") => Some(FieldDef {
+            number: 3,
+            name: "position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("course_point", "distance") => Some(FieldDef {
+            number: 4,
+            name: "distance",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("course_point", "type") => Some(FieldDef {
+            number: 5,
+            name: "type",
+            field_ty: "course_point",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("course_point", "name") => Some(FieldDef {
+            number: 6,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("course_point", "favorite") => Some(FieldDef {
+            number: 8,
+            name: "favorite",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_id", "name") => Some(FieldDef {
+            number: 0,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_id", "uuid") => Some(FieldDef {
+            number: 1,
+            name: "uuid",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_id", "sport") => Some(FieldDef {
+            number: 2,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_id", "enabled") => Some(FieldDef {
+            number: 3,
+            name: "enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_id", "user_profile_primary_key") => Some(FieldDef {
+            number: 4,
+            name: "user_profile_primary_key",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_id", "device_id") => Some(FieldDef {
+            number: 5,
+            name: "device_id",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_id", "default_race_leader") => Some(FieldDef {
+            number: 6,
+            name: "default_race_leader",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_id", "delete_status") => Some(FieldDef {
+            number: 7,
+            name: "delete_status",
+            field_ty: "segment_delete_status",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_id", "selection_type") => Some(FieldDef {
+            number: 8,
+            name: "selection_type",
+            field_ty: "segment_selection_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_leaderboard_entry", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_leaderboard_entry", "name") => Some(FieldDef {
+            number: 0,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_leaderboard_entry", "type") => Some(FieldDef {
+            number: 1,
+            name: "type",
+            field_ty: "segment_leaderboard_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_leaderboard_entry", "group_primary_key") => Some(FieldDef {
+            number: 2,
+            name: "group_primary_key",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_leaderboard_entry", "activity_id") => Some(FieldDef {
+            number: 3,
+            name: "activity_id",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_leaderboard_entry", "segment_time") => Some(FieldDef {
+            number: 4,
+            name: "segment_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_leaderboard_entry", "activity_id_string") => Some(FieldDef {
+            number: 5,
+            name: "activity_id_string",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_point", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_point", "position_lat") => Some(FieldDef {
+            number: 1,
+            name: "position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("segment_point", "position_long") => Some(FieldDef {
+            number: 2,
+            name: "position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("segment_point", "distance") => Some(FieldDef {
+            number: 3,
+            name: "distance",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("segment_point", "altitude") => Some(FieldDef {
+            number: 4,
+            name: "altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("segment_point", "leader_time") => Some(FieldDef {
+            number: 5,
+            name: "leader_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_point", "enhanced_altitude") => Some(FieldDef {
+            number: 6,
+            name: "enhanced_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("segment_lap", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_lap", "event") => Some(FieldDef {
+            number: 0,
+            name: "event",
+            field_ty: "event",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "event_type") => Some(FieldDef {
+            number: 1,
+            name: "event_type",
+            field_ty: "event_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "start_time") => Some(FieldDef {
+            number: 2,
+            name: "start_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "start_position_lat") => Some(FieldDef {
+            number: 3,
+            name: "start_position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("segment_lap", "start_position_long") => Some(FieldDef {
+            number: 4,
+            name: "start_position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("segment_lap", "end_position_lat") => Some(FieldDef {
+            number: 5,
+            name: "end_position_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("segment_lap", "end_position_long") => Some(FieldDef {
+            number: 6,
+            name: "end_position_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("segment_lap", "total_elapsed_time") => Some(FieldDef {
+            number: 7,
+            name: "total_elapsed_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_lap", "total_timer_time") => Some(FieldDef {
+            number: 8,
+            name: "total_timer_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_lap", "total_distance") => Some(FieldDef {
+            number: 9,
+            name: "total_distance",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("segment_lap", "total_cycles") => Some(FieldDef {
+            number: 10,
+            name: "total_cycles",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "cycles",
+        }),
+        ("segment_lap", "total_calories") => Some(FieldDef {
+            number: 11,
+            name: "total_calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("segment_lap", "total_fat_calories") => Some(FieldDef {
+            number: 12,
+            name: "total_fat_calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("segment_lap", "avg_speed") => Some(FieldDef {
+            number: 13,
+            name: "avg_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("segment_lap", "max_speed") => Some(FieldDef {
+            number: 14,
+            name: "max_speed",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("segment_lap", "avg_heart_rate") => Some(FieldDef {
+            number: 15,
+            name: "avg_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("segment_lap", "max_heart_rate") => Some(FieldDef {
+            number: 16,
+            name: "max_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("segment_lap", "avg_cadence") => Some(FieldDef {
+            number: 17,
+            name: "avg_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("segment_lap", "max_cadence") => Some(FieldDef {
+            number: 18,
+            name: "max_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("segment_lap", "avg_power") => Some(FieldDef {
+            number: 19,
+            name: "avg_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("segment_lap", "max_power") => Some(FieldDef {
+            number: 20,
+            name: "max_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("segment_lap", "total_ascent") => Some(FieldDef {
+            number: 21,
+            name: "total_ascent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("segment_lap", "total_descent") => Some(FieldDef {
+            number: 22,
+            name: "total_descent",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("segment_lap", "sport") => Some(FieldDef {
+            number: 23,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "event_group") => Some(FieldDef {
+            number: 24,
+            name: "event_group",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "nec_lat") => Some(FieldDef {
+            number: 25,
+            name: "nec_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("segment_lap", "nec_long") => Some(FieldDef {
+            number: 26,
+            name: "nec_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("segment_lap", "swc_lat") => Some(FieldDef {
+            number: 27,
+            name: "swc_lat",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("segment_lap", "swc_long") => Some(FieldDef {
+            number: 28,
+            name: "swc_long",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "semicircles",
+        }),
+        ("segment_lap", "name") => Some(FieldDef {
+            number: 29,
+            name: "name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "normalized_power") => Some(FieldDef {
+            number: 30,
+            name: "normalized_power",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("segment_lap", "left_right_balance") => Some(FieldDef {
+            number: 31,
+            name: "left_right_balance",
+            field_ty: "left_right_balance_100",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "sub_sport") => Some(FieldDef {
+            number: 32,
+            name: "sub_sport",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "total_work") => Some(FieldDef {
+            number: 33,
+            name: "total_work",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "J",
+        }),
+        ("segment_lap", "avg_altitude") => Some(FieldDef {
+            number: 34,
+            name: "avg_altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("segment_lap", "max_altitude") => Some(FieldDef {
+            number: 35,
+            name: "max_altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("segment_lap", "gps_accuracy") => Some(FieldDef {
+            number: 36,
+            name: "gps_accuracy",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("segment_lap", "avg_grade") => Some(FieldDef {
+            number: 37,
+            name: "avg_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("segment_lap", "avg_pos_grade") => Some(FieldDef {
+            number: 38,
+            name: "avg_pos_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("segment_lap", "avg_neg_grade") => Some(FieldDef {
+            number: 39,
+            name: "avg_neg_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("segment_lap", "max_pos_grade") => Some(FieldDef {
+            number: 40,
+            name: "max_pos_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("segment_lap", "max_neg_grade") => Some(FieldDef {
+            number: 41,
+            name: "max_neg_grade",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("segment_lap", "avg_temperature") => Some(FieldDef {
+            number: 42,
+            name: "avg_temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("segment_lap", "max_temperature") => Some(FieldDef {
+            number: 43,
+            name: "max_temperature",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("segment_lap", "total_moving_time") => Some(FieldDef {
+            number: 44,
+            name: "total_moving_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_lap", "avg_pos_vertical_speed") => Some(FieldDef {
+            number: 45,
+            name: "avg_pos_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("segment_lap", "avg_neg_vertical_speed") => Some(FieldDef {
+            number: 46,
+            name: "avg_neg_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("segment_lap", "max_pos_vertical_speed") => Some(FieldDef {
+            number: 47,
+            name: "max_pos_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("segment_lap", "max_neg_vertical_speed") => Some(FieldDef {
+            number: 48,
+            name: "max_neg_vertical_speed",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("segment_lap", "time_in_hr_zone") => Some(FieldDef {
+            number: 49,
+            name: "time_in_hr_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_lap", "time_in_speed_zone") => Some(FieldDef {
+            number: 50,
+            name: "time_in_speed_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_lap", "time_in_cadence_zone") => Some(FieldDef {
+            number: 51,
+            name: "time_in_cadence_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_lap", "time_in_power_zone") => Some(FieldDef {
+            number: 52,
+            name: "time_in_power_zone",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_lap", "repetition_num") => Some(FieldDef {
+            number: 53,
+            name: "repetition_num",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "min_altitude") => Some(FieldDef {
+            number: 54,
+            name: "min_altitude",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("segment_lap", "min_heart_rate") => Some(FieldDef {
+            number: 55,
+            name: "min_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("segment_lap", "active_time") => Some(FieldDef {
+            number: 56,
+            name: "active_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_lap", "wkt_step_index") => Some(FieldDef {
+            number: 57,
+            name: "wkt_step_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "sport_event") => Some(FieldDef {
+            number: 58,
+            name: "sport_event",
+            field_ty: "sport_event",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "avg_left_torque_effectiveness") => Some(FieldDef {
+            number: 59,
+            name: "avg_left_torque_effectiveness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("segment_lap", "avg_right_torque_effectiveness") => Some(FieldDef {
+            number: 60,
+            name: "avg_right_torque_effectiveness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("segment_lap", "avg_left_pedal_smoothness") => Some(FieldDef {
+            number: 61,
+            name: "avg_left_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("segment_lap", "avg_right_pedal_smoothness") => Some(FieldDef {
+            number: 62,
+            name: "avg_right_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("segment_lap", "avg_combined_pedal_smoothness") => Some(FieldDef {
+            number: 63,
+            name: "avg_combined_pedal_smoothness",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 2.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("segment_lap", "status") => Some(FieldDef {
+            number: 64,
+            name: "status",
+            field_ty: "segment_lap_status",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "uuid") => Some(FieldDef {
+            number: 65,
+            name: "uuid",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "avg_fractional_cadence") => Some(FieldDef {
+            number: 66,
+            name: "avg_fractional_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("segment_lap", "max_fractional_cadence") => Some(FieldDef {
+            number: 67,
+            name: "max_fractional_cadence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("segment_lap", "total_fractional_cycles") => Some(FieldDef {
+            number: 68,
+            name: "total_fractional_cycles",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 128.0,
+            offset: 0.0,
+            units: "cycles",
+        }),
+        ("segment_lap", "front_gear_shift_count") => Some(FieldDef {
+            number: 69,
+            name: "front_gear_shift_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "rear_gear_shift_count") => Some(FieldDef {
+            number: 70,
+            name: "rear_gear_shift_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "time_standing") => Some(FieldDef {
+            number: 71,
+            name: "time_standing",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("segment_lap", "stand_count") => Some(FieldDef {
+            number: 72,
+            name: "stand_count",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "avg_left_pco") => Some(FieldDef {
+            number: 73,
+            name: "avg_left_pco",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("segment_lap", "avg_right_pco") => Some(FieldDef {
+            number: 74,
+            name: "avg_right_pco",
+            field_ty: "sint8",
+            base_type: fit::BaseType::SInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mm",
+        }),
+        ("segment_lap", "avg_left_power_phase") => Some(FieldDef {
+            number: 75,
+            name: "avg_left_power_phase",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("segment_lap", "avg_left_power_phase_peak") => Some(FieldDef {
+            number: 76,
+            name: "avg_left_power_phase_peak",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("segment_lap", "avg_right_power_phase") => Some(FieldDef {
+            number: 77,
+            name: "avg_right_power_phase",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("segment_lap", "avg_right_power_phase_peak") => Some(FieldDef {
+            number: 78,
+            name: "avg_right_power_phase_peak",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 0.7111111,
+            offset: 0.0,
+            units: "degrees",
+        }),
+        ("segment_lap", "avg_power_position") => Some(FieldDef {
+            number: 79,
+            name: "avg_power_position",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("segment_lap", "max_power_position") => Some(FieldDef {
+            number: 80,
+            name: "max_power_position",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "watts",
+        }),
+        ("segment_lap", "avg_cadence_position") => Some(FieldDef {
+            number: 81,
+            name: "avg_cadence_position",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("segment_lap", "max_cadence_position") => Some(FieldDef {
+            number: 82,
+            name: "max_cadence_position",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "rpm",
+        }),
+        ("segment_lap", "manufacturer") => Some(FieldDef {
+            number: 83,
+            name: "manufacturer",
+            field_ty: "manufacturer",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_lap", "total_grit") => Some(FieldDef {
+            number: 84,
+            name: "total_grit",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kGrit",
+        }),
+        ("segment_lap", "total_flow") => Some(FieldDef {
+            number: 85,
+            name: "total_flow",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "Flow",
+        }),
+        ("segment_lap", "avg_grit") => Some(FieldDef {
+            number: 86,
+            name: "avg_grit",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kGrit",
+        }),
+        ("segment_lap", "avg_flow") => Some(FieldDef {
+            number: 87,
+            name: "avg_flow",
+            field_ty: "float32",
+            base_type: fit::BaseType::Float32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "Flow",
+        }),
+        ("segment_lap", "total_fractional_ascent") => Some(FieldDef {
+            number: 89,
+            name: "total_fractional_ascent",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("segment_lap", "total_fractional_descent") => Some(FieldDef {
+            number: 90,
+            name: "total_fractional_descent",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("segment_lap", "enhanced_avg_altitude") => Some(FieldDef {
+            number: 91,
+            name: "enhanced_avg_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("segment_lap", "enhanced_max_altitude") => Some(FieldDef {
+            number: 92,
+            name: "enhanced_max_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("segment_lap", "enhanced_min_altitude") => Some(FieldDef {
+            number: 93,
+            name: "enhanced_min_altitude",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 5.0,
+            offset: 500.0,
+            units: "m",
+        }),
+        ("segment_file", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_file", "file_uuid") => Some(FieldDef {
+            number: 1,
+            name: "file_uuid",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_file", "enabled") => Some(FieldDef {
+            number: 3,
+            name: "enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_file", "user_profile_primary_key") => Some(FieldDef {
+            number: 4,
+            name: "user_profile_primary_key",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_file", "leader_type") => Some(FieldDef {
+            number: 7,
+            name: "leader_type",
+            field_ty: "segment_leaderboard_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_file", "leader_group_primary_key") => Some(FieldDef {
+            number: 8,
+            name: "leader_group_primary_key",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_file", "leader_activity_id") => Some(FieldDef {
+            number: 9,
+            name: "leader_activity_id",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_file", "leader_activity_id_string") => Some(FieldDef {
+            number: 10,
+            name: "leader_activity_id_string",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("segment_file", "default_race_leader") => Some(FieldDef {
+            number: 11,
+            name: "default_race_leader",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout", "sport") => Some(FieldDef {
+            number: 4,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout", "capabilities") => Some(FieldDef {
+            number: 5,
+            name: "capabilities",
+            field_ty: "workout_capabilities",
+            base_type: fit::BaseType::UInt32z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout", "num_valid_steps") => Some(FieldDef {
+            number: 6,
+            name: "num_valid_steps",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout", "wkt_name") => Some(FieldDef {
+            number: 8,
+            name: "wkt_name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout", "sub_sport") => Some(FieldDef {
+            number: 11,
+            name: "sub_sport",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout", "pool_length") => Some(FieldDef {
+            number: 14,
+            name: "pool_length",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("workout", "pool_length_unit") => Some(FieldDef {
+            number: 15,
+            name: "pool_length_unit",
+            field_ty: "display_measure",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_session", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_session", "sport") => Some(FieldDef {
+            number: 0,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_session", "sub_sport") => Some(FieldDef {
+            number: 1,
+            name: "sub_sport",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_session", "num_valid_steps") => Some(FieldDef {
+            number: 2,
+            name: "num_valid_steps",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_session", "first_step_index") => Some(FieldDef {
+            number: 3,
+            name: "first_step_index",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_session", "pool_length") => Some(FieldDef {
+            number: 4,
+            name: "pool_length",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("workout_session", "pool_length_unit") => Some(FieldDef {
+            number: 5,
+            name: "pool_length_unit",
+            field_ty: "display_measure",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "wkt_step_name") => Some(FieldDef {
+            number: 0,
+            name: "wkt_step_name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "duration_type") => Some(FieldDef {
+            number: 1,
+            name: "duration_type",
+            field_ty: "wkt_step_duration",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "duration_value") => Some(FieldDef {
+            number: 2,
+            name: "duration_value",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "target_type") => Some(FieldDef {
+            number: 3,
+            name: "target_type",
+            field_ty: "wkt_step_target",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "target_value") => Some(FieldDef {
+            number: 4,
+            name: "target_value",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "custom_target_value_low") => Some(FieldDef {
+            number: 5,
+            name: "custom_target_value_low",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "custom_target_value_high") => Some(FieldDef {
+            number: 6,
+            name: "custom_target_value_high",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "intensity") => Some(FieldDef {
+            number: 7,
+            name: "intensity",
+            field_ty: "intensity",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "notes") => Some(FieldDef {
+            number: 8,
+            name: "notes",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "equipment") => Some(FieldDef {
+            number: 9,
+            name: "equipment",
+            field_ty: "workout_equipment",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "exercise_category") => Some(FieldDef {
+            number: 10,
+            name: "exercise_category",
+            field_ty: "exercise_category",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "exercise_name") => Some(FieldDef {
+            number: 11,
+            name: "exercise_name",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "exercise_weight") => Some(FieldDef {
+            number: 12,
+            name: "exercise_weight",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "kg",
+        }),
+        ("workout_step", "weight_display_unit") => Some(FieldDef {
+            number: 13,
+            name: "weight_display_unit",
+            field_ty: "fit_base_unit",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "secondary_target_type") => Some(FieldDef {
+            number: 19,
+            name: "secondary_target_type",
+            field_ty: "wkt_step_target",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "secondary_target_value") => Some(FieldDef {
+            number: 20,
+            name: "secondary_target_value",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "secondary_custom_target_value_low") => Some(FieldDef {
+            number: 21,
+            name: "secondary_custom_target_value_low",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("workout_step", "secondary_custom_target_value_high") => Some(FieldDef {
+            number: 22,
+            name: "secondary_custom_target_value_high",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exercise_title", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exercise_title", "exercise_category") => Some(FieldDef {
+            number: 0,
+            name: "exercise_category",
+            field_ty: "exercise_category",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exercise_title", "exercise_name") => Some(FieldDef {
+            number: 1,
+            name: "exercise_name",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exercise_title", "wkt_step_name") => Some(FieldDef {
+            number: 2,
+            name: "wkt_step_name",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("schedule", "manufacturer") => Some(FieldDef {
+            number: 0,
+            name: "manufacturer",
+            field_ty: "manufacturer",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("schedule", "product") => Some(FieldDef {
+            number: 1,
+            name: "product",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("schedule", "serial_number") => Some(FieldDef {
+            number: 2,
+            name: "serial_number",
+            field_ty: "uint32z",
+            base_type: fit::BaseType::UInt32z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("schedule", "time_created") => Some(FieldDef {
+            number: 3,
+            name: "time_created",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("schedule", "completed") => Some(FieldDef {
+            number: 4,
+            name: "completed",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("schedule", "type") => Some(FieldDef {
+            number: 5,
+            name: "type",
+            field_ty: "schedule",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("schedule", "scheduled_time") => Some(FieldDef {
+            number: 6,
+            name: "scheduled_time",
+            field_ty: "local_date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("totals", "message_index") => Some(FieldDef {
+            number: 254,
+            name: "message_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("totals", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("totals", "timer_time") => Some(FieldDef {
+            number: 0,
+            name: "timer_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("totals", "distance") => Some(FieldDef {
+            number: 1,
+            name: "distance",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("totals", "calories") => Some(FieldDef {
+            number: 2,
+            name: "calories",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("totals", "sport") => Some(FieldDef {
+            number: 3,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("totals", "elapsed_time") => Some(FieldDef {
+            number: 4,
+            name: "elapsed_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("totals", "sessions") => Some(FieldDef {
+            number: 5,
+            name: "sessions",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("totals", "active_time") => Some(FieldDef {
+            number: 6,
+            name: "active_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("totals", "sport_index") => Some(FieldDef {
+            number: 9,
+            name: "sport_index",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weight_scale", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("weight_scale", "weight") => Some(FieldDef {
+            number: 0,
+            name: "weight",
+            field_ty: "weight",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "kg",
+        }),
+        ("weight_scale", "percent_fat") => Some(FieldDef {
+            number: 1,
+            name: "percent_fat",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("weight_scale", "percent_hydration") => Some(FieldDef {
+            number: 2,
+            name: "percent_hydration",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "%",
+        }),
+        ("weight_scale", "visceral_fat_mass") => Some(FieldDef {
+            number: 3,
+            name: "visceral_fat_mass",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "kg",
+        }),
+        ("weight_scale", "bone_mass") => Some(FieldDef {
+            number: 4,
+            name: "bone_mass",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "kg",
+        }),
+        ("weight_scale", "muscle_mass") => Some(FieldDef {
+            number: 5,
+            name: "muscle_mass",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "kg",
+        }),
+        ("weight_scale", "basal_met") => Some(FieldDef {
+            number: 7,
+            name: "basal_met",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 4.0,
+            offset: 0.0,
+            units: "kcal/day",
+        }),
+        ("weight_scale", "physique_rating") => Some(FieldDef {
+            number: 8,
+            name: "physique_rating",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weight_scale", "active_met") => Some(FieldDef {
+            number: 9,
+            name: "active_met",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 4.0,
+            offset: 0.0,
+            units: "kcal/day",
+        }),
+        ("weight_scale", "metabolic_age") => Some(FieldDef {
+            number: 10,
+            name: "metabolic_age",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "years",
+        }),
+        ("weight_scale", "visceral_fat_rating") => Some(FieldDef {
+            number: 11,
+            name: "visceral_fat_rating",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weight_scale", "user_profile_index") => Some(FieldDef {
+            number: 12,
+            name: "user_profile_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("weight_scale", "bmi") => Some(FieldDef {
+            number: 13,
+            name: "bmi",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "kg/m^2",
+        }),
+        ("blood_pressure", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("blood_pressure", "systolic_pressure") => Some(FieldDef {
+            number: 0,
+            name: "systolic_pressure",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mmHg",
+        }),
+        ("blood_pressure", "diastolic_pressure") => Some(FieldDef {
+            number: 1,
+            name: "diastolic_pressure",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mmHg",
+        }),
+        ("blood_pressure", "mean_arterial_pressure") => Some(FieldDef {
+            number: 2,
+            name: "mean_arterial_pressure",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mmHg",
+        }),
+        ("blood_pressure", "map_3_sample_mean") => Some(FieldDef {
+            number: 3,
+            name: "map_3_sample_mean",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mmHg",
+        }),
+        ("blood_pressure", "map_morning_values") => Some(FieldDef {
+            number: 4,
+            name: "map_morning_values",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mmHg",
+        }),
+        ("blood_pressure", "map_evening_values") => Some(FieldDef {
+            number: 5,
+            name: "map_evening_values",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "mmHg",
+        }),
+        ("blood_pressure", "heart_rate") => Some(FieldDef {
+            number: 6,
+            name: "heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("blood_pressure", "heart_rate_type") => Some(FieldDef {
+            number: 7,
+            name: "heart_rate_type",
+            field_ty: "hr_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("blood_pressure", "status") => Some(FieldDef {
+            number: 8,
+            name: "status",
+            field_ty: "bp_status",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("blood_pressure", "user_profile_index") => Some(FieldDef {
+            number: 9,
+            name: "user_profile_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("monitoring_info", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("monitoring_info", "local_timestamp") => Some(FieldDef {
+            number: 0,
+            name: "local_timestamp",
+            field_ty: "local_date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("monitoring_info", "activity_type") => Some(FieldDef {
+            number: 1,
+            name: "activity_type",
+            field_ty: "activity_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("monitoring_info", "cycles_to_distance") => Some(FieldDef {
+            number: 3,
+            name: "cycles_to_distance",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5000.0,
+            offset: 0.0,
+            units: "m/cycle",
+        }),
+        ("monitoring_info", "cycles_to_calories") => Some(FieldDef {
+            number: 4,
+            name: "cycles_to_calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 5000.0,
+            offset: 0.0,
+            units: "kcal/cycle",
+        }),
+        ("monitoring_info", "resting_metabolic_rate") => Some(FieldDef {
+            number: 5,
+            name: "resting_metabolic_rate",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal / day",
+        }),
+        ("monitoring", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("monitoring", "device_index") => Some(FieldDef {
+            number: 0,
+            name: "device_index",
+            field_ty: "device_index",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("monitoring", "calories") => Some(FieldDef {
+            number: 1,
+            name: "calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("monitoring", "distance") => Some(FieldDef {
+            number: 2,
+            name: "distance",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("monitoring", "cycles") => Some(FieldDef {
+            number: 3,
+            name: "cycles",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 2.0,
+            offset: 0.0,
+            units: "cycles",
+        }),
+        ("monitoring", "active_time") => Some(FieldDef {
+            number: 4,
+            name: "active_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("monitoring", "activity_type") => Some(FieldDef {
+            number: 5,
+            name: "activity_type",
+            field_ty: "activity_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("monitoring", "activity_subtype") => Some(FieldDef {
+            number: 6,
+            name: "activity_subtype",
+            field_ty: "activity_subtype",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("monitoring", "activity_level") => Some(FieldDef {
+            number: 7,
+            name: "activity_level",
+            field_ty: "activity_level",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("monitoring", "distance_16") => Some(FieldDef {
+            number: 8,
+            name: "distance_16",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "100 * m",
+        }),
+        ("monitoring", "cycles_16") => Some(FieldDef {
+            number: 9,
+            name: "cycles_16",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "2 * cycles (steps)",
+        }),
+        ("monitoring", "active_time_16") => Some(FieldDef {
+            number: 10,
+            name: "active_time_16",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("monitoring", "local_timestamp") => Some(FieldDef {
+            number: 11,
+            name: "local_timestamp",
+            field_ty: "local_date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("monitoring", "temperature") => Some(FieldDef {
+            number: 12,
+            name: "temperature",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("monitoring", "temperature_min") => Some(FieldDef {
+            number: 14,
+            name: "temperature_min",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("monitoring", "temperature_max") => Some(FieldDef {
+            number: 15,
+            name: "temperature_max",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "C",
+        }),
+        ("monitoring", "activity_time") => Some(FieldDef {
+            number: 16,
+            name: "activity_time",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "minutes",
+        }),
+        ("monitoring", "active_calories") => Some(FieldDef {
+            number: 19,
+            name: "active_calories",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "kcal",
+        }),
+        ("monitoring", "current_activity_type_intensity") => Some(FieldDef {
+            number: 24,
+            name: "current_activity_type_intensity",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("monitoring", "timestamp_min_8") => Some(FieldDef {
+            number: 25,
+            name: "timestamp_min_8",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "min",
+        }),
+        ("monitoring", "timestamp_16") => Some(FieldDef {
+            number: 26,
+            name: "timestamp_16",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("monitoring", "heart_rate") => Some(FieldDef {
+            number: 27,
+            name: "heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("monitoring", "intensity") => Some(FieldDef {
+            number: 28,
+            name: "intensity",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 10.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("monitoring", "duration_min") => Some(FieldDef {
+            number: 29,
+            name: "duration_min",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "min",
+        }),
+        ("monitoring", "duration") => Some(FieldDef {
+            number: 30,
+            name: "duration",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("monitoring", "ascent") => Some(FieldDef {
+            number: 31,
+            name: "ascent",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("monitoring", "descent") => Some(FieldDef {
+            number: 32,
+            name: "descent",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("monitoring", "moderate_activity_minutes") => Some(FieldDef {
+            number: 33,
+            name: "moderate_activity_minutes",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "minutes",
+        }),
+        ("monitoring", "vigorous_activity_minutes") => Some(FieldDef {
+            number: 34,
+            name: "vigorous_activity_minutes",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "minutes",
+        }),
+        ("monitoring_hr_data", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("monitoring_hr_data", "resting_heart_rate") => Some(FieldDef {
+            number: 0,
+            name: "resting_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("monitoring_hr_data", "current_day_resting_heart_rate") => Some(FieldDef {
+            number: 1,
+            name: "current_day_resting_heart_rate",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("spo2_data", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("spo2_data", "reading_spo2") => Some(FieldDef {
+            number: 0,
+            name: "reading_spo2",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("spo2_data", "reading_confidence") => Some(FieldDef {
+            number: 1,
+            name: "reading_confidence",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("spo2_data", "mode") => Some(FieldDef {
+            number: 2,
+            name: "mode",
+            field_ty: "spo2_measurement_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hr", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hr", "fractional_timestamp") => Some(FieldDef {
+            number: 0,
+            name: "fractional_timestamp",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 32768.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("hr", "time256") => Some(FieldDef {
+            number: 1,
+            name: "time256",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 256.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("hr", "filtered_bpm") => Some(FieldDef {
+            number: 6,
+            name: "filtered_bpm",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "bpm",
+        }),
+        ("hr", "event_timestamp") => Some(FieldDef {
+            number: 9,
+            name: "event_timestamp",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1024.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("hr", "event_timestamp_12") => Some(FieldDef {
+            number: 10,
+            name: "event_timestamp_12",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("stress_level", "stress_level_value") => Some(FieldDef {
+            number: 0,
+            name: "stress_level_value",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("stress_level", "stress_level_time") => Some(FieldDef {
+            number: 1,
+            name: "stress_level_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("max_met_data", "update_time") => Some(FieldDef {
+            number: 0,
+            name: "update_time",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("max_met_data", "vo2_max") => Some(FieldDef {
+            number: 2,
+            name: "vo2_max",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 10.0,
+            offset: 0.0,
+            units: "mL/kg/min",
+        }),
+        ("max_met_data", "sport") => Some(FieldDef {
+            number: 5,
+            name: "sport",
+            field_ty: "sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("max_met_data", "sub_sport") => Some(FieldDef {
+            number: 6,
+            name: "sub_sport",
+            field_ty: "sub_sport",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("max_met_data", "max_met_category") => Some(FieldDef {
+            number: 8,
+            name: "max_met_category",
+            field_ty: "max_met_category",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("max_met_data", "calibrated_data") => Some(FieldDef {
+            number: 9,
+            name: "calibrated_data",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("max_met_data", "hr_source") => Some(FieldDef {
+            number: 12,
+            name: "hr_source",
+            field_ty: "max_met_heart_rate_source",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("max_met_data", "speed_source") => Some(FieldDef {
+            number: 13,
+            name: "speed_source",
+            field_ty: "max_met_speed_source",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("memo_glob", "part_index") => Some(FieldDef {
+            number: 250,
+            name: "part_index",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("memo_glob", "memo") => Some(FieldDef {
+            number: 0,
+            name: "memo",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("memo_glob", "mesg_num") => Some(FieldDef {
+            number: 1,
+            name: "mesg_num",
+            field_ty: "mesg_num",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("memo_glob", "parent_index") => Some(FieldDef {
+            number: 2,
+            name: "parent_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("memo_glob", "field_num") => Some(FieldDef {
+            number: 3,
+            name: "field_num",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("memo_glob", "data") => Some(FieldDef {
+            number: 4,
+            name: "data",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_level", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("sleep_level", "sleep_level") => Some(FieldDef {
+            number: 0,
+            name: "sleep_level",
+            field_ty: "sleep_level",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_channel_id", "channel_number") => Some(FieldDef {
+            number: 0,
+            name: "channel_number",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_channel_id", "device_type") => Some(FieldDef {
+            number: 1,
+            name: "device_type",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_channel_id", "device_number") => Some(FieldDef {
+            number: 2,
+            name: "device_number",
+            field_ty: "uint16z",
+            base_type: fit::BaseType::UInt16z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_channel_id", "transmission_type") => Some(FieldDef {
+            number: 3,
+            name: "transmission_type",
+            field_ty: "uint8z",
+            base_type: fit::BaseType::UInt8z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_channel_id", "device_index") => Some(FieldDef {
+            number: 4,
+            name: "device_index",
+            field_ty: "device_index",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_rx", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("ant_rx", "fractional_timestamp") => Some(FieldDef {
+            number: 0,
+            name: "fractional_timestamp",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 32768.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("ant_rx", "mesg_id") => Some(FieldDef {
+            number: 1,
+            name: "mesg_id",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_rx", "mesg_data") => Some(FieldDef {
+            number: 2,
+            name: "mesg_data",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_rx", "channel_number") => Some(FieldDef {
+            number: 3,
+            name: "channel_number",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_rx", "data") => Some(FieldDef {
+            number: 4,
+            name: "data",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_tx", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("ant_tx", "fractional_timestamp") => Some(FieldDef {
+            number: 0,
+            name: "fractional_timestamp",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 32768.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("ant_tx", "mesg_id") => Some(FieldDef {
+            number: 1,
+            name: "mesg_id",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_tx", "mesg_data") => Some(FieldDef {
+            number: 2,
+            name: "mesg_data",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_tx", "channel_number") => Some(FieldDef {
+            number: 3,
+            name: "channel_number",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("ant_tx", "data") => Some(FieldDef {
+            number: 4,
+            name: "data",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_screen_configuration", "screen_index") => Some(FieldDef {
+            number: 0,
+            name: "screen_index",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_screen_configuration", "field_count") => Some(FieldDef {
+            number: 1,
+            name: "field_count",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_screen_configuration", "layout") => Some(FieldDef {
+            number: 2,
+            name: "layout",
+            field_ty: "exd_layout",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_screen_configuration", "screen_enabled") => Some(FieldDef {
+            number: 3,
+            name: "screen_enabled",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_field_configuration", "screen_index") => Some(FieldDef {
+            number: 0,
+            name: "screen_index",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_field_configuration", "concept_field") => Some(FieldDef {
+            number: 1,
+            name: "concept_field",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_field_configuration", "field_id") => Some(FieldDef {
+            number: 2,
+            name: "field_id",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_field_configuration", "concept_count") => Some(FieldDef {
+            number: 3,
+            name: "concept_count",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_field_configuration", "display_type") => Some(FieldDef {
+            number: 4,
+            name: "display_type",
+            field_ty: "exd_display_type",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_field_configuration", "title") => Some(FieldDef {
+            number: 5,
+            name: "title",
+            field_ty: "string",
+            base_type: fit::BaseType::String,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "screen_index") => Some(FieldDef {
+            number: 0,
+            name: "screen_index",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "concept_field") => Some(FieldDef {
+            number: 1,
+            name: "concept_field",
+            field_ty: "byte",
+            base_type: fit::BaseType::Byte,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "field_id") => Some(FieldDef {
+            number: 2,
+            name: "field_id",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "concept_index") => Some(FieldDef {
+            number: 3,
+            name: "concept_index",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "data_page") => Some(FieldDef {
+            number: 4,
+            name: "data_page",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "concept_key") => Some(FieldDef {
+            number: 5,
+            name: "concept_key",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "scaling") => Some(FieldDef {
+            number: 6,
+            name: "scaling",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "data_units") => Some(FieldDef {
+            number: 8,
+            name: "data_units",
+            field_ty: "exd_data_units",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "qualifier") => Some(FieldDef {
+            number: 9,
+            name: "qualifier",
+            field_ty: "exd_qualifiers",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "descriptor") => Some(FieldDef {
+            number: 10,
+            name: "descriptor",
+            field_ty: "exd_descriptors",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("exd_data_concept_configuration", "is_signed") => Some(FieldDef {
+            number: 11,
+            name: "is_signed",
+            field_ty: "bool",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_summary", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("dive_summary", "reference_mesg") => Some(FieldDef {
+            number: 0,
+            name: "reference_mesg",
+            field_ty: "mesg_num",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_summary", "reference_index") => Some(FieldDef {
+            number: 1,
+            name: "reference_index",
+            field_ty: "message_index",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_summary", "avg_depth") => Some(FieldDef {
+            number: 2,
+            name: "avg_depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("dive_summary", "max_depth") => Some(FieldDef {
+            number: 3,
+            name: "max_depth",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m",
+        }),
+        ("dive_summary", "surface_interval") => Some(FieldDef {
+            number: 4,
+            name: "surface_interval",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("dive_summary", "start_cns") => Some(FieldDef {
+            number: 5,
+            name: "start_cns",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_summary", "end_cns") => Some(FieldDef {
+            number: 6,
+            name: "end_cns",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_summary", "start_n2") => Some(FieldDef {
+            number: 7,
+            name: "start_n2",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_summary", "end_n2") => Some(FieldDef {
+            number: 8,
+            name: "end_n2",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "percent",
+        }),
+        ("dive_summary", "o2_toxicity") => Some(FieldDef {
+            number: 9,
+            name: "o2_toxicity",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "OTUs",
+        }),
+        ("dive_summary", "dive_number") => Some(FieldDef {
+            number: 10,
+            name: "dive_number",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("dive_summary", "bottom_time") => Some(FieldDef {
+            number: 11,
+            name: "bottom_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("dive_summary", "avg_pressure_sac") => Some(FieldDef {
+            number: 12,
+            name: "avg_pressure_sac",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "bar/min",
+        }),
+        ("dive_summary", "avg_volume_sac") => Some(FieldDef {
+            number: 13,
+            name: "avg_volume_sac",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "L/min",
+        }),
+        ("dive_summary", "avg_rmv") => Some(FieldDef {
+            number: 14,
+            name: "avg_rmv",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "L/min",
+        }),
+        ("dive_summary", "descent_time") => Some(FieldDef {
+            number: 15,
+            name: "descent_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("dive_summary", "ascent_time") => Some(FieldDef {
+            number: 16,
+            name: "ascent_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("dive_summary", "avg_ascent_rate") => Some(FieldDef {
+            number: 17,
+            name: "avg_ascent_rate",
+            field_ty: "sint32",
+            base_type: fit::BaseType::SInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("dive_summary", "avg_descent_rate") => Some(FieldDef {
+            number: 22,
+            name: "avg_descent_rate",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("dive_summary", "max_ascent_rate") => Some(FieldDef {
+            number: 23,
+            name: "max_ascent_rate",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("dive_summary", "max_descent_rate") => Some(FieldDef {
+            number: 24,
+            name: "max_descent_rate",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "m/s",
+        }),
+        ("dive_summary", "hang_time") => Some(FieldDef {
+            number: 25,
+            name: "hang_time",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("hrv", "time") => Some(FieldDef {
+            number: 0,
+            name: "time",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1000.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("beat_intervals", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("beat_intervals", "timestamp_ms") => Some(FieldDef {
+            number: 0,
+            name: "timestamp_ms",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("beat_intervals", "time") => Some(FieldDef {
+            number: 1,
+            name: "time",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 1.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("hrv_status_summary", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hrv_status_summary", "weekly_average") => Some(FieldDef {
+            number: 0,
+            name: "weekly_average",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 128.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("hrv_status_summary", "last_night_average") => Some(FieldDef {
+            number: 1,
+            name: "last_night_average",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 128.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("hrv_status_summary", "last_night_5_min_high") => Some(FieldDef {
+            number: 2,
+            name: "last_night_5_min_high",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 128.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("hrv_status_summary", "baseline_low_upper") => Some(FieldDef {
+            number: 3,
+            name: "baseline_low_upper",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 128.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("hrv_status_summary", "baseline_balanced_lower") => Some(FieldDef {
+            number: 4,
+            name: "baseline_balanced_lower",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 128.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("hrv_status_summary", "baseline_balanced_upper") => Some(FieldDef {
+            number: 5,
+            name: "baseline_balanced_upper",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 128.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("hrv_status_summary", "status") => Some(FieldDef {
+            number: 6,
+            name: "status",
+            field_ty: "hrv_status",
+            base_type: fit::BaseType::Enum,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hrv_value", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("hrv_value", "value") => Some(FieldDef {
+            number: 0,
+            name: "value",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 128.0,
+            offset: 0.0,
+            units: "ms",
+        }),
+        ("respiration_rate", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("respiration_rate", "respiration_rate") => Some(FieldDef {
+            number: 0,
+            name: "respiration_rate",
+            field_ty: "sint16",
+            base_type: fit::BaseType::SInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "breaths/min",
+        }),
+        ("tank_update", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("tank_update", "sensor") => Some(FieldDef {
+            number: 0,
+            name: "sensor",
+            field_ty: "ant_channel_id",
+            base_type: fit::BaseType::UInt32z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("tank_update", "pressure") => Some(FieldDef {
+            number: 1,
+            name: "pressure",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "bar",
+        }),
+        ("tank_summary", "timestamp") => Some(FieldDef {
+            number: 253,
+            name: "timestamp",
+            field_ty: "date_time",
+            base_type: fit::BaseType::UInt32,
+            scale: 1.0,
+            offset: 0.0,
+            units: "s",
+        }),
+        ("tank_summary", "sensor") => Some(FieldDef {
+            number: 0,
+            name: "sensor",
+            field_ty: "ant_channel_id",
+            base_type: fit::BaseType::UInt32z,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("tank_summary", "start_pressure") => Some(FieldDef {
+            number: 1,
+            name: "start_pressure",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "bar",
+        }),
+        ("tank_summary", "end_pressure") => Some(FieldDef {
+            number: 2,
+            name: "end_pressure",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "bar",
+        }),
+        ("tank_summary", "volume_used") => Some(FieldDef {
+            number: 3,
+            name: "volume_used",
+            field_ty: "uint32",
+            base_type: fit::BaseType::UInt32,
+            scale: 100.0,
+            offset: 0.0,
+            units: "L",
+        }),
+        ("sleep_assessment", "combined_awake_score") => Some(FieldDef {
+            number: 0,
+            name: "combined_awake_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "awake_time_score") => Some(FieldDef {
+            number: 1,
+            name: "awake_time_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "awakenings_count_score") => Some(FieldDef {
+            number: 2,
+            name: "awakenings_count_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "deep_sleep_score") => Some(FieldDef {
+            number: 3,
+            name: "deep_sleep_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "sleep_duration_score") => Some(FieldDef {
+            number: 4,
+            name: "sleep_duration_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "light_sleep_score") => Some(FieldDef {
+            number: 5,
+            name: "light_sleep_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "overall_sleep_score") => Some(FieldDef {
+            number: 6,
+            name: "overall_sleep_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "sleep_quality_score") => Some(FieldDef {
+            number: 7,
+            name: "sleep_quality_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "sleep_recovery_score") => Some(FieldDef {
+            number: 8,
+            name: "sleep_recovery_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "rem_sleep_score") => Some(FieldDef {
+            number: 9,
+            name: "rem_sleep_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "sleep_restlessness_score") => Some(FieldDef {
+            number: 10,
+            name: "sleep_restlessness_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "awakenings_count") => Some(FieldDef {
+            number: 11,
+            name: "awakenings_count",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "interruptions_score") => Some(FieldDef {
+            number: 14,
+            name: "interruptions_score",
+            field_ty: "uint8",
+            base_type: fit::BaseType::UInt8,
+            scale: 1.0,
+            offset: 0.0,
+            units: "",
+        }),
+        ("sleep_assessment", "average_stress_during_sleep") => Some(FieldDef {
+            number: 15,
+            name: "average_stress_during_sleep",
+            field_ty: "uint16",
+            base_type: fit::BaseType::UInt16,
+            scale: 100.0,
+            offset: 0.0,
+            units: "",
+        }),
+        _ => None,
+    }
+}
@@ -831,6 +831,20 @@ impl TryFrom<&str> for FileFlags {
         }
     }
 }
+impl FileFlags {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (FileFlags::Read, 0x02),
+            (FileFlags::Write, 0x04),
+            (FileFlags::Erase, 0x08),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum MesgCount {
@@ -1407,6 +1421,25 @@ impl TryFrom<&str> for LanguageBits0 {
         }
     }
 }
+impl LanguageBits0 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (LanguageBits0::English, 0x01),
+            (LanguageBits0::French, 0x02),
+            (LanguageBits0::Italian, 0x04),
+            (LanguageBits0::German, 0x08),
+            (LanguageBits0::Spanish, 0x10),
+            (LanguageBits0::Croatian, 0x20),
+            (LanguageBits0::Czech, 0x40),
+            (LanguageBits0::Danish, 0x80),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LanguageBits1 {
@@ -1482,6 +1515,25 @@ impl TryFrom<&str> for LanguageBits1 {
         }
     }
 }
+impl LanguageBits1 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (LanguageBits1::Dutch, 0x01),
+            (LanguageBits1::Finnish, 0x02),
+            (LanguageBits1::Greek, 0x04),
+            (LanguageBits1::Hungarian, 0x08),
+            (LanguageBits1::Norwegian, 0x10),
+            (LanguageBits1::Polish, 0x20),
+            (LanguageBits1::Portuguese, 0x40),
+            (LanguageBits1::Slovakian, 0x80),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LanguageBits2 {
@@ -1557,6 +1609,25 @@ impl TryFrom<&str> for LanguageBits2 {
         }
     }
 }
+impl LanguageBits2 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (LanguageBits2::Slovenian, 0x01),
+            (LanguageBits2::Swedish, 0x02),
+            (LanguageBits2::Russian, 0x04),
+            (LanguageBits2::Turkish, 0x08),
+            (LanguageBits2::Latvian, 0x10),
+            (LanguageBits2::Ukrainian, 0x20),
+            (LanguageBits2::Arabic, 0x40),
+            (LanguageBits2::Farsi, 0x80),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LanguageBits3 {
@@ -1632,6 +1703,25 @@ impl TryFrom<&str> for LanguageBits3 {
         }
     }
 }
+impl LanguageBits3 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (LanguageBits3::Bulgarian, 0x01),
+            (LanguageBits3::Romanian, 0x02),
+            (LanguageBits3::Chinese, 0x04),
+            (LanguageBits3::Japanese, 0x08),
+            (LanguageBits3::Korean, 0x10),
+            (LanguageBits3::Taiwanese, 0x20),
+            (LanguageBits3::Thai, 0x40),
+            (LanguageBits3::Hebrew, 0x80),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LanguageBits4 {
@@ -1697,6 +1787,23 @@ impl TryFrom<&str> for LanguageBits4 {
         }
     }
 }
+impl LanguageBits4 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (LanguageBits4::BrazilianPortuguese, 0x01),
+            (LanguageBits4::Indonesian, 0x02),
+            (LanguageBits4::Malaysian, 0x04),
+            (LanguageBits4::Vietnamese, 0x08),
+            (LanguageBits4::Burmese, 0x10),
+            (LanguageBits4::Mongolian, 0x20),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum TimeZone {
@@ -3147,6 +3254,25 @@ impl TryFrom<&str> for SportBits0 {
         }
     }
 }
+impl SportBits0 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (SportBits0::Generic, 0x01),
+            (SportBits0::Running, 0x02),
+            (SportBits0::Cycling, 0x04),
+            (SportBits0::Transition, 0x08),
+            (SportBits0::FitnessEquipment, 0x10),
+            (SportBits0::Swimming, 0x20),
+            (SportBits0::Basketball, 0x40),
+            (SportBits0::Soccer, 0x80),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits1 {
@@ -3222,6 +3348,25 @@ impl TryFrom<&str> for SportBits1 {
         }
     }
 }
+impl SportBits1 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (SportBits1::Tennis, 0x01),
+            (SportBits1::AmericanFootball, 0x02),
+            (SportBits1::Training, 0x04),
+            (SportBits1::Walking, 0x08),
+            (SportBits1::CrossCountrySkiing, 0x10),
+            (SportBits1::AlpineSkiing, 0x20),
+            (SportBits1::Snowboarding, 0x40),
+            (SportBits1::Rowing, 0x80),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits2 {
@@ -3297,6 +3442,25 @@ impl TryFrom<&str> for SportBits2 {
         }
     }
 }
+impl SportBits2 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (SportBits2::Mountaineering, 0x01),
+            (SportBits2::Hiking, 0x02),
+            (SportBits2::Multisport, 0x04),
+            (SportBits2::Paddling, 0x08),
+            (SportBits2::Flying, 0x10),
+            (SportBits2::EBiking, 0x20),
+            (SportBits2::Motorcycling, 0x40),
+            (SportBits2::Boating, 0x80),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits3 {
@@ -3372,6 +3536,25 @@ impl TryFrom<&str> for SportBits3 {
         }
     }
 }
+impl SportBits3 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (SportBits3::Driving, 0x01),
+            (SportBits3::Golf, 0x02),
+            (SportBits3::HangGliding, 0x04),
+            (SportBits3::HorsebackRiding, 0x08),
+            (SportBits3::Hunting, 0x10),
+            (SportBits3::Fishing, 0x20),
+            (SportBits3::InlineSkating, 0x40),
+            (SportBits3::RockClimbing, 0x80),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits4 {
@@ -3447,6 +3630,25 @@ impl TryFrom<&str> for SportBits4 {
         }
     }
 }
+impl SportBits4 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (SportBits4::Sailing, 0x01),
+            (SportBits4::IceSkating, 0x02),
+            (SportBits4::SkyDiving, 0x04),
+            (SportBits4::Snowshoeing, 0x08),
+            (SportBits4::Snowmobiling, 0x10),
+            (SportBits4::StandUpPaddleboarding, 0x20),
+            (SportBits4::Surfing, 0x40),
+            (SportBits4::Wakeboarding, 0x80),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits5 {
@@ -3522,6 +3724,25 @@ impl TryFrom<&str> for SportBits5 {
         }
     }
 }
+impl SportBits5 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (SportBits5::WaterSkiing, 0x01),
+            (SportBits5::Kayaking, 0x02),
+            (SportBits5::Rafting, 0x04),
+            (SportBits5::Windsurfing, 0x08),
+            (SportBits5::Kitesurfing, 0x10),
+            (SportBits5::Tactical, 0x20),
+            (SportBits5::Jumpmaster, 0x40),
+            (SportBits5::Boxing, 0x80),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SportBits6 {
@@ -3562,6 +3783,16 @@ impl TryFrom<&str> for SportBits6 {
         }
     }
 }
+impl SportBits6 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [(SportBits6::FloorClimbing, 0x01)]
+            .into_iter()
+            .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+            .map(|(flag, _)| flag)
+            .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SubSport {
@@ -10034,6 +10265,31 @@ impl TryFrom<&str> for WorkoutCapabilities {
         }
     }
 }
+impl WorkoutCapabilities {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (WorkoutCapabilities::Interval, 0x00000001),
+            (WorkoutCapabilities::Custom, 0x00000002),
+            (WorkoutCapabilities::FitnessEquipment, 0x00000004),
+            (WorkoutCapabilities::Firstbeat, 0x00000008),
+            (WorkoutCapabilities::NewLeaf, 0x00000010),
+            (WorkoutCapabilities::Tcx, 0x00000020),
+            (WorkoutCapabilities::Speed, 0x00000080),
+            (WorkoutCapabilities::HeartRate, 0x00000100),
+            (WorkoutCapabilities::Distance, 0x00000200),
+            (WorkoutCapabilities::Cadence, 0x00000400),
+            (WorkoutCapabilities::Power, 0x00000800),
+            (WorkoutCapabilities::Grade, 0x00001000),
+            (WorkoutCapabilities::Resistance, 0x00002000),
+            (WorkoutCapabilities::Protected, 0x00004000),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum BatteryStatus {
@@ -10245,6 +10501,29 @@ impl TryFrom<&str> for CourseCapabilities {
         }
     }
 }
+impl CourseCapabilities {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (CourseCapabilities::Processed, 0x00000001),
+            (CourseCapabilities::Valid, 0x00000002),
+            (CourseCapabilities::Time, 0x00000004),
+            (CourseCapabilities::Distance, 0x00000008),
+            (CourseCapabilities::Position, 0x00000010),
+            (CourseCapabilities::HeartRate, 0x00000020),
+            (CourseCapabilities::Power, 0x00000040),
+            (CourseCapabilities::Cadence, 0x00000080),
+            (CourseCapabilities::Training, 0x00000100),
+            (CourseCapabilities::Navigation, 0x00000200),
+            (CourseCapabilities::Bikeway, 0x00000400),
+            (CourseCapabilities::Aviation, 0x00001000),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum Weight {
@@ -10938,6 +11217,16 @@ impl TryFrom<&str> for LeftRightBalance {
         }
     }
 }
+impl LeftRightBalance {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [(LeftRightBalance::Right, 0x80)]
+            .into_iter()
+            .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+            .map(|(flag, _)| flag)
+            .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LeftRightBalance100 {
@@ -10985,6 +11274,16 @@ impl TryFrom<&str> for LeftRightBalance100 {
         }
     }
 }
+impl LeftRightBalance100 {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [(LeftRightBalance100::Right, 0x8000)]
+            .into_iter()
+            .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+            .map(|(flag, _)| flag)
+            .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum LengthType {
@@ -11340,6 +11639,60 @@ impl TryFrom<&str> for ConnectivityCapabilities {
         }
     }
 }
+impl ConnectivityCapabilities {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (ConnectivityCapabilities::Bluetooth, 0x00000001),
+            (ConnectivityCapabilities::BluetoothLe, 0x00000002),
+            (ConnectivityCapabilities::Ant, 0x00000004),
+            (ConnectivityCapabilities::ActivityUpload, 0x00000008),
+            (ConnectivityCapabilities::CourseDownload, 0x00000010),
+            (ConnectivityCapabilities::WorkoutDownload, 0x00000020),
+            (ConnectivityCapabilities::LiveTrack, 0x00000040),
+            (ConnectivityCapabilities::WeatherConditions, 0x00000080),
+            (ConnectivityCapabilities::WeatherAlerts, 0x00000100),
+            (ConnectivityCapabilities::GpsEphemerisDownload, 0x00000200),
+            (ConnectivityCapabilities::ExplicitArchive, 0x00000400),
+            (ConnectivityCapabilities::SetupIncomplete, 0x00000800),
+            (ConnectivityCapabilities::ConnectIqAppDownload, 0x00002000),
+            (ConnectivityCapabilities::GolfCourseDownload, 0x00004000),
+            (ConnectivityCapabilities::DeviceInitiatesSync, 0x00008000),
+            (
+                ConnectivityCapabilities::ConnectIqWatchAppDownload,
+                0x00010000,
+            ),
+            (
+                ConnectivityCapabilities::ConnectIqWidgetDownload,
+                0x00020000,
+            ),
+            (
+                ConnectivityCapabilities::ConnectIqWatchFaceDownload,
+                0x00040000,
+            ),
+            (
+                ConnectivityCapabilities::ConnectIqDataFieldDownload,
+                0x00080000,
+            ),
+            (ConnectivityCapabilities::ConnectIqAppManagment, 0x00100000),
+            (ConnectivityCapabilities::SwingSensor, 0x00200000),
+            (ConnectivityCapabilities::SwingSensorRemote, 0x00400000),
+            (ConnectivityCapabilities::IncidentDetection, 0x00800000),
+            (ConnectivityCapabilities::AudioPrompts, 0x01000000),
+            (ConnectivityCapabilities::WifiVerification, 0x02000000),
+            (ConnectivityCapabilities::TrueUp, 0x04000000),
+            (ConnectivityCapabilities::FindMyWatch, 0x08000000),
+            (ConnectivityCapabilities::RemoteManualSync, 0x10000000),
+            (ConnectivityCapabilities::LiveTrackAutoStart, 0x20000000),
+            (ConnectivityCapabilities::LiveTrackMessaging, 0x40000000),
+            (ConnectivityCapabilities::InstantInput, 0x80000000),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum WeatherReport {
@@ -13827,6 +14180,30 @@ impl TryFrom<&str> for AttitudeValidity {
         }
     }
 }
+impl AttitudeValidity {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (AttitudeValidity::TrackAngleHeadingValid, 0x0001),
+            (AttitudeValidity::PitchValid, 0x0002),
+            (AttitudeValidity::RollValid, 0x0004),
+            (AttitudeValidity::LateralBodyAccelValid, 0x0008),
+            (AttitudeValidity::NormalBodyAccelValid, 0x0010),
+            (AttitudeValidity::TurnRateValid, 0x0020),
+            (AttitudeValidity::HwFail, 0x0040),
+            (AttitudeValidity::MagInvalid, 0x0080),
+            (AttitudeValidity::NoGps, 0x0100),
+            (AttitudeValidity::GpsInvalid, 0x0200),
+            (AttitudeValidity::SolutionCoasting, 0x0400),
+            (AttitudeValidity::TrueTrackAngle, 0x0800),
+            (AttitudeValidity::MagneticHeading, 0x1000),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum AutoSyncFrequency {
@@ -15217,6 +15594,24 @@ impl TryFrom<&str> for AutoActivityDetect {
         }
     }
 }
+impl AutoActivityDetect {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (AutoActivityDetect::None, 0x00000000),
+            (AutoActivityDetect::Running, 0x00000001),
+            (AutoActivityDetect::Cycling, 0x00000002),
+            (AutoActivityDetect::Swimming, 0x00000004),
+            (AutoActivityDetect::Walking, 0x00000008),
+            (AutoActivityDetect::Elliptical, 0x00000020),
+            (AutoActivityDetect::Sedentary, 0x00000400),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum SupportedExdScreenLayouts {
@@ -15308,6 +15703,34 @@ impl TryFrom<&str> for SupportedExdScreenLayouts {
         }
     }
 }
+impl SupportedExdScreenLayouts {
+    pub fn flags(value: &fit::Value) -> Vec<Self> {
+        let raw = value.try_as_usize().unwrap_or(0);
+        [
+            (SupportedExdScreenLayouts::FullScreen, 0x00000001),
+            (SupportedExdScreenLayouts::HalfVertical, 0x00000002),
+            (SupportedExdScreenLayouts::HalfHorizontal, 0x00000004),
+            (
+                SupportedExdScreenLayouts::HalfVerticalRightSplit,
+                0x00000008,
+            ),
+            (
+                SupportedExdScreenLayouts::HalfHorizontalBottomSplit,
+                0x00000010,
+            ),
+            (SupportedExdScreenLayouts::FullQuarterSplit, 0x00000020),
+            (SupportedExdScreenLayouts::HalfVerticalLeftSplit, 0x00000040),
+            (
+                SupportedExdScreenLayouts::HalfHorizontalTopSplit,
+                0x00000080,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+        .map(|(flag, _)| flag)
+        .collect()
+    }
+}
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 pub enum FitBaseType {
@@ -26514,3 +26937,391 @@ impl TryFrom<&str> for NoFlyTimeMode {
         }
     }
 }
+/// Names of the flags set in a bit mask value, `None` if `ty` is not a bit mask type
+pub fn bit_flags(ty: &str, value: &fit::Value) -> Option<Vec<String>> {
+    match ty {
+        "file_flags" => Some(
+            FileFlags::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "language_bits_0" => Some(
+            LanguageBits0::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "language_bits_1" => Some(
+            LanguageBits1::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "language_bits_2" => Some(
+            LanguageBits2::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "language_bits_3" => Some(
+            LanguageBits3::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "language_bits_4" => Some(
+            LanguageBits4::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "sport_bits_0" => Some(
+            SportBits0::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "sport_bits_1" => Some(
+            SportBits1::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "sport_bits_2" => Some(
+            SportBits2::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "sport_bits_3" => Some(
+            SportBits3::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "sport_bits_4" => Some(
+            SportBits4::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "sport_bits_5" => Some(
+            SportBits5::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "sport_bits_6" => Some(
+            SportBits6::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "workout_capabilities" => Some(
+            WorkoutCapabilities::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "course_capabilities" => Some(
+            CourseCapabilities::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "left_right_balance" => Some(
+            LeftRightBalance::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "left_right_balance_100" => Some(
+            LeftRightBalance100::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "connectivity_capabilities" => Some(
+            ConnectivityCapabilities::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "attitude_validity" => Some(
+            AttitudeValidity::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "auto_activity_detect" => Some(
+            AutoActivityDetect::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        "supported_exd_screen_layouts" => Some(
+            SupportedExdScreenLayouts::flags(value)
+                .iter()
+                .map(|it| it.to_string())
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+/// Value mask of a bit mask type holding a value next to its flags, e.g. `left_right_balance`
+pub fn value_mask(ty: &str) -> Option<u64> {
+    match ty {
+        "left_right_balance" => Some(0x7F),
+        "left_right_balance_100" => Some(0x3FFF),
+        _ => None,
+    }
+}
+/// Raw value of a named profile type value, e.g. `("sport", "cycling")`
+pub fn value_of(ty: &str, name: &str) -> Option<fit::Value> {
+    match ty {
+        "file" => File::try_from(name).ok().map(|it| it.value()),
+        "mesg_num" => MesgNum::try_from(name).ok().map(|it| it.value()),
+        "checksum" => Checksum::try_from(name).ok().map(|it| it.value()),
+        "file_flags" => FileFlags::try_from(name).ok().map(|it| it.value()),
+        "mesg_count" => MesgCount::try_from(name).ok().map(|it| it.value()),
+        "date_time" => DateTime::try_from(name).ok().map(|it| it.value()),
+        "local_date_time" => LocalDateTime::try_from(name).ok().map(|it| it.value()),
+        "message_index" => MessageIndex::try_from(name).ok().map(|it| it.value()),
+        "device_index" => DeviceIndex::try_from(name).ok().map(|it| it.value()),
+        "gender" => Gender::try_from(name).ok().map(|it| it.value()),
+        "language" => Language::try_from(name).ok().map(|it| it.value()),
+        "language_bits_0" => LanguageBits0::try_from(name).ok().map(|it| it.value()),
+        "language_bits_1" => LanguageBits1::try_from(name).ok().map(|it| it.value()),
+        "language_bits_2" => LanguageBits2::try_from(name).ok().map(|it| it.value()),
+        "language_bits_3" => LanguageBits3::try_from(name).ok().map(|it| it.value()),
+        "language_bits_4" => LanguageBits4::try_from(name).ok().map(|it| it.value()),
+        "time_zone" => TimeZone::try_from(name).ok().map(|it| it.value()),
+        "display_measure" => DisplayMeasure::try_from(name).ok().map(|it| it.value()),
+        "display_heart" => DisplayHeart::try_from(name).ok().map(|it| it.value()),
+        "display_power" => DisplayPower::try_from(name).ok().map(|it| it.value()),
+        "display_position" => DisplayPosition::try_from(name).ok().map(|it| it.value()),
+        "switch" => Switch::try_from(name).ok().map(|it| it.value()),
+        "sport" => Sport::try_from(name).ok().map(|it| it.value()),
+        "sport_bits_0" => SportBits0::try_from(name).ok().map(|it| it.value()),
+        "sport_bits_1" => SportBits1::try_from(name).ok().map(|it| it.value()),
+        "sport_bits_2" => SportBits2::try_from(name).ok().map(|it| it.value()),
+        "sport_bits_3" => SportBits3::try_from(name).ok().map(|it| it.value()),
+        "sport_bits_4" => SportBits4::try_from(name).ok().map(|it| it.value()),
+        "sport_bits_5" => SportBits5::try_from(name).ok().map(|it| it.value()),
+        "sport_bits_6" => SportBits6::try_from(name).ok().map(|it| it.value()),
+        "sub_sport" => SubSport::try_from(name).ok().map(|it| it.value()),
+        "sport_event" => SportEvent::try_from(name).ok().map(|it| it.value()),
+        "activity" => Activity::try_from(name).ok().map(|it| it.value()),
+        "intensity" => Intensity::try_from(name).ok().map(|it| it.value()),
+        "session_trigger" => SessionTrigger::try_from(name).ok().map(|it| it.value()),
+        "autolap_trigger" => AutolapTrigger::try_from(name).ok().map(|it| it.value()),
+        "lap_trigger" => LapTrigger::try_from(name).ok().map(|it| it.value()),
+        "time_mode" => TimeMode::try_from(name).ok().map(|it| it.value()),
+        "backlight_mode" => BacklightMode::try_from(name).ok().map(|it| it.value()),
+        "date_mode" => DateMode::try_from(name).ok().map(|it| it.value()),
+        "backlight_timeout" => BacklightTimeout::try_from(name).ok().map(|it| it.value()),
+        "event" => Event::try_from(name).ok().map(|it| it.value()),
+        "event_type" => EventType::try_from(name).ok().map(|it| it.value()),
+        "timer_trigger" => TimerTrigger::try_from(name).ok().map(|it| it.value()),
+        "fitness_equipment_state" => FitnessEquipmentState::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "tone" => Tone::try_from(name).ok().map(|it| it.value()),
+        "autoscroll" => Autoscroll::try_from(name).ok().map(|it| it.value()),
+        "activity_class" => ActivityClass::try_from(name).ok().map(|it| it.value()),
+        "hr_zone_calc" => HrZoneCalc::try_from(name).ok().map(|it| it.value()),
+        "pwr_zone_calc" => PwrZoneCalc::try_from(name).ok().map(|it| it.value()),
+        "wkt_step_duration" => WktStepDuration::try_from(name).ok().map(|it| it.value()),
+        "wkt_step_target" => WktStepTarget::try_from(name).ok().map(|it| it.value()),
+        "goal" => Goal::try_from(name).ok().map(|it| it.value()),
+        "goal_recurrence" => GoalRecurrence::try_from(name).ok().map(|it| it.value()),
+        "goal_source" => GoalSource::try_from(name).ok().map(|it| it.value()),
+        "schedule" => Schedule::try_from(name).ok().map(|it| it.value()),
+        "course_point" => CoursePoint::try_from(name).ok().map(|it| it.value()),
+        "manufacturer" => Manufacturer::try_from(name).ok().map(|it| it.value()),
+        "garmin_product" => GarminProduct::try_from(name).ok().map(|it| it.value()),
+        "antplus_device_type" => AntplusDeviceType::try_from(name).ok().map(|it| it.value()),
+        "ant_network" => AntNetwork::try_from(name).ok().map(|it| it.value()),
+        "workout_capabilities" => WorkoutCapabilities::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "battery_status" => BatteryStatus::try_from(name).ok().map(|it| it.value()),
+        "hr_type" => HrType::try_from(name).ok().map(|it| it.value()),
+        "course_capabilities" => CourseCapabilities::try_from(name).ok().map(|it| it.value()),
+        "weight" => Weight::try_from(name).ok().map(|it| it.value()),
+        "workout_hr" => WorkoutHr::try_from(name).ok().map(|it| it.value()),
+        "workout_power" => WorkoutPower::try_from(name).ok().map(|it| it.value()),
+        "bp_status" => BpStatus::try_from(name).ok().map(|it| it.value()),
+        "user_local_id" => UserLocalId::try_from(name).ok().map(|it| it.value()),
+        "swim_stroke" => SwimStroke::try_from(name).ok().map(|it| it.value()),
+        "activity_type" => ActivityType::try_from(name).ok().map(|it| it.value()),
+        "activity_subtype" => ActivitySubtype::try_from(name).ok().map(|it| it.value()),
+        "activity_level" => ActivityLevel::try_from(name).ok().map(|it| it.value()),
+        "side" => Side::try_from(name).ok().map(|it| it.value()),
+        "left_right_balance" => LeftRightBalance::try_from(name).ok().map(|it| it.value()),
+        "left_right_balance_100" => LeftRightBalance100::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "length_type" => LengthType::try_from(name).ok().map(|it| it.value()),
+        "day_of_week" => DayOfWeek::try_from(name).ok().map(|it| it.value()),
+        "connectivity_capabilities" => ConnectivityCapabilities::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "weather_report" => WeatherReport::try_from(name).ok().map(|it| it.value()),
+        "weather_status" => WeatherStatus::try_from(name).ok().map(|it| it.value()),
+        "weather_severity" => WeatherSeverity::try_from(name).ok().map(|it| it.value()),
+        "weather_severe_type" => WeatherSevereType::try_from(name).ok().map(|it| it.value()),
+        "stroke_type" => StrokeType::try_from(name).ok().map(|it| it.value()),
+        "body_location" => BodyLocation::try_from(name).ok().map(|it| it.value()),
+        "segment_lap_status" => SegmentLapStatus::try_from(name).ok().map(|it| it.value()),
+        "segment_leaderboard_type" => SegmentLeaderboardType::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "segment_delete_status" => SegmentDeleteStatus::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "segment_selection_type" => SegmentSelectionType::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "source_type" => SourceType::try_from(name).ok().map(|it| it.value()),
+        "local_device_type" => LocalDeviceType::try_from(name).ok().map(|it| it.value()),
+        "ble_device_type" => BleDeviceType::try_from(name).ok().map(|it| it.value()),
+        "ant_channel_id" => AntChannelId::try_from(name).ok().map(|it| it.value()),
+        "display_orientation" => DisplayOrientation::try_from(name).ok().map(|it| it.value()),
+        "workout_equipment" => WorkoutEquipment::try_from(name).ok().map(|it| it.value()),
+        "watchface_mode" => WatchfaceMode::try_from(name).ok().map(|it| it.value()),
+        "digital_watchface_layout" => DigitalWatchfaceLayout::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "analog_watchface_layout" => AnalogWatchfaceLayout::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "rider_position_type" => RiderPositionType::try_from(name).ok().map(|it| it.value()),
+        "power_phase_type" => PowerPhaseType::try_from(name).ok().map(|it| it.value()),
+        "camera_event_type" => CameraEventType::try_from(name).ok().map(|it| it.value()),
+        "sensor_type" => SensorType::try_from(name).ok().map(|it| it.value()),
+        "bike_light_network_config_type" => BikeLightNetworkConfigType::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "comm_timeout_type" => CommTimeoutType::try_from(name).ok().map(|it| it.value()),
+        "camera_orientation_type" => CameraOrientationType::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "attitude_stage" => AttitudeStage::try_from(name).ok().map(|it| it.value()),
+        "attitude_validity" => AttitudeValidity::try_from(name).ok().map(|it| it.value()),
+        "auto_sync_frequency" => AutoSyncFrequency::try_from(name).ok().map(|it| it.value()),
+        "exd_layout" => ExdLayout::try_from(name).ok().map(|it| it.value()),
+        "exd_display_type" => ExdDisplayType::try_from(name).ok().map(|it| it.value()),
+        "exd_data_units" => ExdDataUnits::try_from(name).ok().map(|it| it.value()),
+        "exd_qualifiers" => ExdQualifiers::try_from(name).ok().map(|it| it.value()),
+        "exd_descriptors" => ExdDescriptors::try_from(name).ok().map(|it| it.value()),
+        "auto_activity_detect" => AutoActivityDetect::try_from(name).ok().map(|it| it.value()),
+        "supported_exd_screen_layouts" => SupportedExdScreenLayouts::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "fit_base_type" => FitBaseType::try_from(name).ok().map(|it| it.value()),
+        "turn_type" => TurnType::try_from(name).ok().map(|it| it.value()),
+        "bike_light_beam_angle_mode" => BikeLightBeamAngleMode::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "fit_base_unit" => FitBaseUnit::try_from(name).ok().map(|it| it.value()),
+        "set_type" => SetType::try_from(name).ok().map(|it| it.value()),
+        "max_met_category" => MaxMetCategory::try_from(name).ok().map(|it| it.value()),
+        "exercise_category" => ExerciseCategory::try_from(name).ok().map(|it| it.value()),
+        "bench_press_exercise_name" => BenchPressExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "calf_raise_exercise_name" => CalfRaiseExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "cardio_exercise_name" => CardioExerciseName::try_from(name).ok().map(|it| it.value()),
+        "carry_exercise_name" => CarryExerciseName::try_from(name).ok().map(|it| it.value()),
+        "chop_exercise_name" => ChopExerciseName::try_from(name).ok().map(|it| it.value()),
+        "core_exercise_name" => CoreExerciseName::try_from(name).ok().map(|it| it.value()),
+        "crunch_exercise_name" => CrunchExerciseName::try_from(name).ok().map(|it| it.value()),
+        "curl_exercise_name" => CurlExerciseName::try_from(name).ok().map(|it| it.value()),
+        "deadlift_exercise_name" => DeadliftExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "flye_exercise_name" => FlyeExerciseName::try_from(name).ok().map(|it| it.value()),
+        "hip_raise_exercise_name" => HipRaiseExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "hip_stability_exercise_name" => HipStabilityExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "hip_swing_exercise_name" => HipSwingExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "hyperextension_exercise_name" => HyperextensionExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "lateral_raise_exercise_name" => LateralRaiseExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "leg_curl_exercise_name" => LegCurlExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "leg_raise_exercise_name" => LegRaiseExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "lunge_exercise_name" => LungeExerciseName::try_from(name).ok().map(|it| it.value()),
+        "olympic_lift_exercise_name" => OlympicLiftExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "plank_exercise_name" => PlankExerciseName::try_from(name).ok().map(|it| it.value()),
+        "plyo_exercise_name" => PlyoExerciseName::try_from(name).ok().map(|it| it.value()),
+        "pull_up_exercise_name" => PullUpExerciseName::try_from(name).ok().map(|it| it.value()),
+        "push_up_exercise_name" => PushUpExerciseName::try_from(name).ok().map(|it| it.value()),
+        "row_exercise_name" => RowExerciseName::try_from(name).ok().map(|it| it.value()),
+        "shoulder_press_exercise_name" => ShoulderPressExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "shoulder_stability_exercise_name" => ShoulderStabilityExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "shrug_exercise_name" => ShrugExerciseName::try_from(name).ok().map(|it| it.value()),
+        "sit_up_exercise_name" => SitUpExerciseName::try_from(name).ok().map(|it| it.value()),
+        "squat_exercise_name" => SquatExerciseName::try_from(name).ok().map(|it| it.value()),
+        "total_body_exercise_name" => TotalBodyExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "triceps_extension_exercise_name" => TricepsExtensionExerciseName::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "warm_up_exercise_name" => WarmUpExerciseName::try_from(name).ok().map(|it| it.value()),
+        "run_exercise_name" => RunExerciseName::try_from(name).ok().map(|it| it.value()),
+        "water_type" => WaterType::try_from(name).ok().map(|it| it.value()),
+        "tissue_model_type" => TissueModelType::try_from(name).ok().map(|it| it.value()),
+        "dive_gas_status" => DiveGasStatus::try_from(name).ok().map(|it| it.value()),
+        "dive_alert" => DiveAlert::try_from(name).ok().map(|it| it.value()),
+        "dive_alarm_type" => DiveAlarmType::try_from(name).ok().map(|it| it.value()),
+        "dive_backlight_mode" => DiveBacklightMode::try_from(name).ok().map(|it| it.value()),
+        "sleep_level" => SleepLevel::try_from(name).ok().map(|it| it.value()),
+        "spo2_measurement_type" => Spo2MeasurementType::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "ccr_setpoint_switch_mode" => CcrSetpointSwitchMode::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "dive_gas_mode" => DiveGasMode::try_from(name).ok().map(|it| it.value()),
+        "favero_product" => FaveroProduct::try_from(name).ok().map(|it| it.value()),
+        "split_type" => SplitType::try_from(name).ok().map(|it| it.value()),
+        "climb_pro_event" => ClimbProEvent::try_from(name).ok().map(|it| it.value()),
+        "gas_consumption_rate_type" => GasConsumptionRateType::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "tap_sensitivity" => TapSensitivity::try_from(name).ok().map(|it| it.value()),
+        "radar_threat_level_type" => RadarThreatLevelType::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "max_met_speed_source" => MaxMetSpeedSource::try_from(name).ok().map(|it| it.value()),
+        "max_met_heart_rate_source" => MaxMetHeartRateSource::try_from(name)
+            .ok()
+            .map(|it| it.value()),
+        "hrv_status" => HrvStatus::try_from(name).ok().map(|it| it.value()),
+        "no_fly_time_mode" => NoFlyTimeMode::try_from(name).ok().map(|it| it.value()),
+        _ => None,
+    }
+}
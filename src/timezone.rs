@@ -0,0 +1,67 @@
+use crate::decoder::Messages;
+use crate::fit;
+use chrono::FixedOffset;
+
+/// Messages carrying both `timestamp` and `local_timestamp`, in order of preference
+const OFFSET_SOURCES: [&str; 2] = ["activity", "monitoring_info"];
+
+/// Timestamp fields that receive a local time equivalent, `(message, field, local field)`
+const LOCAL_TIME_FIELDS: [(&str, &str, &str); 5] = [
+    ("record", "timestamp", "local_timestamp"),
+    ("lap", "timestamp", "local_timestamp"),
+    ("lap", "start_time", "local_start_time"),
+    ("session", "timestamp", "local_timestamp"),
+    ("session", "start_time", "local_start_time"),
+];
+
+/// Derive the device UTC offset from `local_timestamp - timestamp`
+///
+/// `activity` messages are preferred, `monitoring_info` is used as a fallback.
+/// Returns `None` when neither message carries both timestamps.
+pub fn utc_offset(messages: &Messages) -> Option<FixedOffset> {
+    OFFSET_SOURCES
+        .iter()
+        .filter_map(|name| messages.get(*name))
+        .flatten()
+        .find_map(|record| {
+            let timestamp = match record.get("timestamp") {
+                Some(fit::Value::DateTime(val)) => val.timestamp(),
+                _ => return None,
+            };
+            // a `local_date_time` value, typed but without a name
+            let local_timestamp = record
+                .get("local_timestamp")
+                .filter(|it| it.is_valid())?
+                .try_as_i64()
+                .ok()?
+                + fit::FIT_EPOCH_OFFSET;
+            FixedOffset::east_opt(i32::try_from(local_timestamp - timestamp).ok()?)
+        })
+}
+
+/// Attach local time equivalents to the `record`, `lap` and `session` timestamps
+///
+/// `timestamp` is mirrored to `local_timestamp` and `start_time` to `local_start_time`.
+pub fn apply_local_times(messages: &mut Messages, offset: &FixedOffset) {
+    for (message, field, local_field) in LOCAL_TIME_FIELDS {
+        let records = if let Some(records) = messages.get_mut(message) {
+            records
+        } else {
+            continue;
+        };
+        for record in records {
+            let local = match record.get(field) {
+                Some(fit::Value::DateTime(val)) => val.with_timezone(offset),
+                _ => continue,
+            };
+            record.insert(local_field, fit::Value::LocalDateTime(local));
+        }
+    }
+}
+
+/// Derive the UTC offset and attach local times, returns the offset that was applied
+pub fn localize(messages: &mut Messages) -> Option<FixedOffset> {
+    let offset = utc_offset(messages)?;
+    apply_local_times(messages, &offset);
+    Some(offset)
+}
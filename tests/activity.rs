@@ -0,0 +1,202 @@
+use chrono::Duration;
+use fit::activity::Activity;
+use fit::decoder::{Decoder, Messages, Record};
+use fit::profile::types::Sport;
+mod data;
+
+fn activity_messages() -> Messages {
+    let buf = std::fs::read("tests/data/Activity.fit").unwrap();
+    Decoder::new(&buf).decode().unwrap().1
+}
+
+fn sport(value: u8, name: &str) -> fit::Value {
+    fit::Value::Typed(fit::TypedValue {
+        ty: "sport",
+        value: Box::new(fit::Value::Enum(value)),
+        name: Some(name.to_string()),
+    })
+}
+
+/// The one hour paddle split into a run of two 10 minute laps, a 1 minute transition and a ride
+/// for the rest of the hour
+fn multisport() -> Messages {
+    let mut messages = activity_messages();
+    let start = messages["session"][0]["start_time"].clone();
+    let fit::Value::DateTime(start) = start else {
+        panic!("{start:?}")
+    };
+    let end = start + Duration::seconds(3601);
+    let lap_ends = [600, 1200, 1260].map(|it| start + Duration::seconds(it));
+    let lap_template = messages["lap"][0].clone();
+    let laps = [start, lap_ends[0], lap_ends[1], lap_ends[2]]
+        .into_iter()
+        .zip(lap_ends.into_iter().chain([end]))
+        .enumerate()
+        .map(|(index, (start, end))| {
+            let mut lap = lap_template.clone();
+            lap.insert("message_index", fit::Value::UInt16(index as u16));
+            lap.insert("timestamp", fit::Value::DateTime(end));
+            lap.insert("start_time", fit::Value::DateTime(start));
+            lap
+        })
+        .collect::<Vec<_>>();
+    let session_template = messages["session"][0].clone();
+    let sessions = [
+        (sport(1, "running"), 0, 2, start, lap_ends[1]),
+        (sport(3, "transition"), 2, 1, lap_ends[1], lap_ends[2]),
+        (sport(2, "cycling"), 3, 1, lap_ends[2], end),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(index, (sport, first_lap_index, num_laps, start, end))| {
+        let mut session = session_template.clone();
+        session.insert("message_index", fit::Value::UInt16(index as u16));
+        session.insert("timestamp", fit::Value::DateTime(end));
+        session.insert("start_time", fit::Value::DateTime(start));
+        session.insert("sport", sport);
+        session.insert("first_lap_index", fit::Value::UInt16(first_lap_index));
+        session.insert("num_laps", fit::Value::UInt16(num_laps));
+        session
+    })
+    .collect::<Vec<_>>();
+    messages.insert("lap".to_string(), laps);
+    messages.insert("session".to_string(), sessions);
+    messages
+}
+
+fn counts<'a, T: 'a>(groups: impl Iterator<Item = &'a Vec<T>>) -> Vec<usize> {
+    groups.map(|it| it.len()).collect()
+}
+
+#[test]
+fn single_session() {
+    let activity = Activity::new(activity_messages());
+    assert_eq!(activity.sessions.len(), 1);
+    let session = &activity.sessions[0];
+    assert_eq!(session.laps.len(), 1);
+    assert_eq!(session.records().count(), 3601);
+    assert_eq!(session.events.len(), 2);
+    assert_eq!(session.sport(), Some(Sport::StandUpPaddleboarding));
+    assert!(!activity.is_multisport());
+    assert_eq!(activity.sports(), [Sport::StandUpPaddleboarding]);
+
+    assert_eq!(
+        activity.file_id.as_ref().unwrap()["type"].to_string(),
+        "activity"
+    );
+    assert_eq!(
+        activity.activity.as_ref().unwrap()["num_sessions"],
+        fit::Value::UInt16(1)
+    );
+    assert_eq!(activity.devices.len(), 1);
+    assert!(activity.laps.is_empty() && activity.records.is_empty());
+    assert!(activity.events.is_empty());
+    // messages the model does not place are kept
+    assert_eq!(
+        activity.other.keys().collect::<Vec<_>>(),
+        ["developer_data_id", "field_description"]
+    );
+    assert_eq!(activity.other["field_description"].len(), 2);
+}
+
+#[test]
+fn multisport_by_lap_indices() {
+    let activity = Activity::new(multisport());
+    assert!(activity.is_multisport());
+    assert_eq!(activity.sports(), [Sport::Running, Sport::Cycling]);
+    assert_eq!(activity.legs().count(), 2);
+    assert_eq!(activity.transitions().count(), 1);
+    assert_eq!(
+        activity
+            .sessions
+            .iter()
+            .map(|it| it.laps.len())
+            .collect::<Vec<_>>(),
+        [2, 1, 1]
+    );
+    assert_eq!(
+        counts(activity.laps().map(|it| &it.records)),
+        [601, 600, 60, 2340]
+    );
+    assert_eq!(activity.records().count(), 3601);
+    assert_eq!(
+        counts(activity.sessions.iter().map(|it| &it.events)),
+        [1, 0, 1]
+    );
+
+    let transition = &activity.sessions[1];
+    let time = transition.start_time().unwrap() + Duration::seconds(30);
+    assert_eq!(activity.session_at(time), Some(transition));
+    let records = transition.records().collect::<Vec<_>>();
+    assert_eq!(
+        records[0]["timestamp"],
+        fit::Value::DateTime(transition.laps[0].start_time().unwrap() + Duration::seconds(1))
+    );
+}
+
+#[test]
+fn multisport_by_lap_times() {
+    let mut messages = multisport();
+    for session in messages.get_mut("session").unwrap() {
+        session.shift_remove("first_lap_index");
+    }
+    let lap_indices = |activity: &Activity| {
+        activity
+            .sessions
+            .iter()
+            .map(|session| {
+                session
+                    .laps
+                    .iter()
+                    .map(|it| it.lap["message_index"].clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+    let by_times = Activity::new(messages);
+    let by_indices = Activity::new(multisport());
+    assert_eq!(lap_indices(&by_times), lap_indices(&by_indices));
+    assert!(by_times.laps().eq(by_indices.laps()));
+}
+
+#[test]
+fn laps_missing_from_sessions() {
+    let mut messages = multisport();
+    messages.get_mut("session").unwrap()[2].insert("num_laps", fit::Value::UInt16(0));
+    let activity = Activity::new(messages);
+    assert!(activity.sessions[2].laps.is_empty());
+    assert_eq!(activity.laps.len(), 1);
+    assert_eq!(activity.records().count(), 3601);
+}
+
+#[test]
+fn lengths() {
+    let mut messages = multisport();
+    let lap = &messages["lap"][3];
+    let start = lap["start_time"].clone();
+    let fit::Value::DateTime(start) = start else {
+        panic!("{start:?}")
+    };
+    let lengths = (1..=4)
+        .map(|it| {
+            Record::from([(
+                "timestamp",
+                fit::Value::DateTime(start + Duration::seconds(it * 30)),
+            )])
+        })
+        .collect();
+    messages.insert("length".to_string(), lengths);
+    let activity = Activity::new(messages);
+    assert_eq!(activity.sessions[2].lengths().count(), 4);
+    assert_eq!(activity.lengths().count(), 4);
+}
+
+#[test]
+fn file_without_sessions() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let activity = Activity::from(messages);
+    assert!(activity.sessions.is_empty());
+    assert_eq!(counts(activity.laps.iter().map(|it| &it.records)), [2, 2]);
+    assert_eq!(activity.records().count(), 4);
+    assert!(!activity.is_multisport());
+}
@@ -0,0 +1,105 @@
+use fit::decoder::Decoder;
+use fit::profile::types;
+use fit::{FlagsValue, Value};
+mod data;
+
+#[test]
+fn bit_mask_fields_should_list_flags() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_CAPABILITIES);
+    let (errors, messages) = decoder.decode().unwrap();
+    assert_eq!(errors.len(), 0);
+    let capabilities = &messages.get("capabilities").unwrap()[0];
+    assert_eq!(
+        capabilities.get("sports"),
+        Some(&Value::Flags(FlagsValue {
+            ty: "sport_bits_0",
+            value: Box::new(Value::UInt8(0x06)),
+            flags: vec!["running".to_string(), "cycling".to_string()],
+            masked_value: None,
+        }))
+    );
+    // undefined bits are kept in the raw value
+    assert_eq!(
+        capabilities.get("workouts_supported"),
+        Some(&Value::Flags(FlagsValue {
+            ty: "workout_capabilities",
+            value: Box::new(Value::UInt32z(0x00010101)),
+            flags: vec!["interval".to_string(), "heart_rate".to_string()],
+            masked_value: None,
+        }))
+    );
+}
+
+#[test]
+fn bit_flags_should_serialize_with_raw_value() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_CAPABILITIES);
+    let (_, messages) = decoder.decode().unwrap();
+    let capabilities = &messages.get("capabilities").unwrap()[0];
+    assert_eq!(
+        serde_json::to_string(capabilities.get("sports").unwrap()).unwrap(),
+        r#"{"value":6,"flags":["running","cycling"]}"#
+    );
+}
+
+#[test]
+fn flags_from_raw_value() {
+    assert_eq!(
+        types::SportBits1::flags(&Value::UInt8z(0x09)),
+        vec![types::SportBits1::Tennis, types::SportBits1::Walking]
+    );
+    assert_eq!(types::bit_flags("sport", &Value::Enum(1)), None);
+}
+
+#[test]
+fn value_masks_should_be_kept_next_to_flags() {
+    let buf = std::fs::read("tests/data/WithGearChangeData.fit").unwrap();
+    let mut decoder = Decoder::new(&buf);
+    let (_, messages) = decoder.decode().unwrap();
+    let balance = messages.get("record").unwrap()[28]
+        .get("left_right_balance")
+        .unwrap();
+    assert_eq!(
+        balance,
+        &Value::Flags(FlagsValue {
+            ty: "left_right_balance",
+            value: Box::new(Value::UInt8(0x80 | 34)),
+            flags: vec!["right".to_string()],
+            masked_value: Some(34),
+        })
+    );
+    assert_eq!(balance.to_string(), "34|right");
+    assert_eq!(
+        serde_json::to_string(balance).unwrap(),
+        r#"{"value":162,"flags":["right"],"masked_value":34}"#
+    );
+
+    let mut decoder = Decoder::new(&data::FIT_FILE_SESSION_PEDALING);
+    let (_, messages) = decoder.decode().unwrap();
+    let session = &messages.get("session").unwrap()[0];
+    let Some(Value::Flags(balance)) = session.get("left_right_balance") else {
+        panic!("left_right_balance should be a bit mask");
+    };
+    assert_eq!(balance.ty, "left_right_balance_100");
+    assert_eq!(balance.flags, vec!["right".to_string()]);
+    assert_eq!(balance.masked_value, Some(4850));
+}
+
+#[test]
+fn multi_bit_values_are_not_bit_masks() {
+    // `reserved = 0x7000` is neither a flag nor a low value mask
+    assert_eq!(
+        types::bit_flags("message_index", &Value::UInt16(0x8001)),
+        None
+    );
+    // a single flag of a non `z` base type is a named value
+    assert_eq!(
+        types::bit_flags("date_time", &Value::UInt32(0x10000000)),
+        None
+    );
+    assert_eq!(types::value_mask("left_right_balance"), Some(0x7F));
+    assert_eq!(types::value_mask("sport_bits_0"), None);
+    assert_eq!(
+        types::LeftRightBalance100::flags(&Value::UInt16(0x8000 | 4850)),
+        vec![types::LeftRightBalance100::Right]
+    );
+}
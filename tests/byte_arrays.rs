@@ -0,0 +1,36 @@
+use fit::decoder::Decoder;
+use fit::Value;
+mod data;
+
+#[test]
+fn developer_ids_as_uuid() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_BYTE_ARRAYS);
+    let (_, messages) = decoder.decode().unwrap();
+    let developer_data_id = &messages.get("developer_data_id").unwrap()[0];
+    assert_eq!(
+        developer_data_id.get("developer_id"),
+        Some(&Value::String(
+            "10111213-1415-1617-1819-1a1b1c1d1e1f".to_string()
+        ))
+    );
+    assert_eq!(
+        developer_data_id.get("application_id"),
+        Some(&Value::String(
+            "8e2c5b41-9a3f-4d21-b077-12cde560aaff".to_string()
+        ))
+    );
+}
+
+#[test]
+fn byte_arrays_as_bytes() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_BYTE_ARRAYS);
+    let (errors, messages) = decoder.decode().unwrap();
+    let memo_globs = messages.get("memo_glob").unwrap();
+    // 0xFF bytes are kept as long as not all of them are invalid
+    let memo = memo_globs[0].get("memo").unwrap();
+    assert_eq!(memo, &Value::Bytes(vec![0x48, 0xFF, 0x69, 0xFF]));
+    assert_eq!(memo.to_string(), "48ff69ff");
+    assert_eq!(serde_json::to_string(memo).unwrap(), "\"48ff69ff\"");
+    assert!(!memo_globs[1].contains_key("memo"));
+    assert_eq!(errors.len(), 1);
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "catalog")]
+use fit::catalog::{scan, summarize, CatalogEntry, ScanOptions};
+use std::fs;
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+mod data;
+
+fn zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    for (name, bytes) in entries {
+        writer.start_file(*name, options).unwrap();
+        writer.write_all(bytes).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+/// A Garmin export like tree: loose files, an archive holding an archive, and broken files
+fn history(name: &str) -> PathBuf {
+    let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("activities")).unwrap();
+    fs::copy(
+        "tests/data/Activity.fit",
+        root.join("activities/Activity.fit"),
+    )
+    .unwrap();
+    fs::write(root.join("activities/course.FIT"), data::FIT_FILE_COURSE).unwrap();
+    fs::write(root.join("activities/notes.txt"), "not a FIT file").unwrap();
+    fs::write(root.join("truncated.fit"), &data::FIT_FILE_COURSE[..100]).unwrap();
+    fs::write(root.join("broken.zip"), "not a zip archive").unwrap();
+    let inner = zip(&[("run.fit", &data::FIT_FILE_RUN)]);
+    let export = zip(&[
+        ("DI_CONNECT/chained.fit", &data::FIT_FILE_CHAINED),
+        ("DI_CONNECT/UploadedFiles_0-_Part1.zip", &inner),
+        ("DI_CONNECT/summary.json", b"{}"),
+    ]);
+    fs::write(root.join("export.zip"), export).unwrap();
+    root
+}
+
+fn relative<'a>(root: &Path, entries: &'a [CatalogEntry]) -> Vec<(String, &'a CatalogEntry)> {
+    let prefix = format!("{}/", root.to_string_lossy());
+    entries
+        .iter()
+        .map(|it| (it.path.strip_prefix(&prefix).unwrap().to_string(), it))
+        .collect()
+}
+
+#[test]
+fn scan_directory_and_archives() {
+    let root = history("catalog_history");
+    let entries = scan(&root, &ScanOptions::default()).unwrap();
+    let entries = relative(&root, &entries);
+    assert_eq!(
+        entries
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>(),
+        [
+            "activities/Activity.fit",
+            "activities/course.FIT",
+            "broken.zip",
+            "export.zip/DI_CONNECT/chained.fit",
+            "export.zip/DI_CONNECT/UploadedFiles_0-_Part1.zip/run.fit",
+            "truncated.fit",
+        ]
+    );
+
+    let activity = entries[0].1;
+    assert_eq!(activity.file_type.as_deref(), Some("activity"));
+    assert_eq!(
+        activity.time_created.map(|it| it.to_rfc3339()).as_deref(),
+        Some("2021-07-20T21:11:20+00:00")
+    );
+    assert_eq!(activity.sport.as_deref(), Some("stand_up_paddleboarding"));
+    assert_eq!(activity.duration, Some(3601.0));
+    assert_eq!(activity.device.as_deref(), Some("FIT Cookbook"));
+    assert_eq!((activity.errors, activity.error.as_deref()), (0, None));
+
+    assert_eq!(entries[1].1.file_type.as_deref(), Some("course"));
+    assert!(entries[2].1.error.is_some());
+    assert_eq!(entries[3].1.file_type.as_deref(), Some("settings"));
+    assert_eq!(entries[3].1.device.as_deref(), Some("development"));
+    // the run fixture has no file_id, only its session
+    assert_eq!(entries[4].1.file_type, None);
+    assert!(entries[4].1.distance.is_some());
+    assert!(entries[5].1.error.is_some());
+}
+
+#[test]
+fn thread_count_does_not_change_the_catalog() {
+    let root = history("catalog_threads");
+    let one = scan(
+        &root,
+        &ScanOptions {
+            threads: 1,
+            ..ScanOptions::default()
+        },
+    )
+    .unwrap();
+    let many = scan(
+        &root,
+        &ScanOptions {
+            threads: 8,
+            ..ScanOptions::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(one, many);
+}
+
+#[test]
+fn single_file_and_missing_root() {
+    let root = history("catalog_single");
+    let entries = scan(&root.join("export.zip"), &ScanOptions::default()).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(scan(&root.join("missing"), &ScanOptions::default()).is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinked_directories_are_not_followed() {
+    let root = history("catalog_symlinks");
+    std::os::unix::fs::symlink(&root, root.join("activities/loop")).unwrap();
+    std::os::unix::fs::symlink(
+        root.join("activities/Activity.fit"),
+        root.join("linked.fit"),
+    )
+    .unwrap();
+    let entries = scan(&root, &ScanOptions::default()).unwrap();
+    let entries = relative(&root, &entries);
+    assert_eq!(entries.len(), 7);
+    assert!(entries.iter().all(|(path, _)| !path.contains("loop")));
+    let linked = entries
+        .iter()
+        .find(|(path, _)| path == "linked.fit")
+        .unwrap();
+    assert_eq!(linked.1.file_type.as_deref(), Some("activity"));
+}
+
+#[test]
+fn entries_over_the_size_limit_are_not_read() {
+    let root = history("catalog_limit");
+    let options = ScanOptions {
+        max_entry_size: data::FIT_FILE_CHAINED.len() as u64,
+        ..ScanOptions::default()
+    };
+    let entries = scan(&root.join("export.zip"), &options).unwrap();
+    let entries = relative(&root, &entries);
+    assert_eq!(entries[0].0, "export.zip/DI_CONNECT/chained.fit");
+    assert_eq!(entries[0].1.error, None);
+    // the nested archive is larger than the chained file
+    assert_eq!(
+        entries[1].0,
+        "export.zip/DI_CONNECT/UploadedFiles_0-_Part1.zip"
+    );
+    assert_eq!(
+        entries[1].1.error.as_deref(),
+        Some("entry larger than 232 bytes")
+    );
+}
+
+#[test]
+fn failed_decoding_keeps_decoded_messages() {
+    let mut bytes = data::FIT_FILE_COURSE.to_vec();
+    *bytes.last_mut().unwrap() ^= 0xFF;
+    let entry = summarize("course.fit", &bytes);
+    assert_eq!(entry.file_type.as_deref(), Some("course"));
+    assert_eq!(entry.error.as_deref(), Some("CRC invalid."));
+
+    let entry = summarize("truncated.fit", &data::FIT_FILE_COURSE[..100]);
+    assert_eq!(entry.file_type.as_deref(), Some("course"));
+    assert!(entry.error.unwrap().starts_with("Out of bounds read"));
+
+    // a string typed timestamp makes the decoder panic after the file_id message
+    let mut bytes = data::FIT_FILE_COURSE.to_vec();
+    let at = bytes
+        .windows(3)
+        .position(|it| it == [253, 4, 0x86])
+        .unwrap();
+    bytes[at + 2] = 0x07;
+    let entry = summarize("panic.fit", &bytes);
+    assert_eq!(entry.file_type.as_deref(), Some("course"));
+    assert!(entry.error.unwrap().starts_with("decoder panicked: "));
+}
@@ -18,3 +18,18 @@ fn have_1_message() {
     let (_, messages) = result.unwrap();
     assert_eq!(messages.get("file_id").map(|it| it.len()), Some(1))
 }
+
+#[test]
+fn decode_each_file() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_CHAINED);
+    let (_, files) = decoder.decode_files().unwrap();
+    assert_eq!(files.len(), 2);
+    for file in &files {
+        assert_eq!(file.header.data_size, 100);
+        assert_eq!(file.header.data_type, ".FIT");
+        assert_eq!(
+            file.messages.keys().collect::<Vec<_>>(),
+            ["file_id", "monitoring_hr_data"]
+        );
+    }
+}
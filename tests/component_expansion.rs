@@ -42,25 +42,30 @@ fn test_expansion_with_enum_components() {
     let mut decoder = Decoder::new(&data::FIT_FILE_MONITORING);
     let (_, messages) = decoder.decode().unwrap();
     let monitoring = messages.get("monitoring").unwrap();
+    let activity_type = |idx: usize| match monitoring[idx].get("activity_type") {
+        Some(fit::Value::Typed(val)) => Some(val.clone()),
+        _ => None,
+    };
     assert_eq!(
-        monitoring[0].get("activity_type"),
-        Some(&fit::Value::String(
-            types::ActivityType::Sedentary.to_string()
-        ))
+        activity_type(0).and_then(|it| it.to_type::<types::ActivityType>()),
+        Some(types::ActivityType::Sedentary)
     );
     assert_eq!(monitoring[0].get("intensity"), Some(&fit::Value::UInt8(3)));
 
     assert_eq!(
-        monitoring[1].get("activity_type"),
-        Some(&fit::Value::String(
-            types::ActivityType::Generic.to_string()
-        ))
+        activity_type(1).and_then(|it| it.to_type::<types::ActivityType>()),
+        Some(types::ActivityType::Generic)
     );
     assert_eq!(monitoring[1].get("intensity"), Some(&fit::Value::UInt8(0)));
 
+    // unknown values keep the profile type and the raw value
     assert_eq!(
         monitoring[2].get("activity_type"),
-        Some(&fit::Value::Enum(30))
+        Some(&fit::Value::Typed(fit::TypedValue {
+            ty: "activity_type",
+            value: Box::new(fit::Value::Enum(30)),
+            name: None,
+        }))
     );
     assert_eq!(monitoring[2].get("intensity"), Some(&fit::Value::UInt8(6)));
 
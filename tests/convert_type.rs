@@ -1,4 +1,5 @@
 use fit::decoder::Decoder;
+use fit::profile::types::Manufacturer;
 mod data;
 
 #[test]
@@ -14,3 +15,32 @@ fn should_converted() {
         Some(String::from("activity"))
     )
 }
+
+#[test]
+fn unknown_values_keep_their_type() {
+    let csv = [
+        "Type,Local Number,Message",
+        "Definition,0,file_id,type,1,,manufacturer,1,,product,1,",
+        "Data,0,file_id,type,\"activity\",,manufacturer,\"9999\",,product,\"1\",",
+        "Data,0,file_id,type,\"activity\",,manufacturer,\"garmin\",,product,\"65000\",",
+    ]
+    .join("\n");
+    let bytes = fit::fit_csv::to_fit(&csv).unwrap();
+    let (_, messages) = Decoder::new(&bytes).decode().unwrap();
+    let Some(fit::Value::Typed(manufacturer)) = messages["file_id"][0].get("manufacturer") else {
+        panic!("{:?}", messages["file_id"][0]);
+    };
+    assert_eq!(manufacturer.ty, "manufacturer");
+    assert_eq!(*manufacturer.value, fit::Value::UInt16(9999));
+    assert_eq!(manufacturer.name, None);
+    assert_eq!(manufacturer.to_type::<Manufacturer>(), None);
+    assert_eq!(messages["file_id"][0]["manufacturer"].to_string(), "9999");
+
+    let Some(fit::Value::Typed(product)) = messages["file_id"][1].get("garmin_product") else {
+        panic!("{:?}", messages["file_id"][1]);
+    };
+    assert_eq!(
+        (product.ty, product.value.as_ref(), product.name.as_deref()),
+        ("garmin_product", &fit::Value::UInt16(65000), None)
+    );
+}
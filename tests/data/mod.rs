@@ -122,3 +122,109 @@ pub const WORKOUT_800M_REPEATS_BIG_ENDIAN: [u8; 235] = [
     0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x01, 0x86, 0xA0, 0x00, 0x04, 0x03, 0x01, 0x01, 0x00, 0x00, 0x00,
     0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x87, 0x67,
 ];
+
+pub const FIT_FILE_TIMESTAMP_CORRELATION: [u8; 156] = [
+    0x0E, 0x20, 0x54, 0x08, 0x8C, 0x00, 0x00, 0x00, 0x2E, 0x46, 0x49, 0x54, 0xB1, 0xE4, 0x40, 0x00,
+    0x00, 0x00, 0x00, 0x03, 0x00, 0x01, 0x00, 0x01, 0x02, 0x84, 0x04, 0x04, 0x86, 0x00, 0x04, 0xFF,
+    0x00, 0x00, 0xCA, 0x9A, 0x3B, 0x41, 0x00, 0x00, 0xA5, 0x00, 0x04, 0xFD, 0x04, 0x86, 0x00, 0x02,
+    0x84, 0x01, 0x06, 0x84, 0x02, 0x06, 0x84, 0x01, 0xE3, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x28, 0x00, 0x50, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x42, 0x00, 0x00, 0xA2, 0x00, 0x04,
+    0xFD, 0x04, 0x86, 0x00, 0x02, 0x84, 0x01, 0x04, 0x86, 0x02, 0x02, 0x84, 0x02, 0x00, 0xCA, 0x9A,
+    0x3B, 0x00, 0x40, 0xE8, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01, 0xED, 0x03, 0x00, 0x00, 0xFA, 0x00,
+    0x00, 0x00, 0x28, 0x00, 0x50, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x43, 0x00, 0x00, 0xB2,
+    0x00, 0x03, 0xFD, 0x04, 0x86, 0x00, 0x02, 0x84, 0x01, 0x08, 0x86, 0x03, 0xF2, 0x03, 0x00, 0x00,
+    0x00, 0x00, 0x50, 0x69, 0x0F, 0x00, 0x44, 0x6B, 0x0F, 0x00, 0x4B, 0x76,
+];
+
+pub const FIT_FILE_CAPABILITIES: [u8; 57] = [
+    0x0E, 0x20, 0x54, 0x08, 0x29, 0x00, 0x00, 0x00, 0x2E, 0x46, 0x49, 0x54, 0x7B, 0xA3, 0x40, 0x00,
+    0x00, 0x00, 0x00, 0x03, 0x00, 0x01, 0x00, 0x01, 0x02, 0x84, 0x04, 0x04, 0x86, 0x00, 0x04, 0xFF,
+    0x00, 0x00, 0xCA, 0x9A, 0x3B, 0x41, 0x00, 0x00, 0x01, 0x00, 0x02, 0x01, 0x01, 0x0A, 0x15, 0x04,
+    0x8C, 0x01, 0x06, 0x01, 0x01, 0x01, 0x00, 0x14, 0xB1,
+];
+pub const FIT_FILE_SESSION_PEDALING: [u8; 72] = [
+    0x0E, 0x20, 0x54, 0x08, 0x38, 0x00, 0x00, 0x00, 0x2E, 0x46, 0x49, 0x54, 0xBB, 0x63, 0x40, 0x00,
+    0x00, 0x12, 0x00, 0x0B, 0x25, 0x02, 0x84, 0x65, 0x01, 0x02, 0x66, 0x01, 0x02, 0x67, 0x01, 0x02,
+    0x68, 0x01, 0x02, 0x72, 0x01, 0x01, 0x73, 0x01, 0x01, 0x74, 0x02, 0x02, 0x75, 0x02, 0x02, 0x76,
+    0x02, 0x02, 0x77, 0x02, 0x02, 0x00, 0xF2, 0x92, 0x96, 0x8C, 0x28, 0x26, 0x04, 0xFE, 0x20, 0x80,
+    0x40, 0x60, 0xA0, 0x00, 0xC0, 0x20, 0xDB, 0x6D,
+];
+pub const FIT_FILE_SUB_FIELD_REFERENCE_BASE_TYPES: [u8; 173] = [
+    0x0E, 0x20, 0x54, 0x08, 0x9D, 0x00, 0x00, 0x00, 0x2E, 0x46, 0x49, 0x54, 0x71, 0x24, 0x40, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0x01, 0x02, 0x84, 0x02, 0x02, 0x84, 0x00, 0x01, 0x00, 0x01, 0x00, 0x41,
+    0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x02, 0x8B, 0x02, 0x02, 0x84, 0x01, 0x01, 0x00, 0x01, 0x00,
+    0x42, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x01, 0x00, 0x02, 0x02, 0x84, 0x02, 0x01, 0x01, 0x00,
+    0x43, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x01, 0x02, 0x02, 0x02, 0x84, 0x03, 0x01, 0x01, 0x00,
+    0x44, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x04, 0x86, 0x02, 0x02, 0x84, 0x04, 0x01, 0x00, 0x00,
+    0x00, 0x01, 0x00, 0x45, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01, 0x02, 0x83, 0x02, 0x02, 0x84, 0x05,
+    0x07, 0x01, 0x01, 0x00, 0x46, 0x00, 0x00, 0x12, 0x00, 0x02, 0x05, 0x01, 0x00, 0x0A, 0x04, 0x86,
+    0x06, 0x01, 0xE8, 0x03, 0x00, 0x00, 0x47, 0x00, 0x00, 0x12, 0x00, 0x02, 0x05, 0x01, 0x02, 0x0A,
+    0x04, 0x86, 0x07, 0x01, 0xE8, 0x03, 0x00, 0x00, 0x48, 0x00, 0x00, 0x12, 0x00, 0x02, 0x05, 0x02,
+    0x84, 0x0A, 0x04, 0x86, 0x08, 0x01, 0x00, 0xE8, 0x03, 0x00, 0x00, 0x24, 0x52,
+];
+pub const FIT_FILE_STRING_FIELDS: [u8; 127] = [
+    0x0E, 0x20, 0x54, 0x08, 0x6F, 0x00, 0x00, 0x00, 0x2E, 0x46, 0x49, 0x54, 0xFF, 0xB9, 0x40, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x08, 0x0C, 0x07, 0x00, 0x04, 0x45, 0x64, 0x67, 0x65,
+    0xFF, 0xFE, 0x35, 0x33, 0x30, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x1B, 0x00, 0x03, 0xFE, 0x02,
+    0x84, 0x00, 0x10, 0x07, 0x08, 0x10, 0x07, 0x01, 0x00, 0x00, 0x57, 0x61, 0x72, 0x6D, 0x20, 0x75,
+    0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x45, 0x61, 0x73, 0x79, 0x00, 0x53,
+    0x74, 0x65, 0x61, 0x64, 0x79, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x43, 0x6F,
+    0x6F, 0x6C, 0x20, 0x64, 0x6F, 0x77, 0x6E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x41, 0xEF,
+];
+pub const FIT_FILE_BYTE_ARRAYS: [u8; 102] = [
+    0x0E, 0x20, 0x54, 0x08, 0x56, 0x00, 0x00, 0x00, 0x2E, 0x46, 0x49, 0x54, 0x3C, 0xC7, 0x40, 0x00,
+    0x00, 0xCF, 0x00, 0x04, 0x00, 0x10, 0x0D, 0x01, 0x10, 0x0D, 0x03, 0x01, 0x02, 0x04, 0x04, 0x86,
+    0x00, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E,
+    0x1F, 0x8E, 0x2C, 0x5B, 0x41, 0x9A, 0x3F, 0x4D, 0x21, 0xB0, 0x77, 0x12, 0xCD, 0xE5, 0x60, 0xAA,
+    0xFF, 0x00, 0x6E, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x91, 0x00, 0x02, 0xFA, 0x04, 0x86, 0x00,
+    0x04, 0x0D, 0x01, 0x00, 0x00, 0x00, 0x00, 0x48, 0xFF, 0x69, 0xFF, 0x01, 0x01, 0x00, 0x00, 0x00,
+    0xFF, 0xFF, 0xFF, 0xFF, 0xA9, 0x32,
+];
+pub const FIT_FILE_MEMO_GLOB: [u8; 233] = [
+    0x0E, 0x20, 0x54, 0x08, 0xD9, 0x00, 0x00, 0x00, 0x2E, 0x46, 0x49, 0x54, 0x74, 0xE7, 0x40, 0x00,
+    0x00, 0x1B, 0x00, 0x02, 0xFE, 0x02, 0x84, 0x08, 0x08, 0x07, 0x00, 0x00, 0x00, 0x73, 0x68, 0x6F,
+    0x72, 0x74, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x74, 0x72, 0x75, 0x6E, 0x63, 0x00, 0x00, 0x00,
+    0x41, 0x00, 0x00, 0x1F, 0x00, 0x01, 0x05, 0x08, 0x07, 0x01, 0x4C, 0x6F, 0x6F, 0x70, 0x00, 0x00,
+    0x00, 0x00, 0x42, 0x00, 0x00, 0x91, 0x00, 0x05, 0xFA, 0x04, 0x86, 0x01, 0x02, 0x84, 0x02, 0x02,
+    0x84, 0x03, 0x01, 0x02, 0x04, 0x08, 0x0A, 0x02, 0x02, 0x00, 0x00, 0x00, 0x1B, 0x00, 0x01, 0x00,
+    0x08, 0x64, 0x65, 0x6E, 0x63, 0x65, 0x20, 0x61, 0x62, 0x02, 0x00, 0x00, 0x00, 0x00, 0x1B, 0x00,
+    0x01, 0x00, 0x08, 0x48, 0x6F, 0x6C, 0x64, 0x20, 0x39, 0x30, 0x25, 0x02, 0x03, 0x00, 0x00, 0x00,
+    0x1B, 0x00, 0x01, 0x00, 0x08, 0x6F, 0x76, 0x65, 0x20, 0x39, 0x35, 0x00, 0x00, 0x02, 0x01, 0x00,
+    0x00, 0x00, 0x1B, 0x00, 0x01, 0x00, 0x08, 0x20, 0x46, 0x54, 0x50, 0x2C, 0x20, 0x63, 0x61, 0x02,
+    0x00, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x05, 0x4C, 0x61, 0x6B, 0x65, 0x73, 0x69, 0x64,
+    0x65, 0x02, 0x01, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x05, 0x20, 0x6C, 0x6F, 0x6F, 0x70,
+    0x20, 0x76, 0x69, 0x02, 0x02, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x05, 0x61, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6F, 0x6C, 0x02, 0x03, 0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00, 0x05, 0x64,
+    0x20, 0x6D, 0x69, 0x6C, 0x6C, 0x00, 0x00, 0x72, 0xF5,
+];
+pub const FIT_FILE_COURSE: [u8; 241] = [
+    0x0E, 0x20, 0x54, 0x08, 0xE1, 0x00, 0x00, 0x00, 0x2E, 0x46, 0x49, 0x54, 0x76, 0x55, 0x40, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x04, 0x04, 0x86, 0x00, 0x06, 0x00, 0xCA, 0x9A, 0x3B,
+    0x41, 0x00, 0x00, 0x1F, 0x00, 0x02, 0x04, 0x01, 0x00, 0x05, 0x10, 0x07, 0x01, 0x02, 0x52, 0x69,
+    0x64, 0x67, 0x65, 0x20, 0x3C, 0x6C, 0x6F, 0x6F, 0x70, 0x3E, 0x00, 0x00, 0x00, 0x00, 0x42, 0x00,
+    0x00, 0x14, 0x00, 0x06, 0xFD, 0x04, 0x86, 0x00, 0x04, 0x85, 0x01, 0x04, 0x85, 0x02, 0x02, 0x84,
+    0x03, 0x01, 0x02, 0x07, 0x02, 0x84, 0x02, 0x00, 0xCA, 0x9A, 0x3B, 0x72, 0x1C, 0xC7, 0x21, 0x11,
+    0x11, 0x11, 0xA9, 0xB8, 0x0B, 0x78, 0xC8, 0x00, 0x02, 0x01, 0xCA, 0x9A, 0x3B, 0x1B, 0x21, 0xC7,
+    0x21, 0x68, 0x0C, 0x11, 0xA9, 0xBE, 0x0B, 0x7D, 0xD2, 0x00, 0x02, 0x02, 0xCA, 0x9A, 0x3B, 0xC4,
+    0x25, 0xC7, 0x21, 0xBF, 0x07, 0x11, 0xA9, 0xC4, 0x0B, 0x82, 0xDC, 0x00, 0x02, 0x03, 0xCA, 0x9A,
+    0x3B, 0x6D, 0x2A, 0xC7, 0x21, 0x16, 0x03, 0x11, 0xA9, 0xCA, 0x0B, 0x87, 0xE6, 0x00, 0x43, 0x00,
+    0x00, 0x13, 0x00, 0x02, 0xFD, 0x04, 0x86, 0x02, 0x04, 0x86, 0x03, 0x01, 0xCA, 0x9A, 0x3B, 0x00,
+    0xCA, 0x9A, 0x3B, 0x03, 0x03, 0xCA, 0x9A, 0x3B, 0x02, 0xCA, 0x9A, 0x3B, 0x44, 0x00, 0x00, 0x20,
+    0x00, 0x05, 0x01, 0x04, 0x86, 0x02, 0x04, 0x85, 0x03, 0x04, 0x85, 0x05, 0x01, 0x00, 0x06, 0x10,
+    0x07, 0x04, 0x02, 0xCA, 0x9A, 0x3B, 0xC4, 0x25, 0xC7, 0x21, 0xBF, 0x07, 0x11, 0xA9, 0x01, 0x54,
+    0x6F, 0x70, 0x20, 0x26, 0x20, 0x56, 0x69, 0x65, 0x77, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0,
+    0x46,
+];
+pub const FIT_FILE_RUN: [u8; 158] = [
+    0x0E, 0x20, 0x54, 0x08, 0x8E, 0x00, 0x00, 0x00, 0x2E, 0x46, 0x49, 0x54, 0x30, 0x3D, 0x40, 0x00,
+    0x00, 0x14, 0x00, 0x07, 0xFD, 0x04, 0x86, 0x00, 0x04, 0x85, 0x01, 0x04, 0x85, 0x03, 0x01, 0x02,
+    0x04, 0x01, 0x02, 0x05, 0x04, 0x86, 0x07, 0x02, 0x84, 0x00, 0x00, 0xCA, 0x9A, 0x3B, 0x72, 0x1C,
+    0xC7, 0x21, 0x11, 0x11, 0x11, 0xA9, 0x96, 0x55, 0x00, 0x00, 0x00, 0x00, 0xFA, 0x00, 0x00, 0x01,
+    0xCA, 0x9A, 0x3B, 0x1B, 0x21, 0xC7, 0x21, 0x11, 0x11, 0x11, 0xA9, 0x97, 0x55, 0x2C, 0x01, 0x00,
+    0x00, 0xFA, 0x00, 0x00, 0x02, 0xCA, 0x9A, 0x3B, 0xC4, 0x25, 0xC7, 0x21, 0x11, 0x11, 0x11, 0xA9,
+    0x98, 0x55, 0x58, 0x02, 0x00, 0x00, 0xFA, 0x00, 0x41, 0x00, 0x00, 0x12, 0x00, 0x08, 0xFD, 0x04,
+    0x86, 0x02, 0x04, 0x86, 0x05, 0x01, 0x00, 0x08, 0x04, 0x86, 0x09, 0x04, 0x86, 0x0B, 0x02, 0x84,
+    0x10, 0x01, 0x02, 0x11, 0x01, 0x02, 0x01, 0x02, 0xCA, 0x9A, 0x3B, 0x00, 0xCA, 0x9A, 0x3B, 0x01,
+    0xD0, 0x07, 0x00, 0x00, 0x58, 0x02, 0x00, 0x00, 0x2A, 0x00, 0x97, 0x98, 0xDB, 0x14,
+];
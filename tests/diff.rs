@@ -0,0 +1,146 @@
+use fit::decoder::{Decoder, Messages};
+use fit::diff::{diff, DiffOptions, FieldChange, MessageChange, Summary};
+use serde_json::json;
+mod data;
+
+fn messages(bytes: &[u8]) -> Messages {
+    Decoder::new(bytes).decode().unwrap().1
+}
+
+#[test]
+fn identical_files() {
+    let left = messages(&data::FIT_FILE_COURSE);
+    let diff = diff(&left, &left.clone(), &DiffOptions::default());
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "0 added, 0 removed, 0 changed\n");
+}
+
+#[test]
+fn re_encoded_fit_csv_keeps_messages() {
+    let left = messages(&data::FIT_FILE_COURSE);
+    let (_, decoded) = Decoder::new(&data::FIT_FILE_COURSE)
+        .decode_messages()
+        .unwrap();
+    let bytes = fit::fit_csv::to_fit(&fit::fit_csv::to_string(&decoded)).unwrap();
+    assert!(diff(&left, &messages(&bytes), &DiffOptions::default()).is_empty());
+}
+
+#[test]
+fn aligns_by_timestamp() {
+    let left = messages(&data::FIT_FILE_COURSE);
+    let mut right = left.clone();
+    let records = right.get_mut("record").unwrap();
+    let removed = records.remove(1);
+    records[1].insert("heart_rate", fit::Value::UInt8(99));
+    records[2].shift_remove("altitude");
+
+    let diff = diff(&left, &right, &DiffOptions::default());
+    assert_eq!(
+        diff.summary(),
+        Summary {
+            added: 0,
+            removed: 1,
+            changed: 2
+        }
+    );
+    assert_eq!(
+        diff.changes[0],
+        MessageChange::Removed {
+            message: "record".to_string(),
+            index: 1,
+            timestamp: removed.get("timestamp").cloned(),
+            fields: removed,
+        }
+    );
+    let MessageChange::Changed {
+        left_index,
+        right_index,
+        fields,
+        ..
+    } = &diff.changes[1]
+    else {
+        panic!("{:?}", diff.changes[1]);
+    };
+    assert_eq!((*left_index, *right_index), (2, 1));
+    assert_eq!(
+        fields,
+        &[FieldChange {
+            field: "heart_rate",
+            left: left["record"][2].get("heart_rate").cloned(),
+            right: Some(fit::Value::UInt8(99)),
+        }]
+    );
+    let MessageChange::Changed { fields, .. } = &diff.changes[2] else {
+        panic!("{:?}", diff.changes[2]);
+    };
+    assert_eq!(fields[0].field, "altitude");
+    assert_eq!(fields[0].right, None);
+}
+
+#[test]
+fn added_message_types() {
+    let left = messages(&data::FIT_FILE_COURSE);
+    let mut right = left.clone();
+    right.insert("activity".to_string(), vec![left["course"][0].clone()]);
+    let diff = diff(&left, &right, &DiffOptions::default());
+    assert!(matches!(
+        &diff.changes[..],
+        [MessageChange::Added { message, index: 0, .. }] if message == "activity"
+    ));
+    assert!(diff.to_string().starts_with("+ activity #0\n"));
+}
+
+#[test]
+fn ignores_noise_fields() {
+    let left = messages(&data::FIT_FILE_COURSE);
+    let mut right = left.clone();
+    let file_id = &mut right.get_mut("file_id").unwrap()[0];
+    file_id.insert("time_created", left["record"][3]["timestamp"].clone());
+    file_id.insert("serial_number", fit::Value::UInt32z(1234));
+    right.get_mut("record").unwrap()[0].insert("heart_rate", fit::Value::UInt8(99));
+    assert_eq!(
+        diff(&left, &right, &DiffOptions::default())
+            .summary()
+            .changed,
+        1
+    );
+
+    let options = DiffOptions {
+        ignore_fields: vec!["record.heart_rate".to_string()],
+    };
+    let diff = diff(&left, &right, &options);
+    let MessageChange::Changed {
+        message, fields, ..
+    } = &diff.changes[0]
+    else {
+        panic!("{:?}", diff.changes[0]);
+    };
+    assert_eq!(message, "file_id");
+    assert_eq!(
+        fields.iter().map(|it| it.field).collect::<Vec<_>>(),
+        ["time_created", "serial_number"]
+    );
+    assert_eq!(diff.changes.len(), 1);
+}
+
+#[test]
+fn json_output() {
+    let left = messages(&data::FIT_FILE_COURSE);
+    let mut right = left.clone();
+    right.get_mut("course_point").unwrap()[0].insert("name", fit::Value::String("Top".into()));
+    let diff = diff(&left, &right, &DiffOptions::default());
+    assert_eq!(
+        serde_json::to_value(&diff).unwrap(),
+        json!({
+            "summary": { "added": 0, "removed": 0, "changed": 1 },
+            "changes": [{
+                "change": "changed",
+                "message": "course_point",
+                "left_index": 0,
+                "right_index": 0,
+                "timestamp": right["course_point"][0]["timestamp"],
+                "fields": [{ "field": "name", "left": "Top & View", "right": "Top" }]
+            }]
+        })
+    );
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "arrow")]
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, Int32Type, TimestampSecondType, UInt8Type};
+use arrow_schema::{DataType, TimeUnit};
+use fit::decoder::Decoder;
+use fit::export::arrow;
+use fit::timezone;
+mod data;
+
+#[test]
+fn batch_per_message_type() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let batches = arrow::to_record_batches(&messages).unwrap();
+    assert_eq!(
+        batches.keys().collect::<Vec<_>>(),
+        messages.keys().collect::<Vec<_>>()
+    );
+    let record = &batches["record"];
+    assert_eq!(record.num_rows(), 4);
+    let schema = record.schema();
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|it| (it.name().as_str(), it.data_type().clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        columns,
+        [
+            (
+                "timestamp",
+                DataType::Timestamp(TimeUnit::Second, Some("+00:00".into()))
+            ),
+            ("position_lat", DataType::Int32),
+            ("position_long", DataType::Int32),
+            ("altitude", DataType::Float64),
+            ("heart_rate", DataType::UInt8),
+            ("power", DataType::UInt16),
+            ("enhanced_altitude", DataType::Float64),
+        ]
+    );
+    assert_eq!(
+        schema
+            .field(4)
+            .metadata()
+            .get("units")
+            .map(|it| it.as_str()),
+        Some("bpm")
+    );
+    let timestamps = record.column(0).as_primitive::<TimestampSecondType>();
+    // 2021-09-08T01:46:40Z
+    assert_eq!(timestamps.value(0), 1631065600);
+    let heart_rates = record.column(4).as_primitive::<UInt8Type>();
+    assert_eq!(heart_rates.values(), &[120, 125, 130, 135]);
+    let altitudes = record.column(3).as_primitive::<Float64Type>();
+    assert!((altitudes.value(1) - 101.2).abs() < 1e-9);
+}
+
+#[test]
+fn profile_types_are_dictionaries() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let batch = arrow::to_record_batch("course", &messages["course"]).unwrap();
+    let sport = batch.column_by_name("sport").unwrap();
+    assert_eq!(
+        sport.data_type(),
+        &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+    );
+    let sport = sport.as_dictionary::<Int32Type>();
+    assert_eq!(sport.values().as_string::<i32>().value(0), "cycling");
+    assert_eq!(
+        batch
+            .column_by_name("name")
+            .unwrap()
+            .as_string::<i32>()
+            .value(0),
+        "Ridge <loop>"
+    );
+}
+
+#[test]
+fn arrays_become_lists() {
+    let buf = std::fs::read("tests/data/WithGearChangeData.fit").unwrap();
+    let (_, messages) = Decoder::new(&buf).decode().unwrap();
+    let batch = arrow::to_record_batch("record", &messages["record"]).unwrap();
+    let column = batch.column_by_name("left_power_phase").unwrap();
+    assert_eq!(
+        column.data_type(),
+        &DataType::new_list(DataType::Float64, true)
+    );
+    let phase = column.as_list::<i32>().value(28);
+    assert_eq!(
+        phase.as_primitive::<Float64Type>().values(),
+        &[337.5000052734376, 199.68750312011724]
+    );
+}
+
+#[test]
+fn local_date_time_has_no_zone() {
+    let buf = std::fs::read("tests/data/Activity.fit").unwrap();
+    let (_, mut messages) = Decoder::new(&buf).decode().unwrap();
+    timezone::localize(&mut messages).unwrap();
+    let batch = arrow::to_record_batch("session", &messages["session"]).unwrap();
+    let column = batch.column_by_name("local_start_time").unwrap();
+    assert_eq!(
+        column.data_type(),
+        &DataType::Timestamp(TimeUnit::Second, None)
+    );
+    // 2021-07-20T14:11:20, wall clock time
+    assert_eq!(
+        column.as_primitive::<TimestampSecondType>().value(0),
+        1626790280
+    );
+}
@@ -0,0 +1,90 @@
+use fit::decoder::Decoder;
+use fit::export::geojson::{self, TrackGrouping};
+use serde_json::json;
+mod data;
+
+#[test]
+fn line_per_lap() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let geojson = geojson::to_value(&messages, TrackGrouping::Lap);
+    assert_eq!(geojson["type"], "FeatureCollection");
+    let features = geojson["features"].as_array().unwrap();
+    // two laps of two records, one course point
+    assert_eq!(features.len(), 3);
+    assert_eq!(
+        features[0]["geometry"],
+        json!({
+            "type": "LineString",
+            "coordinates": [[-122.25, 47.5, 100.0], [-122.2501, 47.5001, 101.20000000000005]],
+        })
+    );
+    let properties = &features[0]["properties"];
+    assert_eq!(properties["message"], "lap");
+    assert_eq!(properties["index"], 0);
+    assert_eq!(properties["start_time"], "2021-09-08T01:46:40+00:00");
+    assert_eq!(
+        properties["coordinateProperties"],
+        json!({
+            "times": ["2021-09-08T01:46:40Z", "2021-09-08T01:46:41Z"],
+            "heart_rates": [120.0, 125.0],
+            "powers": [200.0, 210.0],
+        })
+    );
+    assert_eq!(features[1]["properties"]["index"], 1);
+    assert_eq!(features[2]["geometry"]["type"], "Point");
+    assert_eq!(features[2]["properties"]["message"], "course_point");
+    assert_eq!(features[2]["properties"]["name"], "Top & View");
+    assert_eq!(features[2]["properties"]["type"], "summit");
+}
+
+#[test]
+fn multi_line_per_session() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_RUN).decode().unwrap();
+    let geojson = geojson::to_value(&messages, TrackGrouping::Session);
+    let features = geojson["features"].as_array().unwrap();
+    assert_eq!(features.len(), 1);
+    let geometry = &features[0]["geometry"];
+    assert_eq!(geometry["type"], "MultiLineString");
+    assert_eq!(geometry["coordinates"].as_array().unwrap().len(), 1);
+    assert_eq!(geometry["coordinates"][0].as_array().unwrap().len(), 3);
+    let properties = &features[0]["properties"];
+    assert_eq!(properties["message"], "session");
+    assert_eq!(properties["sport"], "running");
+    assert_eq!(
+        properties["coordinateProperties"]["heart_rates"],
+        json!([[150.0, 151.0, 152.0]])
+    );
+}
+
+#[test]
+fn lap_start_points() {
+    let (_, mut messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let record = messages["record"][2].clone();
+    let lap = &mut messages.get_mut("lap").unwrap()[1];
+    lap.insert("start_position_lat", record["position_lat"].clone());
+    lap.insert("start_position_long", record["position_long"].clone());
+    let geojson = geojson::to_value(&messages, TrackGrouping::Lap);
+    let point = geojson["features"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|it| it["geometry"]["type"] == "Point" && it["properties"]["message"] == "lap")
+        .unwrap();
+    assert_eq!(
+        point["geometry"]["coordinates"],
+        json!([-122.2502, 47.5002])
+    );
+    assert_eq!(
+        point["properties"],
+        json!({ "message": "lap", "index": 1, "start_time": "2021-09-08T01:46:42+00:00" })
+    );
+}
+
+#[test]
+fn empty_collection() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_SHORT).decode().unwrap();
+    assert_eq!(
+        geojson::to_string(&messages, TrackGrouping::Lap),
+        r#"{"features":[],"type":"FeatureCollection"}"#
+    );
+}
@@ -0,0 +1,60 @@
+use fit::decoder::{Decoder, Messages};
+use fit::export::gpx;
+mod data;
+
+#[test]
+fn course_to_gpx() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_COURSE);
+    let (_, messages) = decoder.decode().unwrap();
+    let gpx = gpx::to_string(&messages);
+    assert!(gpx.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<gpx version=\"1.1\""));
+    assert!(gpx.ends_with("</gpx>\n"));
+    assert!(gpx.contains("<metadata>\n    <time>2021-09-08T01:46:40Z</time>\n  </metadata>"));
+    assert!(gpx.contains(
+        "  <wpt lat=\"47.5002000\" lon=\"-122.2502000\">\n    \
+         <time>2021-09-08T01:46:42Z</time>\n    \
+         <name>Top &amp; View</name>\n    \
+         <type>summit</type>\n  </wpt>"
+    ));
+    assert!(gpx.contains("<name>Ridge &lt;loop&gt;</name>\n    <type>cycling</type>"));
+    // one segment per lap
+    assert_eq!(gpx.matches("<trkseg>").count(), 2);
+    assert_eq!(gpx.matches("<trkpt ").count(), 4);
+    assert!(gpx.contains(
+        "      <trkpt lat=\"47.5001000\" lon=\"-122.2501000\">\n        \
+         <ele>101.2</ele>\n        \
+         <time>2021-09-08T01:46:41Z</time>\n        \
+         <extensions>\n          \
+         <power>210</power>\n          \
+         <gpxtpx:TrackPointExtension>\n            \
+         <gpxtpx:hr>125</gpxtpx:hr>\n          \
+         </gpxtpx:TrackPointExtension>\n        \
+         </extensions>\n      </trkpt>"
+    ));
+}
+
+#[test]
+fn activity_to_gpx() {
+    let buf = std::fs::read("tests/data/HrmPluginTestActivity.fit").unwrap();
+    let mut decoder = Decoder::new(&buf);
+    let (_, messages) = decoder.decode().unwrap();
+    let positions = messages
+        .get("record")
+        .unwrap()
+        .iter()
+        .filter(|it| it.contains_key("position_lat") && it.contains_key("position_long"))
+        .count();
+    let mut out = Vec::new();
+    gpx::write(&messages, &mut out).unwrap();
+    let gpx = String::from_utf8(out).unwrap();
+    assert_eq!(gpx.matches("<trkpt ").count(), positions);
+    assert_eq!(gpx.matches("<trkseg>").count(), 1);
+    assert!(gpx.contains("<name>Walk</name>\n    <type>walking</type>"));
+    assert!(gpx.contains("<gpxtpx:atemp>26</gpxtpx:atemp>"));
+}
+
+#[test]
+fn empty_gpx() {
+    let gpx = gpx::to_string(&Messages::new());
+    assert!(gpx.ends_with("xsi:schemaLocation=\"http://www.topografix.com/GPX/1/1 http://www.topografix.com/GPX/1/1/gpx.xsd http://www.garmin.com/xmlschemas/TrackPointExtension/v2 http://www.garmin.com/xmlschemas/TrackPointExtensionv2.xsd\">\n</gpx>\n"));
+}
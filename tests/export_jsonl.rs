@@ -0,0 +1,96 @@
+use fit::decoder::Decoder;
+use fit::export::jsonl;
+use serde_json::{json, Value};
+mod data;
+
+fn lines(bytes: &[u8]) -> Vec<Value> {
+    let mut output = Vec::new();
+    jsonl::write(&mut Decoder::new(bytes), &mut output).unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|it| serde_json::from_str(it).unwrap())
+        .collect()
+}
+
+#[test]
+fn line_per_message() {
+    let lines = lines(&data::FIT_FILE_RUN);
+    let (_, messages) = Decoder::new(&data::FIT_FILE_RUN).decode().unwrap();
+    assert_eq!(
+        lines.len(),
+        messages.values().map(|it| it.len()).sum::<usize>()
+    );
+    assert_eq!(lines[0]["message"], "record");
+    // the first definition message follows the 14 byte file header
+    assert!(lines[0]["offset"].as_u64().unwrap() > 14);
+    assert_eq!(lines[0]["fields"], json!(messages["record"][0]));
+    assert_eq!(lines[3]["message"], "session");
+    let offsets = lines
+        .iter()
+        .map(|it| it["offset"].as_u64().unwrap())
+        .collect::<Vec<_>>();
+    assert!(offsets.windows(2).all(|it| it[0] < it[1]));
+}
+
+#[test]
+fn diagnostics_as_separate_lines() {
+    let lines = lines(&data::FIT_FILE_DEV_DATA_WITHOUT_FIELD_DESCRIPTION);
+    let record = lines
+        .iter()
+        .position(|it| it["message"] == "record")
+        .unwrap();
+    assert_eq!(
+        lines[record + 1],
+        json!({
+            "diagnostic": {
+                "kind": "MissingDeveloperDataDef",
+                "message": "Missing developer data definition for developer data index 0"
+            },
+            "offset": lines[record]["offset"]
+        })
+    );
+    assert!(lines
+        .iter()
+        .all(|it| it.get("message").is_some() != it.get("diagnostic").is_some()));
+}
+
+#[test]
+fn fatal_error_ends_output() {
+    let mut bytes = data::FIT_FILE_COURSE.to_vec();
+    *bytes.last_mut().unwrap() ^= 0xFF;
+    let lines = lines(&bytes);
+    assert_eq!(lines[0]["message"], "file_id");
+    assert_eq!(
+        lines.last().unwrap(),
+        &json!({
+            "diagnostic": { "kind": "InvalidCRC", "message": "CRC invalid." },
+            "fatal": true
+        })
+    );
+}
+
+#[test]
+fn filtered_fields() {
+    let mut output = Vec::new();
+    let completed = jsonl::write_filtered(
+        &mut Decoder::new(&data::FIT_FILE_RUN),
+        &mut output,
+        |message, field| message == "session" && field == "sport",
+    )
+    .unwrap();
+    assert!(completed);
+    let lines = String::from_utf8(output).unwrap();
+    let lines = lines
+        .lines()
+        .map(|it| serde_json::from_str::<Value>(it).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["fields"], json!({ "sport": "running" }));
+
+    let mut bytes = data::FIT_FILE_RUN.to_vec();
+    *bytes.last_mut().unwrap() ^= 0xFF;
+    let completed =
+        jsonl::write_filtered(&mut Decoder::new(&bytes), &mut Vec::new(), |_, _| true).unwrap();
+    assert!(!completed);
+}
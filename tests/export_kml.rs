@@ -0,0 +1,66 @@
+use fit::decoder::Decoder;
+use fit::export::kml::{self, ColorMetric};
+mod data;
+
+#[test]
+fn lap_tracks() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let kml = kml::to_string(&messages, None);
+    assert!(kml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<kml "));
+    assert!(kml.contains("    <name>Ridge &lt;loop&gt;</name>\n"));
+    assert_eq!(kml.matches("<gx:Track>").count(), 2);
+    assert!(kml.contains(
+        "        <name>Lap 2</name>\n        \
+         <styleUrl>#track</styleUrl>\n        \
+         <gx:Track>\n          \
+         <altitudeMode>absolute</altitudeMode>\n          \
+         <when>2021-09-08T01:46:42Z</when>\n          \
+         <when>2021-09-08T01:46:43Z</when>\n          \
+         <gx:coord>-122.2502000 47.5002000 102.4</gx:coord>\n          \
+         <gx:coord>-122.2503000 47.5003000 103.6</gx:coord>\n        \
+         </gx:Track>\n"
+    ));
+    assert!(kml
+        .contains("      <name>Top &amp; View</name>\n      <description>summit</description>\n"));
+    // no colour-coded route without a metric
+    assert!(!kml.contains("<MultiGeometry>"));
+    assert!(!kml.contains("#step"));
+}
+
+#[test]
+fn color_coded_route() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let kml = kml::to_string(&messages, Some(ColorMetric::HeartRate));
+    assert!(kml.contains("      <name>Heart rate</name>\n"));
+    // heart rate 120..135, segments end at 125, 130 and 135
+    assert_eq!(kml.matches("<LineString>").count(), 3);
+    assert!(kml.contains(
+        "        <name>Heart rate 123.2 - 125.4</name>\n        <styleUrl>#step2</styleUrl>\n"
+    ));
+    assert!(kml.contains("        <styleUrl>#step5</styleUrl>\n"));
+    assert!(kml.contains(
+        "        <styleUrl>#step7</styleUrl>\n        <MultiGeometry>\n          <LineString>\n            \
+         <tessellate>1</tessellate>\n            \
+         <coordinates>-122.2502000,47.5002000 -122.2503000,47.5003000</coordinates>\n"
+    ));
+    assert!(kml
+        .contains("    <Style id=\"step0\">\n      <LineStyle>\n        <color>ff00ff00</color>"));
+    assert!(kml
+        .contains("    <Style id=\"step7\">\n      <LineStyle>\n        <color>ff0000ff</color>"));
+}
+
+#[cfg(feature = "kmz")]
+#[test]
+fn kmz_archive() {
+    use std::io::{Cursor, Read};
+    let (_, messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let kml = kml::to_string(&messages, Some(ColorMetric::Power));
+    let kmz = kml::to_kmz(&messages, Some(ColorMetric::Power)).unwrap();
+    let mut archive = zip::ZipArchive::new(Cursor::new(kmz)).unwrap();
+    assert_eq!(archive.len(), 1);
+    let mut entry = archive.by_name("doc.kml").unwrap();
+    assert_eq!(entry.compression(), zip::CompressionMethod::Deflated);
+    let mut text = String::new();
+    entry.read_to_string(&mut text).unwrap();
+    assert_eq!(text, kml);
+}
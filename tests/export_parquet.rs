@@ -0,0 +1,52 @@
+#![cfg(feature = "parquet")]
+use arrow_array::cast::AsArray;
+use arrow_array::types::UInt8Type;
+use fit::decoder::Decoder;
+use fit::export::{arrow, parquet as fit_parquet};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use std::fs::{self, File};
+mod data;
+
+#[test]
+fn file_per_message_type() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let dir = std::env::temp_dir().join(format!("fit-parquet-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let paths = fit_parquet::write_all(&messages, &dir).unwrap();
+    assert_eq!(
+        paths
+            .iter()
+            .map(|it| it.file_name().unwrap().to_str().unwrap())
+            .collect::<Vec<_>>(),
+        messages
+            .keys()
+            .map(|it| format!("{it}.parquet"))
+            .collect::<Vec<_>>()
+    );
+
+    let reader =
+        ParquetRecordBatchReaderBuilder::try_new(File::open(dir.join("record.parquet")).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    let expected = arrow::to_record_batch("record", &messages["record"]).unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].num_rows(), 4);
+    assert_eq!(batches[0].schema().fields(), expected.schema().fields());
+    let heart_rates = batches[0]
+        .column_by_name("heart_rate")
+        .unwrap()
+        .as_primitive::<UInt8Type>();
+    assert_eq!(heart_rates.values(), &[120, 125, 130, 135]);
+}
+
+#[test]
+fn write_to_writer() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_RUN).decode().unwrap();
+    let mut buf = Vec::new();
+    fit_parquet::write("session", &messages["session"], &mut buf).unwrap();
+    assert_eq!(&buf[..4], b"PAR1");
+    assert_eq!(&buf[buf.len() - 4..], b"PAR1");
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "sqlite")]
+use fit::decoder::{DecodedFile, Decoder};
+use fit::export::sqlite;
+use rusqlite::Connection;
+mod data;
+
+fn files(bytes: &[u8]) -> Vec<DecodedFile> {
+    Decoder::new(bytes).decode_files().unwrap().1
+}
+
+#[test]
+fn table_per_message_type() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    sqlite::write(&mut connection, &files(&data::FIT_FILE_COURSE)).unwrap();
+    let tables = connection
+        .prepare("SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY rowid")
+        .unwrap()
+        .query_map([], |row| row.get::<_, String>(0))
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(tables, ["files", "course", "record", "lap", "course_point"]);
+
+    let columns = connection
+        .prepare("SELECT name, type FROM pragma_table_info('record')")
+        .unwrap()
+        .query_map([], |row| {
+            Ok(format!(
+                "{} {}",
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?
+            ))
+        })
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        columns,
+        [
+            "file_index INTEGER",
+            "timestamp TEXT",
+            "position_lat INTEGER",
+            "position_long INTEGER",
+            "altitude REAL",
+            "heart_rate INTEGER",
+            "power INTEGER",
+            "enhanced_altitude REAL",
+        ]
+    );
+    let (time, heart_rate, altitude): (String, i64, f64) = connection
+        .query_row(
+            "SELECT timestamp, heart_rate, altitude FROM record ORDER BY rowid LIMIT 1 OFFSET 1",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(time, "2021-09-08T01:46:41Z");
+    assert_eq!(heart_rate, 125);
+    assert!((altitude - 101.2).abs() < 1e-9);
+    let (sport, name): (String, String) = connection
+        .query_row("SELECT sport, name FROM course", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!((sport.as_str(), name.as_str()), ("cycling", "Ridge <loop>"));
+}
+
+#[test]
+fn files_from_header_and_file_id() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    sqlite::write(&mut connection, &files(&data::FIT_FILE_CHAINED)).unwrap();
+    let files = connection
+        .prepare("SELECT file_index, data_size, data_type, type, manufacturer FROM files")
+        .unwrap()
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+            ))
+        })
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        files,
+        [0, 1].map(|index| (
+            index,
+            100,
+            ".FIT".to_string(),
+            "settings".to_string(),
+            "development".to_string()
+        ))
+    );
+    let joined: i64 = connection
+        .query_row(
+            "SELECT COUNT(*) FROM monitoring_hr_data JOIN files USING (file_index) WHERE resting_heart_rate = 44",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(joined, 2);
+}
+
+#[test]
+fn append_to_existing_database() {
+    let mut connection = Connection::open_in_memory().unwrap();
+    sqlite::write(&mut connection, &files(&data::FIT_FILE_COURSE)).unwrap();
+    sqlite::write(&mut connection, &files(&data::FIT_FILE_RUN)).unwrap();
+    let counts = connection
+        .prepare("SELECT file_index, COUNT(*) FROM record GROUP BY file_index")
+        .unwrap()
+        .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)))
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(counts, [(0, 4), (1, 3)]);
+    // `distance` and `cadence` are only recorded by the second file
+    let distances: i64 = connection
+        .query_row("SELECT COUNT(distance) FROM record", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(distances, 3);
+}
@@ -0,0 +1,97 @@
+use fit::decoder::Decoder;
+use fit::export::table::{self, TableOptions, TimestampFormat};
+mod data;
+
+#[test]
+fn table_per_message_type() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_RUN).decode().unwrap();
+    let tables = table::to_tables(&messages, &TableOptions::default());
+    assert_eq!(tables.keys().collect::<Vec<_>>(), ["record", "session"]);
+    let lines = tables["record"].lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines[0],
+        "timestamp [s],position_lat [semicircles],position_long [semicircles],heart_rate [bpm],cadence [rpm],distance [m],power [watts]"
+    );
+    assert_eq!(
+        lines[1],
+        "2021-09-08T01:46:40Z,566697074,-1458499311,150,85,0,250"
+    );
+    assert_eq!(lines.len(), 4);
+    assert!(tables["session"].contains("\n2021-09-08T01:46:42Z,2021-09-08T01:46:40Z,running,2,6,"));
+}
+
+#[test]
+fn union_of_columns_in_profile_order() {
+    let (_, mut messages) = Decoder::new(&data::FIT_FILE_RUN).decode().unwrap();
+    let records = messages.get_mut("record").unwrap();
+    records[0].shift_remove("power");
+    records[2].shift_remove("heart_rate");
+    // first seen after `distance`, but listed before it by the profile
+    records[2].insert("altitude", fit::Value::Float64(12.5));
+    let table = table::to_string("record", records, &TableOptions::default());
+    let lines = table.lines().collect::<Vec<_>>();
+    assert_eq!(
+        lines[0],
+        "timestamp [s],position_lat [semicircles],position_long [semicircles],altitude [m],heart_rate [bpm],cadence [rpm],distance [m],power [watts]"
+    );
+    assert_eq!(
+        lines[1],
+        "2021-09-08T01:46:40Z,566697074,-1458499311,,150,85,0,"
+    );
+    assert_eq!(
+        lines[3],
+        "2021-09-08T01:46:42Z,566699460,-1458499311,12.5,,85,6,250"
+    );
+}
+
+#[test]
+fn array_flattening() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_SESSION_PEDALING)
+        .decode()
+        .unwrap();
+    let sessions = &messages["session"];
+    let flat = table::to_string("session", sessions, &TableOptions::default());
+    assert!(flat.contains(
+        "avg_left_power_phase_0 [degrees],avg_left_power_phase_1 [degrees],avg_left_power_phase_peak_0 [degrees]"
+    ));
+    let joined = table::to_string(
+        "session",
+        sessions,
+        &TableOptions {
+            flatten_arrays: false,
+            ..TableOptions::default()
+        },
+    );
+    assert!(joined.contains(",avg_left_power_phase [degrees],"));
+    assert!(joined.contains(",45.00000070312501|180.00000281250004,"));
+}
+
+#[test]
+fn tsv_and_timestamp_formats() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let options = TableOptions {
+        timestamp_format: TimestampFormat::Unix,
+        ..TableOptions::tsv()
+    };
+    let table = table::to_string("course_point", &messages["course_point"], &options);
+    assert_eq!(
+        table,
+        "timestamp\tposition_lat [semicircles]\tposition_long [semicircles]\ttype\tname\n\
+         1631065602\t566699460\t-1458501697\tsummit\tTop & View\n"
+    );
+    let options = TableOptions {
+        timestamp_format: TimestampFormat::Fit,
+        ..TableOptions::csv()
+    };
+    let table = table::to_string("file_id", &messages["file_id"], &options);
+    assert_eq!(table, "type,time_created\ncourse,1000000000\n");
+}
+
+#[test]
+fn quoted_cells() {
+    let (_, mut messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let course = messages.get_mut("course").unwrap();
+    course[0].insert("name", fit::Value::String("Ridge, \"loop\"".to_string()));
+    let table = table::to_string("course", course, &TableOptions::default());
+    assert_eq!(table, "sport,name\ncycling,\"Ridge, \"\"loop\"\"\"\n");
+}
@@ -0,0 +1,64 @@
+use fit::decoder::{Decoder, Messages};
+use fit::export::tcx;
+mod data;
+
+#[test]
+fn running_session_as_lap() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_RUN);
+    let (_, messages) = decoder.decode().unwrap();
+    let tcx = tcx::to_string(&messages);
+    assert!(tcx.contains("<Activity Sport=\"Running\">\n      <Id>2021-09-08T01:46:40Z</Id>"));
+    assert_eq!(tcx.matches("<Lap ").count(), 1);
+    assert!(tcx.contains(
+        "        <TotalTimeSeconds>2.000</TotalTimeSeconds>\n        \
+         <DistanceMeters>6.00</DistanceMeters>\n        \
+         <Calories>42</Calories>\n        \
+         <AverageHeartRateBpm>\n          <Value>151</Value>\n        </AverageHeartRateBpm>\n        \
+         <MaximumHeartRateBpm>\n          <Value>152</Value>\n        </MaximumHeartRateBpm>\n        \
+         <Intensity>Active</Intensity>\n        \
+         <TriggerMethod>Manual</TriggerMethod>\n"
+    ));
+    assert_eq!(tcx.matches("<Trackpoint>").count(), 3);
+    // running cadence goes to the extension instead of `Cadence`
+    assert!(!tcx.contains("<Cadence>"));
+    assert!(tcx.contains(
+        "              <ns3:TPX>\n                \
+         <ns3:RunCadence>85</ns3:RunCadence>\n                \
+         <ns3:Watts>250</ns3:Watts>\n              \
+         </ns3:TPX>"
+    ));
+}
+
+#[test]
+fn laps_split_track() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_COURSE);
+    let (_, messages) = decoder.decode().unwrap();
+    let tcx = tcx::to_string(&messages);
+    assert!(tcx.contains("<Activity Sport=\"Biking\">"));
+    assert_eq!(tcx.matches("<Lap ").count(), 2);
+    assert!(tcx.contains("<Lap StartTime=\"2021-09-08T01:46:42Z\">"));
+    assert_eq!(tcx.matches("<Track>").count(), 2);
+    assert_eq!(tcx.matches("<Trackpoint>").count(), 4);
+}
+
+#[test]
+fn activity_to_tcx() {
+    let buf = std::fs::read("tests/data/HrmPluginTestActivity.fit").unwrap();
+    let mut decoder = Decoder::new(&buf);
+    let (_, messages) = decoder.decode().unwrap();
+    let mut out = Vec::new();
+    tcx::write(&messages, &mut out).unwrap();
+    let tcx = String::from_utf8(out).unwrap();
+    assert!(tcx.contains("<Activity Sport=\"Other\">\n      <Id>2022-08-29T18:31:22Z</Id>"));
+    assert!(tcx.contains("<TotalTimeSeconds>308.545</TotalTimeSeconds>"));
+    assert_eq!(
+        tcx.matches("<Trackpoint>").count(),
+        messages.get("record").unwrap().len()
+    );
+}
+
+#[test]
+fn empty_tcx() {
+    let tcx = tcx::to_string(&Messages::new());
+    assert!(tcx.ends_with("  <Activities/>\n</TrainingCenterDatabase>\n"));
+}
@@ -0,0 +1,85 @@
+use fit::decoder::{DecodedMessage, Decoder};
+use fit::error::ErrorKind;
+use fit::fit_csv;
+use fit::Value;
+mod data;
+
+fn to_csv(bytes: &[u8]) -> String {
+    let (_, messages) = Decoder::new(bytes).decode_messages().unwrap();
+    fit_csv::to_string(&messages)
+}
+
+#[test]
+fn definition_and_data_rows() {
+    let csv = to_csv(&data::FIT_FILE_RUN);
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert!(lines[0].starts_with("Type,Local Number,Message,Field 1,Value 1,Units 1,"));
+    assert_eq!(
+        lines[1],
+        "Definition,0,record,timestamp,1,,position_lat,1,,position_long,1,,heart_rate,1,,cadence,1,,distance,1,,power,1,"
+    );
+    assert_eq!(
+        lines[2],
+        "Data,0,record,timestamp,\"1000000000\",s,position_lat,\"566697074\",semicircles,position_long,\"-1458499311\",semicircles,heart_rate,\"150\",bpm,cadence,\"85\",rpm,distance,\"0\",m,power,\"250\",watts"
+    );
+    assert!(lines[6].starts_with(
+        "Data,1,session,timestamp,\"1000000002\",s,start_time,\"1000000000\",,sport,\"running\",,"
+    ));
+}
+
+#[test]
+fn decode_messages_keeps_definitions() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_RUN).decode_messages().unwrap();
+    assert_eq!(messages.len(), 6);
+    match &messages[0] {
+        DecodedMessage::Definition(def) => {
+            assert_eq!(def.local_message_number, 0);
+            assert_eq!(def.global_message_number, 20);
+            assert_eq!(def.field_definitions.len(), 7);
+        }
+        _ => panic!("expected a definition message"),
+    }
+    assert!(matches!(
+        &messages[5],
+        DecodedMessage::Data { local_message_number: 1, name, .. } if name == "session"
+    ));
+}
+
+#[test]
+fn round_trip() {
+    let activity = std::fs::read("tests/data/Activity.fit").unwrap();
+    for bytes in [&data::FIT_FILE_COURSE[..], &data::FIT_FILE_RUN, &activity] {
+        let (_, expected) = Decoder::new(bytes).decode().unwrap();
+        let actual = fit_csv::read(&to_csv(bytes)).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn hand_edited() {
+    let csv = to_csv(&data::FIT_FILE_RUN)
+        .replace("sport,\"running\"", "sport,\"cycling\"")
+        .replace("heart_rate,\"151\"", "heart_rate,\"171\"");
+    let messages = fit_csv::read(&csv).unwrap();
+    assert_eq!(messages["session"][0]["sport"].to_string(), "cycling");
+    assert_eq!(messages["record"][1]["heart_rate"], Value::UInt8(171));
+    let bytes = fit_csv::to_fit(&csv).unwrap();
+    assert!(Decoder::new(&bytes).check_integrity());
+}
+
+#[test]
+fn invalid_csv() {
+    let csv = "Type,Local Number,Message\nData,0,record,heart_rate,\"150\",bpm\n";
+    assert_eq!(
+        fit_csv::read(csv),
+        Err(ErrorKind::InvalidCsv {
+            line: 2,
+            reason: "no definition for local message number 0".to_string()
+        })
+    );
+    let csv = "Definition,0,record,heart_rate,1,\nData,0,record,heart_rate,\"fast\",bpm\n";
+    assert!(matches!(
+        fit_csv::read(csv),
+        Err(ErrorKind::InvalidCsv { line: 2, .. })
+    ));
+}
@@ -0,0 +1,181 @@
+use fit::decoder::hex_dump::{annotate, Item, MessageHeader};
+use fit::error::ErrorKind;
+mod data;
+
+#[test]
+fn spans_cover_every_byte() {
+    let bytes = std::fs::read("tests/data/Activity.fit").unwrap();
+    let dump = annotate(&bytes);
+    assert_eq!(dump.fatal, None);
+    let mut offset = 0;
+    for span in &dump.spans {
+        assert_eq!(span.offset, offset);
+        assert_eq!(span.bytes, &bytes[offset..offset + span.bytes.len()]);
+        offset += span.bytes.len();
+    }
+    assert_eq!(offset, bytes.len());
+    assert!(matches!(
+        dump.spans.last().unwrap().item,
+        Item::Crc { value, expected } if value == expected
+    ));
+}
+
+#[test]
+fn message_headers() {
+    let dump = annotate(&data::FIT_FILE_SHORT_COMPRESSED_TIMESTAMP);
+    let headers = dump
+        .spans
+        .iter()
+        .filter_map(|span| match &span.item {
+            Item::MessageHeader(header) => Some((span.offset, span.bytes, header.clone())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        headers,
+        [
+            (
+                14,
+                &[0x40][..],
+                MessageHeader {
+                    definition: true,
+                    local_message_number: 0,
+                    developer_data: false,
+                    time_offset: None,
+                }
+            ),
+            (
+                32,
+                &[0x80][..],
+                MessageHeader {
+                    definition: false,
+                    local_message_number: 0,
+                    developer_data: false,
+                    time_offset: Some(0),
+                }
+            ),
+        ]
+    );
+    // the fixture keeps the CRC of the file it was made from
+    assert!(matches!(
+        dump.spans.last().unwrap().item,
+        Item::Crc { value: 0xF25D, expected } if expected != 0xF25D
+    ));
+}
+
+#[test]
+fn raw_and_decoded_field_values() {
+    let dump = annotate(&data::FIT_FILE_DEV_DATA_WITHOUT_FIELD_DESCRIPTION);
+    let fields = dump
+        .spans
+        .iter()
+        .filter_map(|span| match &span.item {
+            Item::Field(field) => Some(field),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let speed = fields.iter().find(|it| it.name == Some("speed")).unwrap();
+    assert_eq!(speed.value, Ok(fit::Value::UInt16(1000)));
+    assert_eq!(
+        speed
+            .decoded
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>(),
+        ["speed", "enhanced_speed"]
+    );
+    let position = fields
+        .iter()
+        .filter(|it| it.name == Some("position_lat"))
+        .nth(1)
+        .unwrap();
+    assert!(matches!(
+        position.value,
+        Err(ErrorKind::InvalidFieldValue { .. })
+    ));
+    assert!(position.decoded.is_empty());
+
+    let developer_fields = dump
+        .spans
+        .iter()
+        .filter(|it| matches!(it.item, Item::DeveloperField { .. }))
+        .count();
+    assert_eq!(developer_fields, 4);
+    assert!(dump.errors.contains(&(
+        0x56,
+        ErrorKind::MissingDeveloperDataDef {
+            developer_data_index: 0
+        }
+    )));
+}
+
+#[test]
+fn components_overwriting_values_are_credited() {
+    let csv = [
+        "Type,Local Number,Message",
+        "Definition,0,record,enhanced_speed,1,,speed,1,",
+        "Data,0,record,enhanced_speed,\"5.0\",m/s,speed,\"10.0\",m/s",
+    ]
+    .join("\n");
+    let bytes = fit::fit_csv::to_fit(&csv).unwrap();
+    let dump = annotate(&bytes);
+    let decoded = |number: u8| {
+        dump.spans
+            .iter()
+            .find_map(|span| match &span.item {
+                Item::Field(field) if field.number == number => Some(field.decoded.clone()),
+                _ => None,
+            })
+            .unwrap()
+    };
+    // the component of `speed` overwrites `enhanced_speed`
+    let speed = decoded(6);
+    assert_eq!(
+        speed.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+        ["speed", "enhanced_speed"]
+    );
+    assert_eq!(speed[1].1, fit::Value::Float64(10.0));
+    assert_eq!(decoded(73)[0].0, "enhanced_speed");
+
+    // written as `major * 1000 + minor` by the encoder
+    assert!(dump.to_string().contains(", profile 21.126, "));
+}
+
+#[test]
+fn definition_field_triples() {
+    let dump = annotate(&data::FIT_FILE_COURSE);
+    let Item::DefinitionHeader { message, .. } = &dump.spans[2].item else {
+        panic!("{:?}", dump.spans[2]);
+    };
+    assert_eq!(message.as_deref(), Some("file_id"));
+    let Item::FieldDefinition { definition, name } = &dump.spans[3].item else {
+        panic!("{:?}", dump.spans[3]);
+    };
+    assert_eq!(dump.spans[3].bytes.len(), 3);
+    assert_eq!(dump.spans[3].bytes[0], definition.field_definition_number);
+    assert_eq!(*name, Some("type"));
+
+    let text = dump.to_string();
+    assert!(text.starts_with("00000000  0e "));
+    assert!(text.contains("  field 0 type: 6 -> course\n"));
+    assert!(text.contains("definition: global 0 (file_id), little endian"));
+}
+
+#[test]
+fn chained_files() {
+    let dump = annotate(&data::FIT_FILE_CHAINED);
+    let items = |f: fn(&Item) -> bool| dump.spans.iter().filter(|it| f(&it.item)).count();
+    assert_eq!(items(|it| matches!(it, Item::FileHeader(_))), 2);
+    assert_eq!(items(|it| matches!(it, Item::Crc { .. })), 2);
+}
+
+#[test]
+fn truncated_file() {
+    let dump = annotate(&data::FIT_FILE_COURSE[..60]);
+    assert!(matches!(
+        dump.fatal,
+        Some(ErrorKind::OutOfBoundsRead { .. })
+    ));
+    assert!(!dump.spans.is_empty());
+    assert!(dump.to_string().ends_with("available.\n"));
+}
@@ -0,0 +1,198 @@
+use chrono::{TimeZone, Utc};
+use fit::decoder::Decoder;
+use fit::error::ErrorKind;
+use fit::export::gpx as gpx_export;
+use fit::import::{gpx, tcx, CourseOptions};
+use fit::Value;
+mod data;
+
+const GPX_TRACK: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1"
+  xmlns:gpxtpx="http://www.garmin.com/xmlschemas/TrackPointExtension/v1">
+  <wpt lat="47.501" lon="-122.25"><name>Top</name><type>Summit</type></wpt>
+  <trk>
+    <name>Lake loop</name>
+    <type>cycling</type>
+    <trkseg>
+      <trkpt lat="47.5" lon="-122.25"><ele>100</ele><time>2021-09-08T01:46:40Z</time>
+        <extensions><gpxtpx:TrackPointExtension><gpxtpx:hr>120</gpxtpx:hr><gpxtpx:cad>80</gpxtpx:cad></gpxtpx:TrackPointExtension></extensions>
+      </trkpt>
+      <trkpt lat="47.5005" lon="-122.25"><ele>101</ele><time>2021-09-08T01:46:50Z</time></trkpt>
+    </trkseg>
+    <trkseg>
+      <trkpt lat="47.501" lon="-122.25"><ele>102</ele><time>2021-09-08T01:47:00Z</time></trkpt>
+      <trkpt lat="47.501" lon="-122.2495"><ele>103</ele><time>2021-09-08T01:47:10Z</time></trkpt>
+    </trkseg>
+  </trk>
+</gpx>"#;
+
+const TCX_COURSE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<TrainingCenterDatabase xmlns="http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2">
+  <Courses>
+    <Course>
+      <Name>Hill</Name>
+      <Lap><TotalTimeSeconds>20</TotalTimeSeconds></Lap>
+      <Track>
+        <Trackpoint><Time>2021-09-08T01:46:40Z</Time><Position><LatitudeDegrees>47.5</LatitudeDegrees><LongitudeDegrees>-122.25</LongitudeDegrees></Position><AltitudeMeters>100</AltitudeMeters></Trackpoint>
+        <Trackpoint><Time>2021-09-08T01:46:50Z</Time><Position><LatitudeDegrees>47.5009</LatitudeDegrees><LongitudeDegrees>-122.25</LongitudeDegrees></Position><AltitudeMeters>120</AltitudeMeters></Trackpoint>
+        <Trackpoint><Time>2021-09-08T01:47:00Z</Time><Position><LatitudeDegrees>47.5018</LatitudeDegrees><LongitudeDegrees>-122.25</LongitudeDegrees></Position><AltitudeMeters>110</AltitudeMeters></Trackpoint>
+      </Track>
+      <CoursePoint>
+        <Name>Water</Name>
+        <Time>2021-09-08T01:46:50Z</Time>
+        <Position><LatitudeDegrees>47.5009</LatitudeDegrees><LongitudeDegrees>-122.25</LongitudeDegrees></Position>
+        <PointType>Water</PointType>
+      </CoursePoint>
+    </Course>
+  </Courses>
+</TrainingCenterDatabase>"#;
+
+fn number(value: Option<&Value>) -> f64 {
+    value.and_then(|it| it.try_as_f64().ok()).unwrap()
+}
+
+#[test]
+fn gpx_track_to_course() {
+    let course = gpx::read(GPX_TRACK).unwrap();
+    assert_eq!(course.name.as_deref(), Some("Lake loop"));
+    assert_eq!(course.laps.len(), 2);
+    assert_eq!(course.laps[0][0].heart_rate, Some(120));
+    assert_eq!(course.laps[0][0].cadence, Some(80));
+
+    let bytes = course.to_fit(&CourseOptions::default()).unwrap();
+    assert!(Decoder::new(&bytes).check_integrity());
+    let (_, messages) = Decoder::new(&bytes).decode().unwrap();
+    assert_eq!(
+        messages.keys().collect::<Vec<_>>(),
+        [
+            "file_id",
+            "course",
+            "lap",
+            "event",
+            "record",
+            "course_point"
+        ]
+    );
+    assert_eq!(messages["file_id"][0]["type"].to_string(), "course");
+    assert_eq!(messages["course"][0]["sport"].to_string(), "cycling");
+    assert_eq!(messages["course"][0]["name"].to_string(), "Lake loop");
+    assert_eq!(messages["event"].len(), 2);
+
+    let records = &messages["record"];
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0]["heart_rate"], Value::UInt8(120));
+    assert!(records[1].get("heart_rate").is_none());
+    // 0.0005 degrees of latitude are about 55.6 m
+    let distances = records
+        .iter()
+        .map(|it| number(it.get("distance")))
+        .collect::<Vec<_>>();
+    assert_eq!(distances[0], 0.0);
+    assert!((distances[1] - 55.6).abs() < 0.1, "{distances:?}");
+    assert!((distances[2] - 111.2).abs() < 0.1, "{distances:?}");
+    assert!(distances[3] > distances[2]);
+    assert!((number(records[3].get("altitude")) - 103.0).abs() < 0.2);
+
+    let laps = &messages["lap"];
+    assert_eq!(laps.len(), 2);
+    assert_eq!(number(laps[0].get("total_elapsed_time")), 10.0);
+    assert!((number(laps[0].get("total_distance")) - 55.6).abs() < 0.1);
+
+    let course_point = &messages["course_point"][0];
+    assert_eq!(course_point["type"].to_string(), "summit");
+    assert_eq!(course_point["name"].to_string(), "Top");
+    assert_eq!(
+        number(course_point.get("distance")),
+        (distances[2] * 100.0).round() / 100.0
+    );
+    assert_eq!(course_point["timestamp"], records[2]["timestamp"]);
+}
+
+#[test]
+fn gpx_route_without_times() {
+    let gpx = r#"<gpx version="1.1"><rte><name>Route</name>
+        <rtept lat="47.5" lon="-122.25"/><rtept lat="47.5009" lon="-122.25"/>
+        </rte></gpx>"#;
+    let start_time = Utc.with_ymd_and_hms(2024, 5, 1, 8, 0, 0).unwrap();
+    let options = CourseOptions {
+        speed: 10.0,
+        start_time,
+    };
+    let bytes = gpx::read(gpx).unwrap().to_fit(&options).unwrap();
+    let (_, messages) = Decoder::new(&bytes).decode().unwrap();
+    let records = &messages["record"];
+    assert_eq!(records[0]["timestamp"], Value::DateTime(start_time));
+    // about 100 m at 10 m/s
+    assert_eq!(
+        records[1]["timestamp"],
+        Value::DateTime(start_time + chrono::Duration::seconds(10))
+    );
+    assert_eq!(
+        messages["file_id"][0]["time_created"],
+        Value::DateTime(start_time)
+    );
+}
+
+#[test]
+fn long_names_are_truncated_on_char_boundaries() {
+    let gpx = GPX_TRACK
+        .replace(
+            "<name>Lake loop</name>",
+            &format!("<name>{}</name>", "湖".repeat(100)),
+        )
+        .replace(
+            "<name>Top</name>",
+            &format!("<name>{}</name>", "ö".repeat(200)),
+        );
+    let options = CourseOptions::default();
+    let bytes = gpx::read(&gpx).unwrap().to_fit(&options).unwrap();
+    let (_, messages) = Decoder::new(&bytes).decode().unwrap();
+    // 254 bytes would split the 85th character
+    assert_eq!(
+        messages["course"][0]["name"],
+        Value::String("湖".repeat(84))
+    );
+    assert_eq!(
+        messages["course_point"][0]["name"],
+        Value::String("ö".repeat(127))
+    );
+}
+
+#[test]
+fn tcx_course_to_course() {
+    let course = tcx::read(TCX_COURSE).unwrap();
+    assert_eq!(course.name.as_deref(), Some("Hill"));
+    let bytes = course.to_fit(&CourseOptions::default()).unwrap();
+    let (_, messages) = Decoder::new(&bytes).decode().unwrap();
+    assert_eq!(messages["record"].len(), 3);
+    assert_eq!(messages["lap"].len(), 1);
+    assert_eq!(number(messages["lap"][0].get("total_elapsed_time")), 20.0);
+    let course_point = &messages["course_point"][0];
+    assert_eq!(course_point["type"].to_string(), "water");
+    assert_eq!(course_point["distance"], messages["record"][1]["distance"]);
+}
+
+#[test]
+fn round_trip_through_gpx_export() {
+    let (_, messages) = Decoder::new(&data::FIT_FILE_COURSE).decode().unwrap();
+    let course = gpx::read(&gpx_export::to_string(&messages)).unwrap();
+    let bytes = course.to_fit(&CourseOptions::default()).unwrap();
+    let (_, imported) = Decoder::new(&bytes).decode().unwrap();
+    assert_eq!(imported["record"].len(), messages["record"].len());
+    for (imported, original) in imported["record"].iter().zip(&messages["record"]) {
+        assert_eq!(imported["timestamp"], original["timestamp"]);
+        assert_eq!(imported["heart_rate"], original["heart_rate"]);
+    }
+}
+
+#[test]
+fn invalid_documents() {
+    assert!(matches!(
+        gpx::read("<gpx><trk>"),
+        Err(ErrorKind::InvalidXml { .. })
+    ));
+    assert!(matches!(
+        tcx::read("<TrainingCenterDatabase/>"),
+        Err(ErrorKind::InvalidXml { .. })
+    ));
+}
@@ -0,0 +1,57 @@
+use fit::decoder::Decoder;
+use fit::memo;
+use fit::Value;
+mod data;
+
+#[test]
+fn reassemble_memo_glob() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_MEMO_GLOB);
+    let (errors, mut messages) = decoder.decode().unwrap();
+    assert_eq!(errors.len(), 0);
+    assert_eq!(messages.get("memo_glob").map(|it| it.len()), Some(8));
+    memo::reassemble(&mut messages);
+
+    let steps = messages.get("workout_step").unwrap();
+    assert_eq!(
+        steps[0].get("notes"),
+        Some(&Value::String("short".to_string()))
+    );
+    assert_eq!(
+        steps[1].get("notes"),
+        Some(&Value::String("Hold 90% FTP, cadence above 95".to_string()))
+    );
+    // parent without message_index is matched by position
+    assert_eq!(
+        messages.get("course").unwrap()[0].get("name"),
+        Some(&Value::String("Lakeside loop via the old mill".to_string()))
+    );
+}
+
+#[test]
+fn position_is_not_used_when_other_records_have_message_index() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_MEMO_GLOB);
+    let (_, mut messages) = decoder.decode().unwrap();
+    messages.get_mut("workout_step").unwrap()[1].shift_remove("message_index");
+    memo::reassemble(&mut messages);
+
+    let steps = messages.get("workout_step").unwrap();
+    assert_eq!(
+        steps[0].get("notes"),
+        Some(&Value::String("short".to_string()))
+    );
+    // the memo of message_index 1 has no parent, the step keeps its own notes
+    assert_eq!(
+        steps[1].get("notes"),
+        Some(&Value::String("trunc".to_string()))
+    );
+}
+
+#[test]
+fn reassemble_without_memo_glob() {
+    let buf = std::fs::read("tests/data/Activity.fit").unwrap();
+    let mut decoder = Decoder::new(&buf);
+    let (_, mut messages) = decoder.decode().unwrap();
+    let expected = messages.clone();
+    memo::reassemble(&mut messages);
+    assert_eq!(messages, expected);
+}
@@ -0,0 +1,95 @@
+use fit::decoder::Decoder;
+use fit::pedaling::{Balance, PedalingDynamics};
+mod data;
+
+fn assert_close(actual: Option<f64>, expected: f64) {
+    let actual = actual.unwrap();
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn decode_left_right_balance() {
+    assert_eq!(
+        Balance::from_left_right_balance(0x80 | 34),
+        Some(Balance {
+            left: 66.0,
+            right: 34.0
+        })
+    );
+    assert_eq!(Balance::from_left_right_balance(34), None);
+    assert_eq!(
+        Balance::from_left_right_balance_100(0x8000 | 4850),
+        Some(Balance {
+            left: 51.5,
+            right: 48.5
+        })
+    );
+    assert_eq!(Balance::from_left_right_balance_100(4850), None);
+}
+
+#[test]
+fn record_pedaling_dynamics() {
+    let buf = std::fs::read("tests/data/WithGearChangeData.fit").unwrap();
+    let mut decoder = Decoder::new(&buf);
+    let (_, messages) = decoder.decode().unwrap();
+    let records = messages.get("record").unwrap();
+    let dynamics = PedalingDynamics::from_record(&records[28]).unwrap();
+    assert_eq!(
+        dynamics.balance,
+        Some(Balance {
+            left: 66.0,
+            right: 34.0
+        })
+    );
+    assert_close(dynamics.left.platform_center_offset, 2.0);
+    assert_close(dynamics.right.platform_center_offset, -3.0);
+    let phase = dynamics.left.power_phase.unwrap();
+    assert_close(Some(phase.start), 337.5);
+    assert_close(Some(phase.end), 199.6875);
+    let peak = dynamics.right.power_phase_peak.unwrap();
+    assert_close(Some(peak.start), 70.3125);
+    assert_close(Some(peak.end), 106.875);
+}
+
+#[test]
+fn session_pedaling_dynamics() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_SESSION_PEDALING);
+    let (_, messages) = decoder.decode().unwrap();
+    let session = &messages.get("session").unwrap()[0];
+    let dynamics = PedalingDynamics::from_summary(session).unwrap();
+    let balance = dynamics.balance.unwrap();
+    assert_close(Some(balance.left), 51.5);
+    assert_close(Some(balance.right), 48.5);
+
+    assert_close(dynamics.left.torque_effectiveness, 75.0);
+    assert_close(dynamics.right.torque_effectiveness, 70.0);
+    assert_close(dynamics.left.pedal_smoothness, 20.0);
+    assert_close(dynamics.right.pedal_smoothness, 19.0);
+    assert_close(dynamics.left.platform_center_offset, 4.0);
+    assert_close(dynamics.right.platform_center_offset, -2.0);
+
+    let phase = dynamics.left.power_phase.unwrap();
+    assert_close(Some(phase.start), 45.0);
+    assert_close(Some(phase.end), 180.0);
+    let peak = dynamics.left.power_phase_peak.unwrap();
+    assert_close(Some(peak.start), 90.0);
+    assert_close(Some(peak.end), 135.0);
+    let phase = dynamics.right.power_phase.unwrap();
+    assert_close(Some(phase.start), 225.0);
+    assert_close(Some(phase.end), 0.0);
+    let peak = dynamics.right.power_phase_peak.unwrap();
+    assert_close(Some(peak.start), 270.0);
+    assert_close(Some(peak.end), 45.0);
+}
+
+#[test]
+fn no_pedaling_dynamics() {
+    let buf = std::fs::read("tests/data/Activity.fit").unwrap();
+    let mut decoder = Decoder::new(&buf);
+    let (_, messages) = decoder.decode().unwrap();
+    let record = &messages.get("record").unwrap()[0];
+    assert_eq!(PedalingDynamics::from_record(record), None);
+}
@@ -0,0 +1,39 @@
+use fit::decoder::Decoder;
+mod data;
+
+#[test]
+fn keep_definition_order() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_STRING_FIELDS);
+    let (_, messages) = decoder.decode().unwrap();
+    assert_eq!(
+        messages.keys().collect::<Vec<_>>(),
+        vec!["file_id", "workout_step"]
+    );
+    let step = &messages.get("workout_step").unwrap()[0];
+    assert_eq!(
+        step.keys().copied().collect::<Vec<_>>(),
+        vec!["message_index", "wkt_step_name", "notes"]
+    );
+}
+
+#[test]
+fn expanded_fields_follow_their_source() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_SUB_FIELD_REFERENCE_BASE_TYPES);
+    let (_, messages) = decoder.decode().unwrap();
+    let file_id = &messages.get("file_id").unwrap()[0];
+    assert_eq!(
+        file_id.keys().copied().collect::<Vec<_>>(),
+        vec!["manufacturer", "product", "garmin_product"]
+    );
+}
+
+#[test]
+fn deterministic_serialization() {
+    let buf = std::fs::read("tests/data/Activity.fit").unwrap();
+    let (_, first) = Decoder::new(&buf).decode().unwrap();
+    let (_, second) = Decoder::new(&buf).decode().unwrap();
+    assert_eq!(
+        serde_json::to_string(&first).unwrap(),
+        serde_json::to_string(&second).unwrap()
+    );
+}
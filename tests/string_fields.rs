@@ -0,0 +1,45 @@
+use fit::decoder::Decoder;
+use fit::error::ErrorKind;
+use fit::Value;
+mod data;
+
+#[test]
+fn split_string_arrays() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_STRING_FIELDS);
+    let (_, messages) = decoder.decode().unwrap();
+    let steps = messages.get("workout_step").unwrap();
+    assert_eq!(
+        steps[0].get("wkt_step_name"),
+        Some(&Value::String("Warm up".to_string()))
+    );
+    assert_eq!(
+        steps[0].get("notes"),
+        Some(&Value::Array(vec![
+            Value::String("Easy".to_string()),
+            Value::String("Steady".to_string()),
+        ]))
+    );
+    // leading empty strings are kept, trailing padding is dropped
+    assert_eq!(
+        steps[1].get("wkt_step_name"),
+        Some(&Value::String("".to_string()))
+    );
+    assert_eq!(
+        steps[1].get("notes"),
+        Some(&Value::Array(vec![
+            Value::String("".to_string()),
+            Value::String("Cool down".to_string()),
+        ]))
+    );
+}
+
+#[test]
+fn decode_invalid_utf8_lossily() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_STRING_FIELDS);
+    let (errors, messages) = decoder.decode().unwrap();
+    assert_eq!(
+        messages.get("file_id").unwrap()[0].get("product_name"),
+        Some(&Value::String("Edge\u{FFFD}\u{FFFD}530".to_string()))
+    );
+    assert_eq!(errors, vec![ErrorKind::InvalidUtf8String { offset: 28 }]);
+}
@@ -29,11 +29,10 @@ fn test_sub_field_type_to_string_conversion() {
         .unwrap()
         .iter()
         .filter(|it| it.contains_key("rider_position"))
-        .map(|it| it
+        .all(|it| it
             .get("rider_position")
-            .map(|it| matches!(it, fit::Value::String(_)))
-            .unwrap())
-        .all(|it| it))
+            .map(|it| matches!(it, fit::Value::Typed(fit::TypedValue { name: Some(_), .. })))
+            .unwrap()))
 }
 
 #[test]
@@ -65,3 +64,36 @@ fn test_sub_fields_scale_offset() {
         }
     }
 }
+
+#[test]
+fn test_sub_field_reference_independent_of_base_type() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_SUB_FIELD_REFERENCE_BASE_TYPES);
+    let (errors, messages) = decoder.decode().unwrap();
+    assert_eq!(errors.len(), 0);
+
+    // manufacturer declared as uint16, uint16z, enum, uint8, uint32 and sint16
+    let file_ids = messages.get("file_id").unwrap();
+    assert_eq!(file_ids.len(), 6);
+    for file_id in &file_ids[..5] {
+        assert_eq!(
+            file_id.get("garmin_product").map(|it| it.to_string()),
+            Some("hrm1".to_string()),
+            "manufacturer {:?}",
+            file_id.get("manufacturer")
+        );
+    }
+    assert!(file_ids[5].contains_key("favero_product"));
+    assert!(!file_ids[5].contains_key("garmin_product"));
+
+    // sport declared as enum, uint8 and uint16
+    let sessions = messages.get("session").unwrap();
+    assert_eq!(sessions.len(), 3);
+    for session in sessions {
+        assert_eq!(
+            session.get("total_strides"),
+            Some(&fit::Value::UInt32(1000)),
+            "sport {:?}",
+            session.get("sport")
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::DateTime;
+use fit::decoder::Decoder;
+use fit::Value;
+mod data;
+
+fn fit_date_time(seconds: f64) -> Value {
+    Value::DateTime(
+        DateTime::from_timestamp_millis(((seconds + fit::FIT_EPOCH_OFFSET as f64) * 1000.0) as i64)
+            .unwrap(),
+    )
+}
+
+#[test]
+fn system_time_should_be_correlated() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_TIMESTAMP_CORRELATION);
+    let (errors, messages) = decoder.decode().unwrap();
+    assert_eq!(errors.len(), 0);
+    let accelerometer_data = messages.get("accelerometer_data").unwrap();
+    // no correlation seen yet
+    assert_eq!(accelerometer_data[0].get("utc_timestamp"), None);
+    assert_eq!(
+        accelerometer_data[1].get("utc_timestamp"),
+        Some(&fit_date_time(1000000005.75))
+    );
+}
+
+#[test]
+fn system_time_array_should_be_correlated() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_TIMESTAMP_CORRELATION);
+    let (_, messages) = decoder.decode().unwrap();
+    assert_eq!(
+        messages
+            .get("aviation_attitude")
+            .and_then(|it| it[0].get("utc_timestamp")),
+        Some(&Value::Array(vec![
+            fit_date_time(1000000010.5),
+            fit_date_time(1000000011.0)
+        ]))
+    );
+}
+
+#[test]
+fn utc_timestamps_should_be_left_untouched() {
+    let csv = [
+        "Type,Local Number,Message",
+        "Definition,0,timestamp_correlation,timestamp,1,,system_timestamp,1,",
+        "Data,0,timestamp_correlation,timestamp,\"1000000000\",s,system_timestamp,\"1000\",s",
+        "Definition,1,accelerometer_data,timestamp,1,,timestamp_ms,1,",
+        "Data,1,accelerometer_data,timestamp,\"1000000005\",s,timestamp_ms,\"250\",ms",
+        "Data,1,accelerometer_data,timestamp,\"1005\",s,timestamp_ms,\"250\",ms",
+    ]
+    .join("\n");
+    let bytes = fit::fit_csv::to_fit(&csv).unwrap();
+    let (_, messages) = Decoder::new(&bytes).decode().unwrap();
+    let accelerometer_data = messages.get("accelerometer_data").unwrap();
+    assert_eq!(accelerometer_data[0].get("utc_timestamp"), None);
+    assert_eq!(
+        accelerometer_data[1].get("utc_timestamp"),
+        Some(&fit_date_time(1000000005.25))
+    );
+}
@@ -0,0 +1,44 @@
+use chrono::FixedOffset;
+use fit::decoder::Decoder;
+use fit::timezone;
+use fit::Value;
+mod data;
+
+#[test]
+fn derive_utc_offset_from_activity() {
+    let buf = std::fs::read("tests/data/Activity.fit").unwrap();
+    let mut decoder = Decoder::new(&buf);
+    let (_, messages) = decoder.decode().unwrap();
+    assert_eq!(
+        timezone::utc_offset(&messages),
+        FixedOffset::west_opt(7 * 3600)
+    );
+}
+
+#[test]
+fn attach_local_times() {
+    let buf = std::fs::read("tests/data/Activity.fit").unwrap();
+    let mut decoder = Decoder::new(&buf);
+    let (_, mut messages) = decoder.decode().unwrap();
+    let offset = timezone::localize(&mut messages).unwrap();
+    let session = &messages.get("session").unwrap()[0];
+    assert_eq!(
+        session.get("local_start_time").map(|it| it.to_string()),
+        Some("2021-07-20 14:11:20 -07:00".to_string())
+    );
+    for record in messages.get("record").unwrap() {
+        let (utc, local) = match (record.get("timestamp"), record.get("local_timestamp")) {
+            (Some(Value::DateTime(utc)), Some(Value::LocalDateTime(local))) => (utc, local),
+            _ => panic!("record is missing local_timestamp"),
+        };
+        assert_eq!(utc, local);
+        assert_eq!(local.offset(), &offset);
+    }
+}
+
+#[test]
+fn no_offset_without_activity() {
+    let mut decoder = Decoder::new(&data::FIT_FILE_SHORT);
+    let (_, messages) = decoder.decode().unwrap();
+    assert_eq!(timezone::utc_offset(&messages), None);
+}
@@ -0,0 +1,93 @@
+use crate::CliResult;
+use fit::catalog::{scan, CatalogEntry, ScanOptions};
+use std::io::{self, Write};
+use std::path::Path;
+
+const COLUMNS: [&str; 8] = [
+    "time_created",
+    "type",
+    "sport",
+    "duration",
+    "distance",
+    "device",
+    "errors",
+    "path",
+];
+
+pub fn run(root: &Path, threads: usize, json: bool) -> CliResult<bool> {
+    let entries = scan(
+        root,
+        &ScanOptions {
+            threads,
+            ..ScanOptions::default()
+        },
+    )
+    .map_err(|err| format!("{}: {err}", root.display()))?;
+    let valid = entries.iter().all(|it| it.error.is_none());
+    let mut out = io::stdout().lock();
+    if json {
+        writeln!(out, "{}", serde_json::to_string_pretty(&entries)?)?;
+        return Ok(valid);
+    }
+
+    let rows = entries.iter().map(row).collect::<Vec<_>>();
+    // the path is the last column and is not padded
+    let mut widths = COLUMNS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut print = |cells: &[String]| -> io::Result<()> {
+        let (path, cells) = cells.split_last().unwrap();
+        let line = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}  "))
+            .collect::<String>();
+        writeln!(out, "{line}{path}")
+    };
+    print(&COLUMNS.map(str::to_string))?;
+    for row in &rows {
+        print(row)?;
+    }
+    for entry in &entries {
+        if let Some(error) = &entry.error {
+            eprintln!("{}: {error}", entry.path);
+        }
+    }
+    Ok(valid)
+}
+
+fn row(entry: &CatalogEntry) -> [String; 8] {
+    let text = |value: &Option<String>| value.clone().unwrap_or_default();
+    [
+        entry
+            .time_created
+            .map(|it| it.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_default(),
+        text(&entry.file_type),
+        text(&entry.sport),
+        entry.duration.map(duration).unwrap_or_default(),
+        entry
+            .distance
+            .map(|it| format!("{:.2} km", it / 1000.0))
+            .unwrap_or_default(),
+        text(&entry.device),
+        match entry.error {
+            Some(_) => "failed".to_string(),
+            None => entry.errors.to_string(),
+        },
+        entry.path.clone(),
+    ]
+}
+
+fn duration(seconds: f64) -> String {
+    let seconds = seconds.round() as u64;
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
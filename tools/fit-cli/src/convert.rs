@@ -0,0 +1,148 @@
+use crate::{read, CliResult};
+use clap::{Args, ValueEnum};
+use fit::decoder::{Decoder, Messages};
+use fit::export::{geojson, gpx, jsonl, kml, table, tcx};
+use fit::fit_csv;
+use fit::import::{self, CourseOptions};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Args, Debug)]
+pub struct ConvertArgs {
+    /// FIT file, or a `.gpx`, `.tcx` or FitCSVTool `.csv` file which is encoded as FIT first
+    file: PathBuf,
+    /// Output format
+    #[arg(short, long, value_enum)]
+    to: Format,
+    /// Output file, or directory for `csv`, `tsv` and `parquet` with several message types,
+    /// standard output by default
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Only convert these message types, e.g. `record,lap`, for `json`, `csv`, `tsv` and `parquet`
+    #[arg(short, long, value_delimiter = ',')]
+    message: Vec<String>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// Messages grouped by type as JSON
+    Json,
+    /// A JSON line per message and diagnostic
+    Jsonl,
+    /// A CSV table per message type
+    Csv,
+    /// A TSV table per message type
+    Tsv,
+    /// FitCSVTool CSV
+    FitCsv,
+    Gpx,
+    Tcx,
+    Geojson,
+    Kml,
+    Kmz,
+    /// SQLite database, requires `--output`
+    #[cfg(feature = "sqlite")]
+    Sqlite,
+    /// A Parquet file per message type, requires `--output` directory
+    #[cfg(feature = "parquet")]
+    Parquet,
+    /// FIT, e.g. to encode GPX, TCX or FitCSVTool CSV input
+    Fit,
+}
+
+pub fn run(args: &ConvertArgs) -> CliResult<bool> {
+    let bytes = input(&args.file)?;
+    let output = args.output.as_deref();
+    let decode = || -> CliResult<Messages> {
+        let (_, mut messages) = Decoder::new(&bytes).decode()?;
+        if !args.message.is_empty() {
+            messages.retain(|name, _| args.message.contains(name));
+        }
+        Ok(messages)
+    };
+    match args.to {
+        Format::Json => write(output, serde_json::to_string_pretty(&decode()?)?.as_bytes())?,
+        Format::Jsonl => {
+            let mut writer: BufWriter<Box<dyn Write>> = BufWriter::new(match output {
+                Some(path) => Box::new(File::create(path)?),
+                None => Box::new(io::stdout().lock()),
+            });
+            jsonl::write(&mut Decoder::new(&bytes), &mut writer)?;
+            writer.flush()?;
+        }
+        Format::Csv => write_tables(decode()?, table::TableOptions::csv(), "csv", output)?,
+        Format::Tsv => write_tables(decode()?, table::TableOptions::tsv(), "tsv", output)?,
+        Format::FitCsv => {
+            let (_, messages) = Decoder::new(&bytes).decode_messages()?;
+            write(output, fit_csv::to_string(&messages).as_bytes())?;
+        }
+        Format::Gpx => write(output, gpx::to_string(&decode()?).as_bytes())?,
+        Format::Tcx => write(output, tcx::to_string(&decode()?).as_bytes())?,
+        Format::Geojson => {
+            let geojson = geojson::to_string(&decode()?, geojson::TrackGrouping::default());
+            write(output, geojson.as_bytes())?
+        }
+        Format::Kml => write(output, kml::to_string(&decode()?, None).as_bytes())?,
+        Format::Kmz => write(output, &kml::to_kmz(&decode()?, None)?)?,
+        #[cfg(feature = "sqlite")]
+        Format::Sqlite => {
+            let output = output.ok_or("--output is required for sqlite")?;
+            let (_, files) = Decoder::new(&bytes).decode_files()?;
+            fit::export::sqlite::write_file(output, &files)?;
+        }
+        #[cfg(feature = "parquet")]
+        Format::Parquet => {
+            let output = output.ok_or("--output is required for parquet")?;
+            fs::create_dir_all(output)?;
+            fit::export::parquet::write_all(&decode()?, output)?;
+        }
+        Format::Fit => write(output, &bytes)?,
+    }
+    Ok(true)
+}
+
+/// Read the input as FIT, encoding GPX, TCX and FitCSVTool CSV files
+fn input(path: &Path) -> CliResult<Vec<u8>> {
+    let extension = path
+        .extension()
+        .map(|it| it.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let text = || fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()));
+    let bytes = match extension.as_str() {
+        "gpx" => import::gpx::read(&text()?)?.to_fit(&CourseOptions::default())?,
+        "tcx" => import::tcx::read(&text()?)?.to_fit(&CourseOptions::default())?,
+        "csv" => fit_csv::to_fit(&text()?)?,
+        _ => read(path)?,
+    };
+    Ok(bytes)
+}
+
+/// A single table goes to the output file, several tables to `{message}.{extension}` files of
+/// the output directory
+fn write_tables(
+    messages: Messages,
+    options: table::TableOptions,
+    extension: &str,
+    output: Option<&Path>,
+) -> CliResult<()> {
+    let tables = table::to_tables(&messages, &options);
+    if tables.len() == 1 {
+        let (_, table) = tables.first().unwrap();
+        return write(output, table.as_bytes());
+    }
+    let dir = output.ok_or("--output directory or a single --message is required")?;
+    fs::create_dir_all(dir)?;
+    for (message_type, table) in tables {
+        fs::write(dir.join(format!("{message_type}.{extension}")), table)?;
+    }
+    Ok(())
+}
+
+fn write(output: Option<&Path>, bytes: &[u8]) -> CliResult<()> {
+    match output {
+        Some(path) => fs::write(path, bytes)?,
+        None => io::stdout().lock().write_all(bytes)?,
+    }
+    Ok(())
+}
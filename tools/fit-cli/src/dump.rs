@@ -0,0 +1,60 @@
+use crate::CliResult;
+use fit::decoder::{DecodeEvent, DecodedMessage, Decoder, Record};
+use fit::export::jsonl;
+use fit::profile::messages::field_def;
+use std::io::{self, Write};
+
+/// Print the data messages as they are decoded, diagnostics go to stderr unless `json` is set
+pub fn run(bytes: &[u8], messages: &[String], fields: &[String], json: bool) -> CliResult<bool> {
+    let wanted = |list: &[String], name: &str| list.is_empty() || list.iter().any(|it| it == name);
+    if json {
+        let filter =
+            |message: &str, field: &str| wanted(messages, message) && wanted(fields, field);
+        return Ok(jsonl::write_filtered(
+            &mut Decoder::new(bytes),
+            &mut io::stdout().lock(),
+            filter,
+        )?);
+    }
+    let mut out = io::stdout().lock();
+    // the first write error, e.g. a closed pipe, stops the output
+    let mut result = Ok(());
+    let decoded = Decoder::new(bytes).decode_events(|event| match event {
+        DecodeEvent::Message {
+            offset,
+            message: DecodedMessage::Data { name, record, .. },
+        } => {
+            if result.is_err() || !wanted(messages, &name) {
+                return;
+            }
+            let record = record
+                .into_iter()
+                .filter(|(field, _)| wanted(fields, field))
+                .collect::<Record>();
+            if !record.is_empty() {
+                result = write_record(&mut out, &name, offset, &record);
+            }
+        }
+        DecodeEvent::Message { .. } => {}
+        DecodeEvent::Error { offset, error } => eprintln!("warning @ {offset}: {error}"),
+    });
+    result?;
+    decoded?;
+    Ok(true)
+}
+
+fn write_record(
+    out: &mut impl Write,
+    name: &str,
+    offset: usize,
+    record: &Record,
+) -> io::Result<()> {
+    writeln!(out, "{name} @ {offset}")?;
+    for (field, value) in record {
+        match field_def(name, field).map(|it| it.units) {
+            Some(units) if !units.is_empty() => writeln!(out, "  {field}: {value} {units}")?,
+            _ => writeln!(out, "  {field}: {value}")?,
+        }
+    }
+    Ok(())
+}
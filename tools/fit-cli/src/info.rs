@@ -0,0 +1,129 @@
+use crate::CliResult;
+use fit::decoder::{DecodedFile, Decoder, FitFileHeader, Record};
+use serde_json::{json, Map, Value};
+use std::io::{self, Write};
+
+/// `device_info` fields describing a device, repeated for every timestamp in most files
+const DEVICE_FIELDS: [&str; 8] = [
+    "device_index",
+    "device_type",
+    "manufacturer",
+    "product",
+    "garmin_product",
+    "product_name",
+    "serial_number",
+    "software_version",
+];
+
+pub fn run(bytes: &[u8], json: bool) -> CliResult<bool> {
+    let (errors, files) = Decoder::new(bytes).decode_files()?;
+    let mut out = io::stdout().lock();
+    if json {
+        let files = files.iter().map(file_json).collect::<Vec<_>>();
+        writeln!(
+            out,
+            "{:#}",
+            json!({ "files": files, "errors": errors.len() })
+        )?;
+        return Ok(true);
+    }
+    for (index, file) in files.iter().enumerate() {
+        if files.len() > 1 {
+            writeln!(out, "File #{}", index + 1)?;
+        }
+        let header = &file.header;
+        writeln!(out, "Header")?;
+        writeln!(out, "  size: {} bytes", header.header_size)?;
+        writeln!(out, "  protocol version: {}", protocol_version(header))?;
+        writeln!(
+            out,
+            "  profile version: {}",
+            header.profile_version_string()
+        )?;
+        writeln!(out, "  data size: {} bytes", header.data_size)?;
+        writeln!(out, "  header crc: {:#06x}", header.header_crc)?;
+        if let Some(file_id) = file.messages.get("file_id").and_then(|it| it.first()) {
+            writeln!(out, "File id")?;
+            for (name, value) in file_id {
+                writeln!(out, "  {name}: {value}")?;
+            }
+        }
+        writeln!(out, "Messages")?;
+        for (name, records) in &file.messages {
+            writeln!(out, "  {name}: {}", records.len())?;
+        }
+        let devices = devices(file);
+        if !devices.is_empty() {
+            writeln!(out, "Devices")?;
+            for device in devices {
+                let line = device
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                writeln!(out, "  {line}")?;
+            }
+        }
+        writeln!(out)?;
+    }
+    writeln!(out, "Errors: {}", errors.len())?;
+    Ok(true)
+}
+
+fn file_json(file: &DecodedFile) -> Value {
+    let header = &file.header;
+    let record_json = |record: &Record| {
+        record
+            .iter()
+            .map(|(name, value)| (name.to_string(), json!(value)))
+            .collect::<Map<_, _>>()
+    };
+    json!({
+        "header": {
+            "size": header.header_size,
+            "protocol_version": protocol_version(header),
+            "profile_version": header.profile_version_string(),
+            "data_size": header.data_size,
+            "header_crc": header.header_crc,
+        },
+        "file_id": file.messages.get("file_id").and_then(|it| it.first()).map(record_json),
+        "messages": file
+            .messages
+            .iter()
+            .map(|(name, records)| (name.clone(), json!(records.len())))
+            .collect::<Map<_, _>>(),
+        "devices": devices(file)
+            .iter()
+            .map(|device| {
+                device
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), json!(value)))
+                    .collect::<Map<_, _>>()
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// The protocol version is stored as `major << 4 | minor`
+fn protocol_version(header: &FitFileHeader) -> String {
+    format!(
+        "{}.{}",
+        header.protocol_version >> 4,
+        header.protocol_version & 0x0F
+    )
+}
+
+/// Distinct devices of the `device_info` messages
+fn devices(file: &DecodedFile) -> Vec<Vec<(&'static str, String)>> {
+    let mut devices = Vec::new();
+    for record in file.messages.get("device_info").into_iter().flatten() {
+        let device = DEVICE_FIELDS
+            .iter()
+            .filter_map(|name| Some((*name, record.get(name)?.to_string())))
+            .collect::<Vec<_>>();
+        if !device.is_empty() && !devices.contains(&device) {
+            devices.push(device);
+        }
+    }
+    devices
+}
@@ -0,0 +1,149 @@
+mod catalog;
+mod convert;
+mod dump;
+mod info;
+mod validate;
+
+use clap::{Parser, Subcommand};
+use fit::decoder::{hex_dump, Decoder};
+use fit::diff::DiffOptions;
+use std::error::Error;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "fit",
+    version,
+    about = "Inspect, validate and convert FIT files"
+)]
+struct CommandArgs {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Show the file headers, file_id, message counts and devices
+    Info {
+        file: PathBuf,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print the messages in file order
+    Dump {
+        file: PathBuf,
+        /// Only print these message types, e.g. `record,lap`
+        #[arg(short, long, value_delimiter = ',')]
+        message: Vec<String>,
+        /// Only print these fields, e.g. `timestamp,heart_rate`
+        #[arg(short, long, value_delimiter = ',')]
+        field: Vec<String>,
+        /// Print JSON Lines instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check the CRCs and the structure of the file, exits with 1 if it is invalid
+    Validate {
+        file: PathBuf,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Compare the messages of two files, exits with 1 if they differ
+    Diff {
+        left: PathBuf,
+        right: PathBuf,
+        /// Fields left out of the comparison, `field` or `message.field`
+        #[arg(
+            short,
+            long,
+            value_delimiter = ',',
+            default_value = "time_created,serial_number"
+        )]
+        ignore: Vec<String>,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print the bytes of the file annotated with what they were decoded as
+    Hexdump { file: PathBuf },
+    /// List every FIT file under a directory, including the files in zip archives, exits with 1
+    /// if any of them fails to decode
+    Catalog {
+        dir: PathBuf,
+        /// Number of decoding threads, one per CPU when 0
+        #[arg(short, long, default_value_t = 0)]
+        threads: usize,
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+    /// Convert a FIT file, or a GPX, TCX or FitCSVTool CSV file, into another format
+    Convert(convert::ConvertArgs),
+}
+
+fn main() -> ExitCode {
+    let args = CommandArgs::parse();
+    let result = match args.command {
+        Command::Info { file, json } => read(&file).and_then(|bytes| info::run(&bytes, json)),
+        Command::Dump {
+            file,
+            message,
+            field,
+            json,
+        } => read(&file).and_then(|bytes| dump::run(&bytes, &message, &field, json)),
+        Command::Validate { file, json } => {
+            read(&file).and_then(|bytes| validate::run(&bytes, json))
+        }
+        Command::Diff {
+            left,
+            right,
+            ignore,
+            json,
+        } => diff(&left, &right, ignore, json),
+        Command::Hexdump { file } => read(&file).and_then(|bytes| {
+            let dump = hex_dump::annotate(&bytes);
+            write!(io::stdout().lock(), "{dump}")?;
+            Ok(dump.fatal.is_none())
+        }),
+        Command::Catalog { dir, threads, json } => catalog::run(&dir, threads, json),
+        Command::Convert(args) => convert::run(&args),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        // e.g. `fit dump x.fit | head`, the reader has all it wanted
+        Err(err) if is_broken_pipe(err.as_ref()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn is_broken_pipe(err: &(dyn Error + 'static)) -> bool {
+    err.downcast_ref::<io::Error>()
+        .is_some_and(|it| it.kind() == io::ErrorKind::BrokenPipe)
+}
+
+fn read(path: &Path) -> CliResult<Vec<u8>> {
+    std::fs::read(path).map_err(|err| format!("{}: {err}", path.display()).into())
+}
+
+fn diff(left: &Path, right: &Path, ignore_fields: Vec<String>, json: bool) -> CliResult<bool> {
+    let (_, left) = Decoder::new(&read(left)?).decode()?;
+    let (_, right) = Decoder::new(&read(right)?).decode()?;
+    let diff = fit::diff::diff(&left, &right, &DiffOptions { ignore_fields });
+    let mut out = io::stdout().lock();
+    if json {
+        writeln!(out, "{}", serde_json::to_string_pretty(&diff)?)?;
+    } else {
+        write!(out, "{diff}")?;
+    }
+    Ok(diff.is_empty())
+}
@@ -0,0 +1,177 @@
+use crate::CliResult;
+use fit::decoder::{DecodedFile, Decoder, Messages};
+use fit::Value;
+use indexmap::IndexMap;
+use serde_json::json;
+use std::io::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Level {
+    Error,
+    Warning,
+}
+
+impl Level {
+    fn name(&self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warning => "warning",
+        }
+    }
+}
+
+/// Issues in the order they were found, identical messages are counted once
+#[derive(Default)]
+struct Issues(IndexMap<(Level, String), usize>);
+
+impl Issues {
+    fn error(&mut self, message: impl Into<String>) {
+        *self.0.entry((Level::Error, message.into())).or_default() += 1;
+    }
+    fn warning(&mut self, message: impl Into<String>) {
+        *self.0.entry((Level::Warning, message.into())).or_default() += 1;
+    }
+    fn is_valid(&self) -> bool {
+        self.0.keys().all(|(level, _)| *level != Level::Error)
+    }
+}
+
+/// Check the headers and CRCs of every chained file, then the decoded messages
+pub fn run(bytes: &[u8], json: bool) -> CliResult<bool> {
+    let mut issues = Issues::default();
+    check_integrity(bytes, &mut issues);
+    match Decoder::new(bytes).decode_files() {
+        Ok((errors, files)) => {
+            for error in errors {
+                issues.warning(error.to_string());
+            }
+            for (index, file) in files.iter().enumerate() {
+                check_messages(index, files.len(), file, &mut issues);
+            }
+        }
+        Err(error) => issues.error(format!("decoding failed: {error}")),
+    }
+
+    let valid = issues.is_valid();
+    let mut out = io::stdout().lock();
+    if json {
+        let issues = issues
+            .0
+            .iter()
+            .map(|((level, message), count)| {
+                json!({ "level": level.name(), "message": message, "count": count })
+            })
+            .collect::<Vec<_>>();
+        writeln!(out, "{:#}", json!({ "valid": valid, "issues": issues }))?;
+    } else {
+        for ((level, message), count) in &issues.0 {
+            match count {
+                1 => writeln!(out, "{}: {message}", level.name())?,
+                _ => writeln!(out, "{}: {message} (x{count})", level.name())?,
+            }
+        }
+        writeln!(out, "{}", if valid { "valid" } else { "invalid" })?;
+    }
+    Ok(valid)
+}
+
+fn check_integrity(bytes: &[u8], issues: &mut Issues) {
+    if !Decoder::is_fit(bytes) {
+        issues.error("not a FIT file");
+        return;
+    }
+    let mut offset = 0;
+    let mut index = 0;
+    while offset < bytes.len() {
+        let rest = &bytes[offset..];
+        if !Decoder::is_fit(rest) {
+            issues.error(format!("{} trailing bytes after file #{index}", rest.len()));
+            return;
+        }
+        index += 1;
+        let header_size = rest[0] as usize;
+        let data_size = u32::from_le_bytes([rest[4], rest[5], rest[6], rest[7]]) as usize;
+        let end = header_size + data_size + 2;
+        if rest.len() < end {
+            issues.error(format!(
+                "file #{index} is truncated, {end} bytes expected but {} found",
+                rest.len()
+            ));
+            return;
+        }
+        if !Decoder::new(&rest[..end]).check_integrity() {
+            issues.error(format!("file #{index} has an invalid CRC"));
+        }
+        offset += end;
+    }
+}
+
+fn check_messages(index: usize, count: usize, file: &DecodedFile, issues: &mut Issues) {
+    let prefix = match count {
+        1 => String::new(),
+        _ => format!("file #{}: ", index + 1),
+    };
+    let messages = &file.messages;
+    let len = |name: &str| messages.get(name).map(|it| it.len()).unwrap_or_default();
+    if !messages.contains_key("file_id") {
+        issues.error(format!("{prefix}missing file_id message"));
+    } else if messages.keys().next().map(|it| it.as_str()) != Some("file_id") {
+        issues.warning(format!("{prefix}file_id is not the first message"));
+    }
+
+    let mut last = None;
+    for record in messages.get("record").into_iter().flatten() {
+        if let Some(Value::DateTime(timestamp)) = record.get("timestamp") {
+            if last.is_some_and(|last| *timestamp < last) {
+                issues.warning(format!("{prefix}record timestamps go backwards"));
+            }
+            last = Some(*timestamp);
+        }
+    }
+
+    let required: &[&str] = match file_type(messages).as_deref() {
+        Some("activity") => &["activity", "session", "lap"],
+        Some("course") => &["course", "lap", "record"],
+        _ => &[],
+    };
+    for name in required {
+        if len(name) == 0 {
+            issues.error(format!("{prefix}missing {name} message"));
+        }
+    }
+    if file_type(messages).as_deref() == Some("activity") {
+        let laps = sum(messages, "session", "num_laps");
+        if laps.is_some_and(|it| it != len("lap")) {
+            issues.warning(format!(
+                "{prefix}sessions count {} laps but the file has {}",
+                laps.unwrap_or_default(),
+                len("lap")
+            ));
+        }
+        let sessions = sum(messages, "activity", "num_sessions");
+        if sessions.is_some_and(|it| it != len("session")) {
+            issues.warning(format!(
+                "{prefix}activity counts {} sessions but the file has {}",
+                sessions.unwrap_or_default(),
+                len("session")
+            ));
+        }
+    }
+}
+
+fn file_type(messages: &Messages) -> Option<String> {
+    messages
+        .get("file_id")?
+        .first()?
+        .get("type")
+        .map(|it| it.to_string())
+}
+
+/// Sum of a count field over the messages having it
+fn sum(messages: &Messages, message_type: &str, field: &str) -> Option<usize> {
+    messages
+        .get(message_type)?
+        .iter()
+        .filter_map(|it| it.get(field)?.try_as_usize().ok())
+        .reduce(|a, b| a + b)
+}
@@ -0,0 +1,238 @@
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+const ACTIVITY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../tests/data/Activity.fit");
+
+fn fit<P: AsRef<Path>>(args: &[&str], file: P) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_fit"))
+        .args(args)
+        .arg(file.as_ref())
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn temp_file(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+#[test]
+fn info_json() {
+    let output = fit(&["info", "--json"], ACTIVITY);
+    assert!(output.status.success());
+    let info: Value = serde_json::from_str(&stdout(&output)).unwrap();
+    let file = &info["files"][0];
+    assert_eq!(file["header"]["protocol_version"], "2.0");
+    assert_eq!(file["file_id"]["type"], "activity");
+    assert_eq!(file["messages"]["record"], 3601);
+    assert_eq!(file["devices"][0]["product_name"], "FIT Cookbook");
+    assert_eq!(info["errors"], 0);
+}
+
+#[test]
+fn dump_filters_messages_and_fields() {
+    let output = fit(
+        &["dump", "--message", "lap,session", "--field", "timestamp"],
+        ACTIVITY,
+    );
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("lap @ "));
+    assert_eq!(stdout(&output).lines().count(), 4);
+
+    let output = fit(
+        &["dump", "--json", "-m", "record", "-f", "heart_rate"],
+        ACTIVITY,
+    );
+    let lines = stdout(&output)
+        .lines()
+        .map(|it| serde_json::from_str::<Value>(it).unwrap())
+        .collect::<Vec<_>>();
+    assert!(!lines.is_empty());
+    assert!(lines.iter().all(|it| it["message"] == "record"
+        && it["fields"].as_object().unwrap().keys().eq(["heart_rate"])));
+}
+
+#[test]
+fn validate_reports_invalid_crc() {
+    let output = fit(&["validate"], ACTIVITY);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "valid\n");
+
+    let mut bytes = std::fs::read(ACTIVITY).unwrap();
+    *bytes.last_mut().unwrap() ^= 0xFF;
+    let path = temp_file("invalid_crc.fit");
+    std::fs::write(&path, bytes).unwrap();
+    let output = fit(&["validate", "--json"], &path);
+    assert_eq!(output.status.code(), Some(1));
+    let report: Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(report["valid"], false);
+    assert_eq!(report["issues"][0]["level"], "error");
+    assert_eq!(report["issues"][0]["message"], "file #1 has an invalid CRC");
+}
+
+#[test]
+fn truncated_file_is_an_error_not_a_panic() {
+    let bytes = std::fs::read(ACTIVITY).unwrap();
+    let path = temp_file("truncated.fit");
+    std::fs::write(&path, &bytes[..3000]).unwrap();
+
+    let output = fit(&["validate"], &path);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output)
+        .starts_with("error: file #1 is truncated, 94096 bytes expected but 3000 found\n"));
+    assert!(stdout(&output).ends_with("invalid\n"));
+
+    let output = fit(&["info"], &path);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("error: Out of bounds read"));
+
+    // messages before the truncation are printed
+    let output = fit(&["dump"], &path);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stdout(&output).starts_with("file_id @ "));
+    let output = fit(&["dump", "--json"], &path);
+    assert_eq!(output.status.code(), Some(1));
+    let last = stdout(&output).lines().last().unwrap().to_string();
+    let last: Value = serde_json::from_str(&last).unwrap();
+    assert_eq!(last["diagnostic"]["kind"], "OutOfBoundsRead");
+    assert_eq!(last["fatal"], true);
+}
+
+#[test]
+fn convert_table_of_one_message() {
+    let output = fit(
+        &["convert", "--to", "csv", "--message", "session"],
+        ACTIVITY,
+    );
+    assert!(output.status.success());
+    let csv = stdout(&output);
+    assert_eq!(csv.lines().count(), 2);
+    assert!(csv.lines().next().unwrap().contains("sport"));
+
+    let output = fit(&["convert", "--to", "tsv"], ACTIVITY);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("error: "));
+}
+
+#[test]
+fn convert_gpx_to_fit() {
+    let gpx = temp_file("activity.gpx");
+    let output = fit(
+        &["convert", "--to", "gpx", "-o", gpx.to_str().unwrap()],
+        ACTIVITY,
+    );
+    assert!(output.status.success());
+
+    let course = temp_file("course.fit");
+    let output = fit(
+        &["convert", "--to", "fit", "-o", course.to_str().unwrap()],
+        &gpx,
+    );
+    assert!(output.status.success());
+    let output = fit(&["validate"], &course);
+    assert_eq!(stdout(&output), "valid\n");
+    let output = fit(&["info", "--json"], &course);
+    let info: Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(info["files"][0]["header"]["profile_version"], "21.126");
+    assert_eq!(info["files"][0]["file_id"]["type"], "course");
+    assert_eq!(info["files"][0]["messages"]["record"], 3601);
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn convert_requires_output_for_sqlite() {
+    let output = fit(&["convert", "--to", "sqlite"], ACTIVITY);
+    assert_eq!(output.status.code(), Some(2));
+
+    let path = temp_file("activity.sqlite");
+    let _ = std::fs::remove_file(&path);
+    let output = fit(
+        &["convert", "--to", "sqlite", "-o", path.to_str().unwrap()],
+        ACTIVITY,
+    );
+    assert!(output.status.success());
+    assert!(path.exists());
+}
+
+#[test]
+fn hexdump() {
+    let output = fit(&["hexdump"], ACTIVITY);
+    assert!(output.status.success());
+    let text = stdout(&output);
+    assert!(text.starts_with("00000000  0e 20 "));
+    assert!(text.contains("field 0 type: 4 -> activity"));
+}
+
+#[test]
+fn closed_pipe_is_not_an_error() {
+    for command in ["hexdump", "dump", "info"] {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_fit"))
+            .args([command, ACTIVITY])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        // like `fit hexdump x.fit | head -0`
+        drop(child.stdout.take());
+        let output = child.wait_with_output().unwrap();
+        assert!(output.status.success(), "{command}");
+        assert!(output.stderr.is_empty(), "{command}");
+    }
+}
+
+#[test]
+fn diff_exit_code() {
+    let output = Command::new(env!("CARGO_BIN_EXE_fit"))
+        .args(["diff", ACTIVITY, ACTIVITY])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "0 added, 0 removed, 0 changed\n");
+
+    let gpx = temp_file("diff.gpx");
+    let course = temp_file("diff.fit");
+    fit(
+        &["convert", "--to", "gpx", "-o", gpx.to_str().unwrap()],
+        ACTIVITY,
+    );
+    fit(
+        &["convert", "--to", "fit", "-o", course.to_str().unwrap()],
+        &gpx,
+    );
+    let output = Command::new(env!("CARGO_BIN_EXE_fit"))
+        .args(["diff", "--json", ACTIVITY, course.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let diff: Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(diff["changes"][0]["change"], "changed");
+    assert_eq!(diff["changes"][0]["message"], "file_id");
+}
+
+#[test]
+fn catalog() {
+    let dir = temp_file("catalog");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("2021")).unwrap();
+    std::fs::copy(ACTIVITY, dir.join("2021/Activity.fit")).unwrap();
+    let output = fit(&["catalog", "--json"], &dir);
+    assert!(output.status.success());
+    let entries: Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(entries[0]["file_type"], "activity");
+    assert_eq!(entries[0]["time_created"], "2021-07-20T21:11:20Z");
+
+    let bytes = std::fs::read(ACTIVITY).unwrap();
+    std::fs::write(dir.join("truncated.fit"), &bytes[..1000]).unwrap();
+    let output = fit(&["catalog", "--threads", "2"], &dir);
+    assert_eq!(output.status.code(), Some(1));
+    let text = stdout(&output);
+    assert!(text.starts_with("time_created "));
+    assert!(text.contains("  1:00:01  "));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(stderr.lines().count(), 1);
+    assert!(stderr.contains("truncated.fit: "));
+}
@@ -66,12 +66,11 @@ fn main() {
 
 fn read_profile_file(path: &str) -> Result<(Option<String>, Vec<u8>), String> {
     let path = {
-        let path = if path.starts_with("~/") {
+        let path = if let Some(trimmed_path) = path.strip_prefix("~/") {
             let home = std::env::var("HOME")
                 .or_else(|_| std::env::var("USERPROFILE"))
                 .ok()
                 .unwrap();
-            let trimmed_path = &path[2..];
             Path::new(&home).join(trimmed_path).to_path_buf()
         } else {
             std::path::PathBuf::from(path)
@@ -105,20 +104,19 @@ fn read_profile_file(path: &str) -> Result<(Option<String>, Vec<u8>), String> {
                 .find(|it| it.contains("Profile.xlsx"))
                 .map(|it| it.to_string())
                 .unwrap();
-            let file = archive.by_name(&name).unwrap();
+            let mut file = archive.by_name(&name).unwrap();
             let version = name
                 .split('/')
                 .next()
-                .and_then(|first_part| extract_sdk_version(first_part))
+                .and_then(extract_sdk_version)
                 .or_else(|| {
                     path.file_name()
                         .and_then(|it| it.to_str())
                         .and_then(|it| extract_sdk_version(it.trim_end_matches(".zip")))
                 });
-            Ok((
-                version,
-                file.bytes().map(|byte| byte.unwrap()).collect::<Vec<_>>(),
-            ))
+            let mut bytes = Vec::new();
+            file.read_to_end(&mut bytes).unwrap();
+            Ok((version, bytes))
         }
         "xlsx" => {
             let bytes = Vec::new();
@@ -27,6 +27,7 @@ pub struct NestedMessageField {
     pub units: String,
     pub ref_field_name: String,
     pub ref_field_value: String,
+    #[allow(unused)]
     pub comment: Option<String>,
     _raw_components: Vec<MessageComponent>,
 }
@@ -163,11 +163,151 @@ pub fn process_types(types: &crate::parser::Types, version: &str) {
                 Visibility::Private,
             );
         });
+        if let Some(bit_mask) = bit_mask(t) {
+            writer.write_impl(&enum_name, None, |writer| {
+                writer.write_fn(
+                    "flags",
+                    vec!["value: &fit::Value"],
+                    Some("Vec<Self>"),
+                    |writer| {
+                        writer.write_line("let raw = value.try_as_usize().unwrap_or(0);");
+                        writer.write_line("[");
+                        writer.scope(|writer| {
+                            for value in t.values.iter().filter(|it| {
+                                bit_mask.value_mask.is_none()
+                                    || hex_value(&it.value) != bit_mask.value_mask
+                            }) {
+                                writer.write_line(format!(
+                                    "({enum_name}::{}, {}),",
+                                    to_pascal_case(&value.name),
+                                    value.value
+                                ));
+                            }
+                        });
+                        writer.write_code_fragment(
+                            r"
+                            ]
+                            .into_iter()
+                            .filter(|(_, bit)| *bit != 0 && raw & bit == *bit)
+                            .map(|(flag, _)| flag)
+                            .collect()",
+                        );
+                    },
+                    Visibility::Public,
+                );
+            });
+        }
         writer.write_newline();
     }
+    writer.write_comment(
+        "Names of the flags set in a bit mask value, `None` if `ty` is not a bit mask type",
+    );
+    writer.write_fn(
+        "bit_flags",
+        vec!["ty: &str", "value: &fit::Value"],
+        Some("Option<Vec<String>>"),
+        |writer| {
+            writer.write_block("match ty", |writer| {
+                for t in types.iter().filter(|it| bit_mask(it).is_some()) {
+                    writer.write_line(format!(
+                        "\"{}\" => Some({}::flags(value).iter().map(|it| it.to_string()).collect()),",
+                        t.type_name,
+                        to_pascal_case(&t.type_name)
+                    ));
+                }
+                writer.write_line("_ => None,");
+            });
+        },
+        Visibility::Public,
+    );
+    writer.write_comment(
+        "Value mask of a bit mask type holding a value next to its flags, e.g. `left_right_balance`",
+    );
+    writer.write_fn(
+        "value_mask",
+        vec!["ty: &str"],
+        Some("Option<u64>"),
+        |writer| {
+            writer.write_block("match ty", |writer| {
+                for t in types.iter() {
+                    if let Some(mask) = bit_mask(t).and_then(|it| it.value_mask) {
+                        writer.write_line(format!("\"{}\" => Some({mask:#X}),", t.type_name));
+                    }
+                }
+                writer.write_line("_ => None,");
+            });
+        },
+        Visibility::Public,
+    );
+    writer
+        .write_comment("Raw value of a named profile type value, e.g. `(\"sport\", \"cycling\")`");
+    writer.write_fn(
+        "value_of",
+        vec!["ty: &str", "name: &str"],
+        Some("Option<fit::Value>"),
+        |writer| {
+            writer.write_block("match ty", |writer| {
+                for t in types.iter().filter(|it| !it.values.is_empty()) {
+                    writer.write_line(format!(
+                        "\"{}\" => {}::try_from(name).ok().map(|it| it.value()),",
+                        t.type_name,
+                        to_pascal_case(&t.type_name)
+                    ));
+                }
+                writer.write_line("_ => None,");
+            });
+        },
+        Visibility::Public,
+    );
     writer.fmt();
 }
 
+/// Layout of a bit mask type, see [`bit_mask`]
+struct BitMask {
+    /// Low bits holding a value next to the flags, e.g. `0x7F` of `left_right_balance`
+    value_mask: Option<u64>,
+}
+
+fn hex_value(value: &str) -> Option<u64> {
+    value
+        .strip_prefix("0x")
+        .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+}
+
+/// Bit mask types are not `enum` based and every named value is hex, either a single bit
+/// or a single value mask of the low bits below every flag. A type with nothing but one
+/// flag needs a `z` base type, which tells `sport_bits_6` from `date_time` (`min`).
+/// Types with other multi-bit values, e.g. `message_index` (`reserved = 0x7000`), are not
+/// bit masks.
+///
+/// # Example
+/// ```text
+/// sport_bits_0: generic = 0x01, running = 0x02, cycling = 0x04 ...
+/// left_right_balance: mask = 0x7F, right = 0x80
+/// ```
+fn bit_mask(t: &crate::parser::Type) -> Option<BitMask> {
+    if t.base_type == "enum" {
+        return None;
+    }
+    let bits = t
+        .values
+        .iter()
+        .map(|it| hex_value(&it.value))
+        .collect::<Option<Vec<_>>>()?;
+    let (flags, masks): (Vec<u64>, Vec<u64>) = bits
+        .into_iter()
+        .filter(|bit| *bit != 0)
+        .partition(|bit| bit.is_power_of_two());
+    let value_mask = match masks[..] {
+        [] => None,
+        [mask] if (mask + 1).is_power_of_two() && flags.iter().all(|bit| *bit > mask) => Some(mask),
+        _ => return None,
+    };
+    let named = flags.len() + masks.len();
+    let is_bit_mask = !flags.is_empty() && (named >= 2 || t.base_type.ends_with('z'));
+    is_bit_mask.then_some(BitMask { value_mask })
+}
+
 pub fn process_messages(
     messages: &crate::parser::Messages,
     types: &crate::parser::Types,
@@ -188,7 +328,7 @@ pub fn process_messages(
         "crate::fit",
         "chrono::{TimeZone, Utc}",
         "std::fmt",
-        "std::collections::HashMap",
+        "indexmap::IndexMap",
         "std::ops::{Deref, Div, Sub}",
         "std::borrow::Cow",
     ]);
@@ -206,7 +346,7 @@ pub fn process_messages(
         writer.write_fn(
             &format!("_{}", message.name),
             vec![
-                "message_map: &mut HashMap<&'static str, Field>",
+                "message_map: &mut IndexMap<&'static str, Field>",
                 "accumulator: &mut crate::accumulator::Accumulator",
                 "args: MessageDecodeArgs",
             ],
@@ -239,13 +379,67 @@ pub fn process_messages(
         |writer| {
             writer.write_block("match message_type", |writer| {
                 for emitted in emitted_messages {
-                    writer.write_line(&format!("\"{emitted}\" => Some(Box::new(_{emitted})),"));
+                    writer.write_line(format!("\"{emitted}\" => Some(Box::new(_{emitted})),"));
                 }
                 writer.write_line("_ => None,");
             });
         },
         Visibility::Crate,
     );
+    writer
+        .write_comment("Profile name of a message field, e.g. `(\"workout_step\", 8)` is `notes`");
+    writer.write_fn(
+        "field_name",
+        vec!["message_type: &str", "field_no: u8"],
+        Some("Option<&'static str>"),
+        |writer| {
+            writer.write_block("match (message_type, field_no)", |writer| {
+                for message in messages {
+                    for field in &message.fields {
+                        writer.write_line(format!(
+                            "(\"{}\", {}) => Some(\"{}\"),",
+                            message.name, field.field_no, field.field_name
+                        ));
+                    }
+                }
+                writer.write_line("_ => None,");
+            });
+        },
+        Visibility::Public,
+    );
+    writer.write_comment("Profile definition of a message field, looked up by field name");
+    writer.write_fn(
+        "field_def",
+        vec!["message_type: &str", "name: &str"],
+        Some("Option<FieldDef>"),
+        |writer| {
+            writer.write_block("match (message_type, name)", |writer| {
+                for message in messages {
+                    for field in &message.fields {
+                        let base_type = types_map
+                            .get(&field.field_type)
+                            .map(|it| it.base_type.as_str())
+                            .unwrap_or(&field.field_type);
+                        // `bool` fields are stored as enums
+                        let base_type = if base_type == "bool" { "enum" } else { base_type };
+                        writer.write_line(format!(
+                            "(\"{message}\", \"{name}\") => Some(FieldDef {{ number: {number}, name: \"{name}\", field_ty: \"{field_ty}\", base_type: fit::BaseType::{base_type}, scale: {scale}, offset: {offset}, units: {units} }}),",
+                            message = message.name,
+                            name = field.field_name,
+                            number = field.field_no,
+                            field_ty = field.field_type,
+                            base_type = to_pascal_case(base_type).replace("int", "Int"),
+                            scale = format_float(field.scale),
+                            offset = format_float(field.offset),
+                            units = quote_text(&field.units)
+                        ));
+                    }
+                }
+                writer.write_line("_ => None,");
+            });
+        },
+        Visibility::Public,
+    );
     writer.fmt();
 }
 
@@ -287,6 +481,18 @@ fn write_message_prelude(writer: &mut Writer) {
             pub is_subfield: bool,
             pub units: &'static str,
         }
+        /// Profile definition of a message field
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct FieldDef {
+            pub number: u8,
+            pub name: &'static str,
+            /// Profile type or base type name, e.g. `date_time` or `uint16`
+            pub field_ty: &'static str,
+            pub base_type: fit::BaseType,
+            pub scale: f64,
+            pub offset: f64,
+            pub units: &'static str,
+        }
         impl fmt::Display for Field{
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 self.value.fmt(f)
@@ -298,7 +504,7 @@ fn write_message_prelude(writer: &mut Writer) {
             pub msg_no: u16,
             pub field_no: u8,
             pub value: &'input fit::Value,
-            pub fields: &'input HashMap<u8, fit::Value>,
+            pub fields: &'input IndexMap<u8, fit::Value>,
         }
 
         struct TransformValueArgs<'input, R: ToString> {
@@ -360,6 +566,14 @@ fn write_message_prelude(writer: &mut Writer) {
             }
         }
 
+        /// Compare a reference field by numeric value, regardless of its on-wire base type
+        fn ref_field_eq(fields: &IndexMap<u8, fit::Value>, field_no: u8, value: i64) -> bool {
+            fields
+                .get(&field_no)
+                .and_then(|it| it.try_as_i64().ok())
+                .is_some_and(|it| it == value)
+        }
+
         fn transform_value<R: ToString>(
             value: Cow<fit::Value>,
             args: TransformValueArgs<R>,
@@ -371,6 +585,10 @@ fn write_message_prelude(writer: &mut Writer) {
             ) {
                 return Ok(value.clone());
             }
+            // null-separated string arrays are kept whole
+            if args.field_ty == "string" {
+                return Ok(value.clone());
+            }
             if let fit::Value::Array(arr) = value {
                 if let Some(len) = args.array {
                     if len != 0 && len != arr.len() {
@@ -387,10 +605,8 @@ fn write_message_prelude(writer: &mut Writer) {
                     ));
                 }
             }
-            if args.field_ty == "string" {
-                Ok(value.clone())
-            } else if args.field_ty == "date_time" {
-                return if let fit::Value::UInt32(timestamp) = value {
+            if args.field_ty == "date_time" {
+                if let fit::Value::UInt32(timestamp) = value {
                     // The second offset between UNIX and FIT Epochs (631065600).
                     Ok(fit::Value::DateTime(
                         Utc.timestamp_opt(*timestamp as i64 + 631065600, 0).unwrap(),
@@ -401,14 +617,17 @@ fn write_message_prelude(writer: &mut Writer) {
                         types::DateTime::base_type(),
                         value
                     ))
-                };
-            }  else if args.is_base_type
+                }
+            } else if args.field_ty == "byte" && matches!(value, fit::Value::Array(_)) {
+                // byte arrays are kept as a single byte string
+                Ok(value.to_bytes().map(fit::Value::Bytes).unwrap_or(value.clone()))
+            } else if args.is_base_type
                 && fit::BaseType::try_from(args.field_ty)
                     .ok()
                     .filter(|ty| ty.is_numeric())
                     .is_some()
             {
-                return if let fit::Value::Array(arr) = value {
+                if let fit::Value::Array(arr) = value {
                     Ok(fit::Value::Array(
                         arr.iter()
                             .map(|it| {
@@ -425,25 +644,41 @@ fn write_message_prelude(writer: &mut Writer) {
                         .div(args.scale)
                         .and_then(|it| it.sub(args.offset))
                         .unwrap())
-                };
+                }
             } else {
                 let ty_convert = |value: &fit::Value| {
-                    args.ty_to_str.deref()(value)
-                        .map(|it| fit::Value::String(it.to_string()))
-                        .unwrap_or(value.clone())
+                    let name = args.ty_to_str.deref()(value).map(|it| it.to_string());
+                    if args.is_base_type {
+                        return name.map(fit::Value::String).unwrap_or(value.clone());
+                    }
+                    if let Some(flags) = types::bit_flags(args.field_ty, value) {
+                        return fit::Value::Flags(fit::FlagsValue {
+                            ty: args.field_ty,
+                            value: Box::new(value.clone()),
+                            flags,
+                            masked_value: types::value_mask(args.field_ty)
+                                .zip(value.try_as_usize().ok())
+                                .map(|(mask, raw)| raw as u64 & mask),
+                        });
+                    }
+                    fit::Value::Typed(fit::TypedValue {
+                        ty: args.field_ty,
+                        value: Box::new(value.clone()),
+                        name,
+                    })
                 };
-                return if let fit::Value::Array(arr) = value {
+                if let fit::Value::Array(arr) = value {
                     Ok(fit::Value::Array(
                         arr.iter().map(ty_convert).collect::<Vec<_>>(),
                     ))
                 } else {
                     Ok(ty_convert(value))
-                };
+                }
             }
         }
         "#
     );
-    writer.write_code_fragment("pub type MessageDecoder = Box<dyn Fn(&mut HashMap<&'static str, Field>, &mut crate::accumulator::Accumulator, MessageDecodeArgs) -> Result<(), String>>;");
+    writer.write_code_fragment("pub type MessageDecoder = Box<dyn Fn(&mut IndexMap<&'static str, Field>, &mut crate::accumulator::Accumulator, MessageDecodeArgs) -> Result<(), String>>;");
 }
 struct WriteMessageFieldArgs<'a> {
     value_source: &'a str,
@@ -573,18 +808,14 @@ fn process_message_sub_fields(
             })
         });
 
-        let ref_type_value = if let Some((ref_type, ref_type_value)) = ref_type {
-            format!(
-                "fit::Value::{}({})",
-                to_pascal_case(&ref_type.base_type),
-                ref_type_value.value
-            )
+        let ref_type_value = if let Some((_, ref_type_value)) = ref_type {
+            ref_type_value.value.clone()
         } else {
             return;
         };
         writer.write_block(
             &format!(
-                "if args.fields.get(&{}u8) == Some(&{})",
+                "if ref_field_eq(args.fields, {}u8, {})",
                 ref_field.field_no, ref_type_value
             ),
             |writer| {
@@ -749,11 +980,17 @@ fn convert_type(
         (try_convert_to_rust_ty(&base_type).unwrap(), base_type)
     };
     if old_ty.1 != new_ty.1 {
+        // base types sharing a rust type, e.g. `byte` and `enum`, need no cast
+        let cast = if old_ty.0 == new_ty.0 {
+            String::new()
+        } else {
+            format!(" as {}", new_ty.0)
+        };
         // write convert type
         writer.write_line(
             format!(
-                "let value = if let fit::Value::{old_base_ty}(v) = {value_source}{{ Cow::Owned(fit::Value::{new_base_ty}(*v as {new_rust_ty})) }} else {{ Cow::Borrowed({value_source}) }};",
-                old_base_ty = to_pascal_case(&old_ty.1), new_base_ty = to_pascal_case(&new_ty.1), new_rust_ty = new_ty.0)
+                "let value = if let fit::Value::{old_base_ty}(v) = {value_source}{{ Cow::Owned(fit::Value::{new_base_ty}(*v{cast})) }} else {{ Cow::Borrowed({value_source}) }};",
+                old_base_ty = to_pascal_case(&old_ty.1), new_base_ty = to_pascal_case(&new_ty.1))
         );
         *value_source = "value".to_string();
         true
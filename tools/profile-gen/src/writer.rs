@@ -100,6 +100,7 @@ impl<'a> Writer<'a> {
         };
         self.write_line(format!("{name}{value},"));
     }
+    #[allow(unused)]
     pub fn write_struct_member(&mut self, name: &str, value: &str, visibility: Visibility) {
         self.write_line(format!("{visibility} {name}: {value},"));
     }
@@ -198,7 +199,7 @@ impl<'a> Writer<'a> {
     {
         self.write_line(format!("{func}(")).increase_indentation();
         for parameter in parameters {
-            self.write_code_fragment(&format!("{parameter},"));
+            self.write_code_fragment(format!("{parameter},"));
         }
         self.decrease_indention().write_line(");");
     }